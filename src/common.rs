@@ -1,5 +1,47 @@
+mod action;
+mod animation;
+mod aria;
+mod classes;
+mod confirm;
+mod defaults;
+mod focus;
+mod icon;
+mod image;
+mod input;
+mod machine;
+#[cfg(feature = "markdown")]
+mod markdown;
+mod queue;
+mod result;
+#[cfg(feature = "sanitize")]
+mod sanitize;
+mod styles;
+mod theme;
+mod toast;
+
+pub use action::*;
+pub use animation::*;
+pub use aria::*;
+pub use classes::*;
+pub use confirm::*;
+pub use defaults::*;
+pub use focus::*;
+pub use icon::*;
+pub use image::*;
+pub use input::*;
+pub use machine::*;
+#[cfg(feature = "markdown")]
+pub use markdown::*;
+pub use queue::*;
+pub use result::*;
+#[cfg(feature = "sanitize")]
+pub use sanitize::*;
+pub use styles::*;
+pub use theme::*;
+pub use toast::*;
+
 /// Alert icon types
-#[derive(Debug, PartialEq, Clone, Default)]
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
 pub enum IconType {
     Warning,
     Error,
@@ -7,10 +49,14 @@ pub enum IconType {
     Info,
     #[default]
     Question,
+    /// A user-provided SVG, image or framework node.
+    Custom(CustomIcon),
+    /// No icon at all.
+    None,
 }
 
 /// Alert positions
-#[derive(Debug, PartialEq, Clone, Default)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Default)]
 pub enum Position {
     TopLeft,
     TopCenter,
@@ -22,20 +68,95 @@ pub enum Position {
     BottomLeft,
     BottomCenter,
     BottomRight,
-    Custom(&'static str, &'static str),
+    /// A `left` and `top` offset as CSS lengths, such as `"10px"` and `"20%"`.
+    Custom(String, String),
+}
+
+impl IconType {
+    /// The icon color used when no explicit `icon_color` is set.
+    pub fn default_color(&self) -> &'static str {
+        match self {
+            IconType::Warning => "orange",
+            IconType::Error => "red",
+            IconType::Success => "green",
+            IconType::Info => "blue",
+            IconType::Question => "gray",
+            IconType::Custom(_) => "currentColor",
+            IconType::None => "transparent",
+        }
+    }
+}
+
+impl Position {
+    /// Inline CSS anchoring an absolutely or fixed positioned element at this position.
+    ///
+    /// ```rust
+    /// use alert_rs::Position;
+    ///
+    /// assert_eq!(Position::TopLeft.style(), "top: 0; left: 0;");
+    /// assert_eq!(
+    ///     Position::Custom("10px".into(), format!("{}%", 20)).style(),
+    ///     "top: 20%; left: 10px;"
+    /// );
+    /// ```
+    pub fn style(&self) -> String {
+        match self {
+            Position::TopLeft => "top: 0; left: 0;".to_string(),
+            Position::TopCenter => "top: 0; left: 50%; transform: translateX(-50%);".to_string(),
+            Position::TopRight => "top: 0; right: 0;".to_string(),
+            Position::LeftCenter => "top: 50%; left: 0; transform: translateY(-50%);".to_string(),
+            Position::Center => {
+                "top: 50%; left: 50%; transform: translate(-50%, -50%);".to_string()
+            }
+            Position::BottomCenter => {
+                "bottom: 0; left: 50%; transform: translateX(-50%);".to_string()
+            }
+            Position::RightCenter => "top: 50%; right: 0; transform: translateY(-50%);".to_string(),
+            Position::BottomRight => "bottom: 0; right: 0;".to_string(),
+            Position::BottomLeft => "bottom: 0; left: 0;".to_string(),
+            Position::Custom(x, y) => format!("top: {}; left: {};", y, x),
+        }
+    }
+
+    /// Whether the position is anchored to the bottom edge of the screen.
+    pub fn is_bottom(&self) -> bool {
+        matches!(
+            self,
+            Position::BottomLeft | Position::BottomCenter | Position::BottomRight
+        )
+    }
 }
 
 /// Default styles
 pub const DEFAULT_ALERT_STYLE: &str =
-    "position: fixed; width: 100vw; height: 100vh; top: 0; left: 0; background: rgba(0, 0, 0, 0.75); z-index: 10; display: flex; justify-content: center; align-items: center;";
+    "position: fixed; width: 100vw; height: 100vh; top: 0; left: 0; background: var(--alert-rs-backdrop, rgba(0, 0, 0, 0.75)); z-index: 10; display: flex; justify-content: center; align-items: center;";
+pub const DEFAULT_BACKDROP_STYLE: &str =
+    "position: absolute; top: 0; left: 0; width: 100%; height: 100%;";
 pub const DEFAULT_CLOSE_BUTTON_STYLE: &str = "position: absolute; top: 10px; right: 10px;";
 pub const DEFAULT_CONFIRM_BUTTON_STYLE: &str =
-    "margin: 5px; padding: 5px 10px; background-color: green; color: white; border: none; border-radius: 5px;";
+    "margin: 5px; padding: 5px 10px; background-color: var(--alert-rs-confirm-color, green); color: var(--alert-rs-button-text-color, white); border: none; border-radius: var(--alert-rs-radius, 5px);";
 pub const DEFAULT_CANCEL_BUTTON_STYLE: &str =
-    "margin: 5px; padding: 5px 10px; background-color: red; color: white; border: none; border-radius: 5px;";
+    "margin: 5px; padding: 5px 10px; background-color: var(--alert-rs-cancel-color, red); color: var(--alert-rs-button-text-color, white); border: none; border-radius: var(--alert-rs-radius, 5px);";
+pub const DEFAULT_DENY_BUTTON_STYLE: &str =
+    "margin: 5px; padding: 5px 10px; background-color: var(--alert-rs-deny-color, gray); color: var(--alert-rs-button-text-color, white); border: none; border-radius: var(--alert-rs-radius, 5px);";
 pub const DEFAULT_ICON_STYLE: &str =
     "display: flex; justify-content: center; align-items: center; padding: 2px; margin: 2px;";
+pub const DEFAULT_IMAGE_STYLE: &str =
+    "display: block; max-width: 100%; margin: 10px auto; object-fit: contain;";
 pub const DEFAULT_TITLE_STYLE: &str =
     "justify-content: center; align-items: center; font-size: 26px;";
 pub const DEFAULT_SEPARATOR_STYLE: &str = "margin: 10px 0;";
 pub const DEFAULT_MESSAGE_STYLE: &str = "font-size: 14px;";
+pub const DEFAULT_INPUT_STYLE: &str =
+    "display: block; width: 100%; box-sizing: border-box; margin: 10px 0; padding: 8px; border: 1px solid var(--alert-rs-border-color, #ccc); border-radius: var(--alert-rs-radius, 5px); font-size: 14px;";
+pub const DEFAULT_VALIDATION_MESSAGE_STYLE: &str =
+    "margin: -5px 0 10px; color: var(--alert-rs-error-color, #d33); font-size: 13px;";
+pub const DEFAULT_SPINNER_STYLE: &str =
+    "display: inline-block; width: 10px; height: 10px; margin-right: 6px; border: 2px solid currentColor; border-right-color: transparent; border-radius: 50%; vertical-align: middle; animation: alert-rs-spin 0.75s linear infinite;";
+pub const SPINNER_KEYFRAMES: &str =
+    "@keyframes alert-rs-spin { to { transform: rotate(360deg); } }";
+pub const DEFAULT_INPUT_LABEL_STYLE: &str =
+    "display: inline-flex; align-items: center; gap: 5px; margin: 10px; font-size: 14px;";
+pub const DEFAULT_TOAST_STYLE: &str =
+    "position: relative; min-width: 250px; max-width: 350px; padding: 10px 30px 10px 15px; background: var(--alert-rs-background, white); color: var(--alert-rs-color, #333); border-radius: var(--alert-rs-radius, 5px); box-shadow: var(--alert-rs-shadow, 0 2px 8px rgba(0, 0, 0, 0.2));";
+pub const DEFAULT_TOAST_TITLE_STYLE: &str = "display: block; font-size: 16px; margin-bottom: 4px;";
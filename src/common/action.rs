@@ -0,0 +1,113 @@
+use super::{DEFAULT_CANCEL_BUTTON_STYLE, DEFAULT_CONFIRM_BUTTON_STYLE, DEFAULT_DENY_BUTTON_STYLE};
+
+/// Visual weight of an [`AlertAction`] button.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub enum ActionVariant {
+    /// The main action, styled like the confirm button.
+    #[default]
+    Primary,
+    /// A secondary action, styled like the deny button.
+    Secondary,
+    /// A destructive action, styled like the cancel button.
+    Danger,
+}
+
+impl ActionVariant {
+    /// The inline style used when the action has no explicit `style`.
+    pub fn default_style(&self) -> &'static str {
+        match self {
+            ActionVariant::Primary => DEFAULT_CONFIRM_BUTTON_STYLE,
+            ActionVariant::Secondary => DEFAULT_DENY_BUTTON_STYLE,
+            ActionVariant::Danger => DEFAULT_CANCEL_BUTTON_STYLE,
+        }
+    }
+}
+
+/// An extra button rendered after the confirm, deny and cancel buttons.
+///
+/// Clicking it calls `on_action` with its `id`. Unless [`AlertAction::closes`] is turned off, it
+/// also closes the alert with [`CloseReason::Action`](super::CloseReason::Action), and the
+/// `AlertResult` carries the id in `action`.
+///
+/// ```rust
+/// use alert_rs::common::{ActionVariant, AlertAction, DEFAULT_DENY_BUTTON_STYLE};
+///
+/// let actions = vec![
+///     AlertAction::new("retry", "Retry"),
+///     AlertAction::new("logs", "Open logs")
+///         .variant(ActionVariant::Secondary)
+///         .closes(false),
+///     AlertAction::new("dismiss", "Dismiss").variant(ActionVariant::Danger),
+/// ];
+///
+/// // Actions built at runtime, e.g. one per saved draft.
+/// let draft = String::from("draft-42");
+/// let open_draft = AlertAction::new(format!("open-{draft}"), format!("Open {draft}"));
+/// assert_eq!(open_draft.id, "open-draft-42");
+///
+/// assert!(actions[0].closes);
+/// assert_eq!(actions[1].button_style(), DEFAULT_DENY_BUTTON_STYLE);
+/// assert_eq!(actions[2].class, "");
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct AlertAction {
+    /// Identifier passed to `on_action` and returned in `AlertResult::action`.
+    pub id: String,
+    /// Text of the button.
+    pub label: String,
+    /// Visual weight of the button.
+    pub variant: ActionVariant,
+    /// Custom CSS class of the button.
+    pub class: String,
+    /// Inline styles of the button. Empty uses the style of the `variant`.
+    pub style: String,
+    /// Whether clicking the button closes the alert.
+    pub closes: bool,
+}
+
+impl AlertAction {
+    /// Creates a primary action that closes the alert.
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            variant: ActionVariant::Primary,
+            class: String::new(),
+            style: String::new(),
+            closes: true,
+        }
+    }
+
+    /// Sets the visual weight.
+    pub fn variant(mut self, variant: ActionVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Sets the CSS class.
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the inline styles, replacing those of the variant.
+    pub fn style(mut self, style: impl Into<String>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets whether clicking the button closes the alert.
+    pub fn closes(mut self, closes: bool) -> Self {
+        self.closes = closes;
+        self
+    }
+
+    /// The inline styles to render: `style`, or the style of the variant when empty.
+    pub fn button_style(&self) -> &str {
+        if self.style.is_empty() {
+            self.variant.default_style()
+        } else {
+            &self.style
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use super::Position;
+
+/// Keyframes used by the [`AlertAnimation`] presets.
+pub const ANIMATION_KEYFRAMES: &str = "@keyframes alert-rs-fade-in { from { opacity: 0; } } \
+@keyframes alert-rs-fade-out { to { opacity: 0; } } \
+@keyframes alert-rs-zoom-in { from { opacity: 0; scale: 0.5; } } \
+@keyframes alert-rs-zoom-out { to { opacity: 0; scale: 0.5; } } \
+@keyframes alert-rs-slide-in { from { opacity: 0; translate: var(--alert-rs-slide-from); } } \
+@keyframes alert-rs-slide-out { to { opacity: 0; translate: var(--alert-rs-slide-from); } } \
+@keyframes alert-rs-bounce-in { 0% { opacity: 0; scale: 0.5; } 45% { opacity: 1; scale: 1.05; } 80% { scale: 0.95; } 100% { scale: 1; } } \
+@keyframes alert-rs-bounce-out { 0% { scale: 1; } 25% { scale: 1.05; } 100% { opacity: 0; scale: 0.5; } }";
+
+/// How an alert enters and leaves the screen.
+///
+/// The presets animate the `scale` and `translate` properties, so they combine with the
+/// `transform` used by [`Position`]. Alerts are not animated unless one is chosen, so
+/// `did_close` fires as soon as an alert is hidden.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub enum AlertAnimation {
+    /// Appears and disappears instantly.
+    #[default]
+    None,
+    /// Fades in and out.
+    Fade,
+    /// Grows in and shrinks out.
+    Zoom,
+    /// Slides in from the edge the alert is positioned at, and back out.
+    Slide,
+    /// Pops in with a bounce and shrinks out.
+    Bounce,
+}
+
+impl AlertAnimation {
+    /// The name of the preset used in its keyframes, or `None` for [`AlertAnimation::None`].
+    fn name(&self) -> Option<&'static str> {
+        match self {
+            AlertAnimation::None => None,
+            AlertAnimation::Fade => Some("fade"),
+            AlertAnimation::Zoom => Some("zoom"),
+            AlertAnimation::Slide => Some("slide"),
+            AlertAnimation::Bounce => Some("bounce"),
+        }
+    }
+
+    /// Inline styles playing the enter animation of the alert box, or the exit one when
+    /// `leaving`.
+    ///
+    /// ```rust
+    /// use alert_rs::common::AlertAnimation;
+    /// use alert_rs::Position;
+    ///
+    /// assert_eq!(
+    ///     AlertAnimation::Zoom.box_style(&Position::Center, 300, false),
+    ///     "animation: alert-rs-zoom-in 300ms ease-out both;"
+    /// );
+    /// assert_eq!(
+    ///     AlertAnimation::Slide.box_style(&Position::BottomLeft, 200, true),
+    ///     "--alert-rs-slide-from: 0 40px; animation: alert-rs-slide-out 200ms ease-in both;"
+    /// );
+    /// assert_eq!(AlertAnimation::None.box_style(&Position::Center, 300, false), "");
+    /// ```
+    pub fn box_style(&self, position: &Position, duration: u32, leaving: bool) -> String {
+        let Some(name) = self.name() else {
+            return String::new();
+        };
+        let animation = if leaving {
+            format!("animation: alert-rs-{name}-out {duration}ms ease-in both;")
+        } else {
+            format!("animation: alert-rs-{name}-in {duration}ms ease-out both;")
+        };
+        if *self == AlertAnimation::Slide {
+            format!(
+                "--alert-rs-slide-from: {}; {animation}",
+                Self::slide_from(position)
+            )
+        } else {
+            animation
+        }
+    }
+
+    /// How long a hidden alert stays mounted to play its exit, in milliseconds.
+    ///
+    /// It is `duration` while this animation or an `exit_class` plays, and `0`, closing at once,
+    /// without either or with reduced motion.
+    ///
+    /// ```rust
+    /// use alert_rs::common::AlertAnimation;
+    ///
+    /// assert_eq!(AlertAnimation::Fade.exit_duration(300, "", false), 300);
+    /// assert_eq!(AlertAnimation::None.exit_duration(300, "fade-out", false), 300);
+    /// assert_eq!(AlertAnimation::None.exit_duration(300, "", false), 0);
+    /// assert_eq!(AlertAnimation::Fade.exit_duration(300, "", true), 0);
+    /// ```
+    pub fn exit_duration(&self, duration: u32, exit_class: &str, reduced_motion: bool) -> u32 {
+        if reduced_motion || (*self == AlertAnimation::None && exit_class.is_empty()) {
+            0
+        } else {
+            duration
+        }
+    }
+
+    /// Inline styles fading the overlay behind the alert in, or out when `leaving`.
+    pub fn overlay_style(&self, duration: u32, leaving: bool) -> String {
+        match (self, leaving) {
+            (AlertAnimation::None, _) => String::new(),
+            (_, false) => format!("animation: alert-rs-fade-in {duration}ms ease-out both;"),
+            (_, true) => format!("animation: alert-rs-fade-out {duration}ms ease-in both;"),
+        }
+    }
+
+    /// The offset the alert slides from, pointing at the edge it is anchored to.
+    fn slide_from(position: &Position) -> &'static str {
+        match position {
+            Position::BottomLeft | Position::BottomCenter | Position::BottomRight => "0 40px",
+            Position::LeftCenter => "-40px 0",
+            Position::RightCenter => "40px 0",
+            _ => "0 -40px",
+        }
+    }
+}
+
+/// Whether alerts honor the system's reduced motion setting.
+///
+/// With reduced motion, alerts appear and disappear instantly, the `enter_class` and
+/// `exit_class` hooks are skipped and nothing is kept mounted after the alert is hidden, so
+/// `did_close` fires without an exit delay. Animated icons fall back to the static ones and the
+/// loading spinner stands still.
+///
+/// Auto-close timers are deliberately unchanged: `timeout` is about how long the message stays
+/// readable, not about motion, so an alert still closes after the same delay.
+///
+/// ```rust
+/// use alert_rs::common::ReducedMotion;
+///
+/// assert!(ReducedMotion::System.reduces(true));
+/// assert!(!ReducedMotion::System.reduces(false));
+/// assert!(ReducedMotion::Always.reduces(false));
+/// assert!(!ReducedMotion::Never.reduces(true));
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub enum ReducedMotion {
+    /// Follows the `prefers-reduced-motion` media query.
+    #[default]
+    System,
+    /// Always reduces motion, e.g. for an in-app motion setting or for tests.
+    Always,
+    /// Never reduces motion.
+    Never,
+}
+
+impl ReducedMotion {
+    /// Whether motion is reduced, given whether the system prefers reduced motion.
+    pub fn reduces(&self, system: bool) -> bool {
+        match self {
+            ReducedMotion::System => system,
+            ReducedMotion::Always => true,
+            ReducedMotion::Never => false,
+        }
+    }
+
+    /// Whether motion is reduced, asking the browser through [`prefers_reduced_motion`] only
+    /// for [`ReducedMotion::System`].
+    pub fn is_reduced(&self) -> bool {
+        match self {
+            ReducedMotion::System => prefers_reduced_motion(),
+            _ => self.reduces(false),
+        }
+    }
+}
+
+/// Whether the user asked the system to minimize motion, read through `matchMedia`.
+pub fn prefers_reduced_motion() -> bool {
+    web_sys::window()
+        .and_then(|win| win.match_media("(prefers-reduced-motion: reduce)").ok())
+        .flatten()
+        .is_some_and(|query| query.matches())
+}
@@ -0,0 +1,116 @@
+use super::IconType;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Counter behind the `alert-rs-N` ids of [`AlertIds::generate`].
+static NEXT_ALERT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// The `id`s linking an alert box to its title and body through `aria-labelledby` and
+/// `aria-describedby`, and naming its buttons for [`InitialFocus`](super::InitialFocus).
+///
+/// Adapters generate them once per mounted `Alert`, so they stay the same across renders.
+///
+/// ```rust
+/// use alert_rs::common::AlertIds;
+///
+/// let ids = AlertIds::new("delete-files");
+///
+/// assert_eq!(ids.dialog, "delete-files");
+/// assert_eq!(ids.title, "delete-files-title");
+/// assert_eq!(ids.body, "delete-files-body");
+/// assert_eq!(ids.confirm_button, "delete-files-confirm");
+/// assert_eq!(ids.radio_group, "delete-files-radio");
+/// assert_eq!(ids.validation_message, "delete-files-validation");
+///
+/// assert_ne!(AlertIds::generate(), AlertIds::generate());
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct AlertIds {
+    /// The `id` of the alert box.
+    pub dialog: String,
+    /// The `id` of the title, referenced by `aria-labelledby`.
+    pub title: String,
+    /// The `id` of the body, referenced by `aria-describedby`.
+    pub body: String,
+    /// The `id` of the confirm button.
+    pub confirm_button: String,
+    /// The `id` of the cancel button.
+    pub cancel_button: String,
+    /// The `id` of the deny button.
+    pub deny_button: String,
+    /// The `name` shared by the radio buttons of the prompt input, so each alert gets its own group.
+    pub radio_group: String,
+    /// The `id` of the validation message, referenced by the `aria-describedby` of an invalid
+    /// radio group.
+    pub validation_message: String,
+}
+
+impl AlertIds {
+    /// Ids derived from `base`, such as an `id` prop set by the app.
+    pub fn new(base: &str) -> Self {
+        Self {
+            dialog: base.to_string(),
+            title: format!("{base}-title"),
+            body: format!("{base}-body"),
+            confirm_button: format!("{base}-confirm"),
+            cancel_button: format!("{base}-cancel"),
+            deny_button: format!("{base}-deny"),
+            radio_group: format!("{base}-radio"),
+            validation_message: format!("{base}-validation"),
+        }
+    }
+
+    /// Ids derived from a fresh `alert-rs-N` base, unique within the page.
+    pub fn generate() -> Self {
+        Self::new(&format!(
+            "alert-rs-{}",
+            NEXT_ALERT_ID.fetch_add(1, Ordering::Relaxed)
+        ))
+    }
+}
+
+impl IconType {
+    /// The ARIA role of an alert box with this icon.
+    ///
+    /// Warnings, errors and questions ask for the user's attention or answer, so they are
+    /// announced as an `alertdialog`. Other alerts are a plain `dialog`.
+    ///
+    /// ```rust
+    /// use alert_rs::IconType;
+    ///
+    /// assert_eq!(IconType::Error.dialog_role(), "alertdialog");
+    /// assert_eq!(IconType::Success.dialog_role(), "dialog");
+    /// ```
+    pub fn dialog_role(&self) -> &'static str {
+        match self {
+            IconType::Warning | IconType::Error | IconType::Question => "alertdialog",
+            _ => "dialog",
+        }
+    }
+
+    /// The `aria-live` politeness of a toast with this icon.
+    ///
+    /// Warnings and errors interrupt the screen reader with `assertive`, other toasts wait
+    /// for it to be idle with `polite`.
+    ///
+    /// ```rust
+    /// use alert_rs::IconType;
+    ///
+    /// assert_eq!(IconType::Warning.aria_live(), "assertive");
+    /// assert_eq!(IconType::Info.aria_live(), "polite");
+    /// ```
+    pub fn aria_live(&self) -> &'static str {
+        match self {
+            IconType::Warning | IconType::Error => "assertive",
+            _ => "polite",
+        }
+    }
+
+    /// The live region role of a toast with this icon: `alert` when it is announced
+    /// assertively, `status` otherwise.
+    pub fn live_role(&self) -> &'static str {
+        match self.aria_live() {
+            "assertive" => "alert",
+            _ => "status",
+        }
+    }
+}
@@ -0,0 +1,119 @@
+/// The CSS classes of an `Alert`, grouped so they can be built once and passed around.
+///
+/// Accepted as the `classes` prop by every adapter. A flat `*_class` prop set on the same alert
+/// wins over the matching field, and unset fields fall back to the app-wide `AlertDefaults`, then
+/// to the prop's default.
+///
+/// ```rust
+/// use alert_rs::common::AlertClasses;
+///
+/// let base = AlertClasses::default()
+///     .alert("rounded-lg shadow-xl p-6")
+///     .confirm_button("btn btn-primary");
+/// let danger = base.clone().merge(AlertClasses::default().confirm_button("btn btn-danger"));
+///
+/// assert_eq!(danger.alert.as_deref(), Some("rounded-lg shadow-xl p-6"));
+/// assert_eq!(danger.confirm_button.as_deref(), Some("btn btn-danger"));
+/// assert_eq!(danger.title, None);
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Default)]
+pub struct AlertClasses {
+    /// CSS class of the alert box.
+    pub alert: Option<String>,
+    /// CSS class of the icon.
+    pub icon: Option<String>,
+    /// CSS class of the image.
+    pub image: Option<String>,
+    /// CSS class of the title.
+    pub title: Option<String>,
+    /// CSS class of the body.
+    pub body: Option<String>,
+    /// CSS class of the confirm button.
+    pub confirm_button: Option<String>,
+    /// CSS class of the cancel button.
+    pub cancel_button: Option<String>,
+    /// CSS class of the deny button.
+    pub deny_button: Option<String>,
+    /// CSS class of the prompt input.
+    pub input: Option<String>,
+    /// CSS class of the validation message.
+    pub validation_message: Option<String>,
+}
+
+impl AlertClasses {
+    /// Sets the CSS class of the alert box.
+    pub fn alert(mut self, class: impl Into<String>) -> Self {
+        self.alert = Some(class.into());
+        self
+    }
+
+    /// Sets the CSS class of the icon.
+    pub fn icon(mut self, class: impl Into<String>) -> Self {
+        self.icon = Some(class.into());
+        self
+    }
+
+    /// Sets the CSS class of the image.
+    pub fn image(mut self, class: impl Into<String>) -> Self {
+        self.image = Some(class.into());
+        self
+    }
+
+    /// Sets the CSS class of the title.
+    pub fn title(mut self, class: impl Into<String>) -> Self {
+        self.title = Some(class.into());
+        self
+    }
+
+    /// Sets the CSS class of the body.
+    pub fn body(mut self, class: impl Into<String>) -> Self {
+        self.body = Some(class.into());
+        self
+    }
+
+    /// Sets the CSS class of the confirm button.
+    pub fn confirm_button(mut self, class: impl Into<String>) -> Self {
+        self.confirm_button = Some(class.into());
+        self
+    }
+
+    /// Sets the CSS class of the cancel button.
+    pub fn cancel_button(mut self, class: impl Into<String>) -> Self {
+        self.cancel_button = Some(class.into());
+        self
+    }
+
+    /// Sets the CSS class of the deny button.
+    pub fn deny_button(mut self, class: impl Into<String>) -> Self {
+        self.deny_button = Some(class.into());
+        self
+    }
+
+    /// Sets the CSS class of the prompt input.
+    pub fn input(mut self, class: impl Into<String>) -> Self {
+        self.input = Some(class.into());
+        self
+    }
+
+    /// Sets the CSS class of the validation message.
+    pub fn validation_message(mut self, class: impl Into<String>) -> Self {
+        self.validation_message = Some(class.into());
+        self
+    }
+
+    /// Returns these classes with every field set in `other` replaced by its value.
+    pub fn merge(self, other: Self) -> Self {
+        Self {
+            alert: other.alert.or(self.alert),
+            icon: other.icon.or(self.icon),
+            image: other.image.or(self.image),
+            title: other.title.or(self.title),
+            body: other.body.or(self.body),
+            confirm_button: other.confirm_button.or(self.confirm_button),
+            cancel_button: other.cancel_button.or(self.cancel_button),
+            deny_button: other.deny_button.or(self.deny_button),
+            input: other.input.or(self.input),
+            validation_message: other.validation_message.or(self.validation_message),
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// The future returned by a `pre_confirm` hook.
+///
+/// Resolving to `Err(message)` keeps the alert open and shows `message` in its validation area.
+pub type PreConfirmFuture = Pin<Box<dyn Future<Output = Result<(), String>>>>;
+
+type PreConfirmFn = dyn Fn(Option<String>) -> PreConfirmFuture + Send + Sync;
+
+/// An async check run when an imperatively fired alert is confirmed, before it closes.
+///
+/// It receives the input value, if the alert has an input.
+///
+/// ```rust
+/// use alert_rs::common::PreConfirm;
+///
+/// let pre_confirm = PreConfirm::new(|name| async move {
+///     match name.as_deref() {
+///         Some("admin") => Err("This name is taken".to_string()),
+///         _ => Ok(()),
+///     }
+/// });
+/// # let _ = pre_confirm.run(None);
+/// ```
+#[derive(Clone)]
+pub struct PreConfirm(Arc<PreConfirmFn>);
+
+impl PreConfirm {
+    /// Wraps an async function.
+    pub fn new<F, Fut>(pre_confirm: F) -> Self
+    where
+        F: Fn(Option<String>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), String>> + 'static,
+    {
+        Self(Arc::new(move |value| Box::pin(pre_confirm(value))))
+    }
+
+    /// Starts the check for `value`.
+    pub fn run(&self, value: Option<String>) -> PreConfirmFuture {
+        (self.0)(value)
+    }
+}
+
+impl PartialEq for PreConfirm {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for PreConfirm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PreConfirm")
+    }
+}
@@ -0,0 +1,279 @@
+use super::{
+    AlertAnimation, AlertClasses, AlertStyles, IconType, InitialFocus, Position, ReducedMotion,
+};
+
+/// App-wide defaults for the props of every `Alert`, shared through the framework's context.
+///
+/// Provide it once at the app root with Yew's `ContextProvider<AlertDefaults>`, Dioxus'
+/// `use_context_provider` or Leptos' `provide_context`. It covers the position, timeout, icon
+/// type, button texts and visibility, dismissal, focus, animation and motion props, as well as
+/// the classes and styles. Each value set here applies to the props an `Alert` leaves unset,
+/// while a prop set on an `Alert` keeps its value, even when it equals the built-in default. The `classes` and `styles` set here sit below the `classes` and
+/// `styles` props of an `Alert`, which sit below its flat `*_class` and `*_style` props.
+///
+/// ```rust
+/// use alert_rs::common::{AlertAnimation, AlertClasses, AlertDefaults, AlertStyles};
+/// use alert_rs::Position;
+///
+/// let defaults = AlertDefaults::default()
+///     .position(Position::TopCenter)
+///     .show_close_button(true)
+///     .close_on_backdrop_click(true)
+///     .animation(AlertAnimation::Zoom)
+///     .confirm_button_text("Got it")
+///     .classes(AlertClasses::default().alert("rounded-lg shadow-xl p-6 bg-white"))
+///     .styles(AlertStyles::default().confirm_button("background-color: #4f46e5; color: white;"));
+///
+/// // Left unset on the alert, so the app-wide value wins.
+/// assert_eq!(
+///     AlertDefaults::resolve(None, defaults.confirm_button_text.clone(), "Okay".into()),
+///     "Got it"
+/// );
+/// // Set on the alert, even to the built-in value, so it is kept.
+/// assert_eq!(
+///     AlertDefaults::resolve(
+///         Some("Okay".to_string()),
+///         defaults.confirm_button_text.clone(),
+///         "Okay".into()
+///     ),
+///     "Okay"
+/// );
+/// // Behavior props resolve the same way.
+/// assert!(AlertDefaults::resolve(None, defaults.show_close_button, false));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct AlertDefaults {
+    /// Position of the alert.
+    pub position: Option<Position>,
+    /// Auto-close timeout in milliseconds.
+    pub timeout: Option<u32>,
+    /// Text of the confirm button.
+    pub confirm_button_text: Option<String>,
+    /// Text of the cancel button.
+    pub cancel_button_text: Option<String>,
+    /// Text of the deny button.
+    pub deny_button_text: Option<String>,
+    /// Icon type of the alert.
+    pub icon_type: Option<IconType>,
+    /// Whether to show the confirm button.
+    pub show_confirm_button: Option<bool>,
+    /// Whether to show the cancel button.
+    pub show_cancel_button: Option<bool>,
+    /// Whether to show the deny button.
+    pub show_deny_button: Option<bool>,
+    /// Whether to show the close button.
+    pub show_close_button: Option<bool>,
+    /// Whether clicking the backdrop closes the alert.
+    pub close_on_backdrop_click: Option<bool>,
+    /// Whether pressing Escape closes the alert.
+    pub close_on_escape: Option<bool>,
+    /// The element focused when the alert opens.
+    pub initial_focus: Option<InitialFocus>,
+    /// The enter and exit animation.
+    pub animation: Option<AlertAnimation>,
+    /// Duration of the enter and exit animations in milliseconds.
+    pub animation_duration: Option<u32>,
+    /// CSS classes of the alert, merged under its `classes` prop.
+    pub classes: AlertClasses,
+    /// Inline styles of the alert, merged under its `styles` prop.
+    pub styles: AlertStyles,
+    /// Whether alerts honor the system's reduced motion setting.
+    pub reduced_motion: Option<ReducedMotion>,
+}
+
+impl AlertDefaults {
+    /// Resolves a prop: the `value` set on the alert, else the app-wide `default`, else the
+    /// built-in default `builtin`.
+    pub fn resolve<T>(value: Option<T>, default: Option<T>, builtin: T) -> T {
+        value.or(default).unwrap_or(builtin)
+    }
+
+    /// Sets the default position.
+    pub fn position(mut self, position: Position) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    /// Sets the default auto-close timeout in milliseconds.
+    pub fn timeout(mut self, timeout: u32) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the default text of the confirm button.
+    pub fn confirm_button_text(mut self, text: impl Into<String>) -> Self {
+        self.confirm_button_text = Some(text.into());
+        self
+    }
+
+    /// Sets the default text of the cancel button.
+    pub fn cancel_button_text(mut self, text: impl Into<String>) -> Self {
+        self.cancel_button_text = Some(text.into());
+        self
+    }
+
+    /// Sets the default text of the deny button.
+    pub fn deny_button_text(mut self, text: impl Into<String>) -> Self {
+        self.deny_button_text = Some(text.into());
+        self
+    }
+
+    /// Sets the default icon type.
+    pub fn icon_type(mut self, icon_type: IconType) -> Self {
+        self.icon_type = Some(icon_type);
+        self
+    }
+
+    /// Sets whether the confirm button is shown by default.
+    pub fn show_confirm_button(mut self, show: bool) -> Self {
+        self.show_confirm_button = Some(show);
+        self
+    }
+
+    /// Sets whether the cancel button is shown by default.
+    pub fn show_cancel_button(mut self, show: bool) -> Self {
+        self.show_cancel_button = Some(show);
+        self
+    }
+
+    /// Sets whether the deny button is shown by default.
+    pub fn show_deny_button(mut self, show: bool) -> Self {
+        self.show_deny_button = Some(show);
+        self
+    }
+
+    /// Sets whether the close button is shown by default.
+    pub fn show_close_button(mut self, show: bool) -> Self {
+        self.show_close_button = Some(show);
+        self
+    }
+
+    /// Sets whether clicking the backdrop closes alerts by default.
+    pub fn close_on_backdrop_click(mut self, close: bool) -> Self {
+        self.close_on_backdrop_click = Some(close);
+        self
+    }
+
+    /// Sets whether pressing Escape closes alerts by default.
+    pub fn close_on_escape(mut self, close: bool) -> Self {
+        self.close_on_escape = Some(close);
+        self
+    }
+
+    /// Sets the element focused by default when an alert opens.
+    pub fn initial_focus(mut self, initial_focus: InitialFocus) -> Self {
+        self.initial_focus = Some(initial_focus);
+        self
+    }
+
+    /// Sets the default enter and exit animation.
+    pub fn animation(mut self, animation: AlertAnimation) -> Self {
+        self.animation = Some(animation);
+        self
+    }
+
+    /// Sets the default duration of the enter and exit animations in milliseconds.
+    pub fn animation_duration(mut self, animation_duration: u32) -> Self {
+        self.animation_duration = Some(animation_duration);
+        self
+    }
+
+    /// Sets the default CSS classes.
+    pub fn classes(mut self, classes: AlertClasses) -> Self {
+        self.classes = classes;
+        self
+    }
+
+    /// Sets the default inline styles.
+    pub fn styles(mut self, styles: AlertStyles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Sets whether alerts honor the system's reduced motion setting, e.g. from an in-app
+    /// motion setting.
+    pub fn reduced_motion(mut self, reduced_motion: ReducedMotion) -> Self {
+        self.reduced_motion = Some(reduced_motion);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Resolves the confirm button class the way the adapters do: prop, then `classes`, then
+    /// `AlertDefaults`.
+    fn confirm_button_class(
+        value: Option<&str>,
+        classes: &AlertClasses,
+        defaults: &AlertDefaults,
+    ) -> String {
+        let group = defaults
+            .classes
+            .clone()
+            .merge(classes.clone())
+            .confirm_button;
+        AlertDefaults::resolve(value.map(str::to_string), group, String::new())
+    }
+
+    #[test]
+    fn default_class_applies_when_nothing_else_is_set() {
+        let defaults =
+            AlertDefaults::default().classes(AlertClasses::default().confirm_button("btn"));
+
+        assert_eq!(
+            confirm_button_class(None, &AlertClasses::default(), &defaults),
+            "btn"
+        );
+        assert_eq!(
+            confirm_button_class(None, &AlertClasses::default(), &AlertDefaults::default()),
+            ""
+        );
+    }
+
+    #[test]
+    fn grouped_class_wins_over_the_default_class() {
+        let defaults = AlertDefaults::default()
+            .classes(AlertClasses::default().confirm_button("btn").alert("card"));
+        let classes = AlertClasses::default().confirm_button("btn btn-danger");
+
+        assert_eq!(
+            confirm_button_class(None, &classes, &defaults),
+            "btn btn-danger"
+        );
+        assert_eq!(
+            defaults.classes.merge(classes).alert.as_deref(),
+            Some("card")
+        );
+    }
+
+    #[test]
+    fn prop_class_wins_over_grouped_and_default_classes() {
+        let defaults =
+            AlertDefaults::default().classes(AlertClasses::default().confirm_button("btn"));
+        let classes = AlertClasses::default().confirm_button("btn btn-danger");
+
+        assert_eq!(
+            confirm_button_class(Some("link"), &classes, &defaults),
+            "link"
+        );
+        assert_eq!(confirm_button_class(Some(""), &classes, &defaults), "");
+    }
+
+    #[test]
+    fn grouped_style_wins_over_the_default_style() {
+        let defaults = AlertDefaults::default().styles(
+            AlertStyles::default()
+                .title("font-size: 18px;")
+                .message("font-size: 12px;"),
+        );
+        let styles = defaults
+            .styles
+            .merge(AlertStyles::default().title("font-size: 20px;"));
+
+        assert_eq!(styles.title.as_deref(), Some("font-size: 20px;"));
+        assert_eq!(styles.message.as_deref(), Some("font-size: 12px;"));
+        assert_eq!(styles.alert, None);
+    }
+}
@@ -0,0 +1,164 @@
+use super::AlertIds;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, KeyboardEvent};
+
+/// Elements that can take keyboard focus inside an alert.
+const FOCUSABLE: &str = "a[href], button:not([disabled]), input:not([disabled]), select:not([disabled]), textarea:not([disabled]), [tabindex]:not([tabindex=\"-1\"])";
+
+/// The element focused when an alert opens.
+///
+/// When the target is not rendered, such as a hidden button or a missing prompt input, the
+/// first focusable element of the alert is focused instead, or the alert box itself.
+///
+/// ```rust
+/// use alert_rs::common::{AlertIds, InitialFocus};
+///
+/// let ids = AlertIds::new("delete-files");
+///
+/// assert_eq!(InitialFocus::default().target(&ids), Some("delete-files-confirm"));
+/// assert_eq!(InitialFocus::Cancel.target(&ids), Some("delete-files-cancel"));
+/// assert_eq!(InitialFocus::None.target(&ids), Some("delete-files"));
+/// assert_eq!(InitialFocus::Input.target(&ids), None);
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub enum InitialFocus {
+    /// The confirm button.
+    #[default]
+    Confirm,
+    /// The cancel button.
+    Cancel,
+    /// The deny button.
+    Deny,
+    /// The prompt input.
+    Input,
+    /// The alert box itself, so screen readers announce it without selecting a control.
+    None,
+}
+
+impl InitialFocus {
+    /// The `id` of the element to focus among the alert's `ids`, or `None` for the prompt
+    /// input, which is found as the first `input`, `select` or `textarea` of the alert.
+    pub fn target<'a>(&self, ids: &'a AlertIds) -> Option<&'a str> {
+        match self {
+            InitialFocus::Confirm => Some(&ids.confirm_button),
+            InitialFocus::Cancel => Some(&ids.cancel_button),
+            InitialFocus::Deny => Some(&ids.deny_button),
+            InitialFocus::Input => None,
+            InitialFocus::None => Some(&ids.dialog),
+        }
+    }
+}
+
+/// Keeps keyboard focus inside an open alert.
+///
+/// [`FocusTrap::activate`] remembers the focused element, focuses the [`InitialFocus`] target
+/// and makes Tab and Shift+Tab cycle through the alert's controls. Dropping the trap removes
+/// the listener and moves focus back to the element that had it before.
+pub struct FocusTrap {
+    previous: Option<HtmlElement>,
+    listener: Option<Closure<dyn FnMut(KeyboardEvent)>>,
+}
+
+impl FocusTrap {
+    /// Traps focus inside the alert box rendered with `ids`, starting at `initial`.
+    pub fn activate(ids: &AlertIds, initial: InitialFocus) -> Self {
+        let document = web_sys::window().and_then(|win| win.document());
+        let previous = document
+            .as_ref()
+            .and_then(|document| document.active_element())
+            .and_then(|element| element.dyn_into::<HtmlElement>().ok());
+        let dialog = document
+            .as_ref()
+            .and_then(|document| document.get_element_by_id(&ids.dialog));
+        if let (Some(document), Some(dialog)) = (&document, &dialog) {
+            let target = match initial.target(ids) {
+                Some(id) => document.get_element_by_id(id),
+                None => dialog
+                    .query_selector("input, select, textarea")
+                    .ok()
+                    .flatten(),
+            };
+            match target.or_else(|| focusable(dialog).into_iter().next().map(Element::from)) {
+                Some(target) => focus(&target),
+                None => focus(dialog),
+            }
+        }
+
+        let dialog_id = ids.dialog.clone();
+        let listener = Closure::<dyn FnMut(KeyboardEvent)>::new(move |event: KeyboardEvent| {
+            if event.key() == "Tab" {
+                trap_tab(&dialog_id, &event);
+            }
+        });
+        let listener = web_sys::window().and_then(|win| {
+            win.add_event_listener_with_callback("keydown", listener.as_ref().unchecked_ref())
+                .ok()
+                .map(|_| listener)
+        });
+        Self { previous, listener }
+    }
+}
+
+impl Drop for FocusTrap {
+    fn drop(&mut self) {
+        if let (Some(win), Some(listener)) = (web_sys::window(), self.listener.take()) {
+            let _ = win
+                .remove_event_listener_with_callback("keydown", listener.as_ref().unchecked_ref());
+        }
+        if let Some(previous) = self
+            .previous
+            .take()
+            .filter(|previous| previous.is_connected())
+        {
+            focus(&previous);
+        }
+    }
+}
+
+/// Wraps Tab from the last focusable element to the first one, Shift+Tab the other way, and
+/// brings focus back into the alert when it is elsewhere.
+fn trap_tab(dialog_id: &str, event: &KeyboardEvent) {
+    let Some(document) = web_sys::window().and_then(|win| win.document()) else {
+        return;
+    };
+    let Some(dialog) = document.get_element_by_id(dialog_id) else {
+        return;
+    };
+    let focusable = focusable(&dialog);
+    let active = document.active_element();
+    let index = active.as_ref().and_then(|active| {
+        focusable
+            .iter()
+            .position(|element| element.unchecked_ref::<Element>() == active)
+    });
+    let target = match (event.shift_key(), index) {
+        (true, Some(0) | None) => focusable.last(),
+        (false, None) => focusable.first(),
+        (false, Some(index)) if index + 1 == focusable.len() => focusable.first(),
+        _ => return,
+    };
+    event.prevent_default();
+    match target {
+        Some(target) => focus(target),
+        None => focus(&dialog),
+    }
+}
+
+/// The focusable elements of `dialog`, in document order.
+fn focusable(dialog: &Element) -> Vec<HtmlElement> {
+    let Ok(nodes) = dialog.query_selector_all(FOCUSABLE) else {
+        return Vec::new();
+    };
+    (0..nodes.length())
+        .filter_map(|index| nodes.item(index))
+        .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+        .collect()
+}
+
+/// Focuses `element` when it can take focus.
+fn focus(element: &Element) {
+    if let Some(element) = element.dyn_ref::<HtmlElement>() {
+        let _ = element.focus();
+    }
+}
@@ -0,0 +1,213 @@
+use super::IconType;
+
+/// The source of an [`IconType::Custom`] icon.
+///
+/// Paths and URLs are owned, so they can come from runtime data such as a server response.
+///
+/// ```rust
+/// use alert_rs::common::CustomIcon;
+/// use alert_rs::IconType;
+///
+/// // A Font Awesome bell, drawn like the built-in icons with `icon_color` and `icon_width`.
+/// let bell = IconType::Custom(CustomIcon::Svg {
+///     path: "M224 0c-17.7 0-32 14.3-32 32v19.2C119 66 64 130.6 64 208v18.8c0 47-17.3 92.4-48.5 127.6l-7.4 8.3c-8.4 9.4-10.4 22.9-5.3 34.4S19.4 416 32 416h384c12.6 0 24-7.4 29.2-18.9s3.1-25-5.3-34.4l-7.4-8.3c-31.2-35.2-48.5-80.5-48.5-127.6V208c0-77.4-55-142-128-156.8V32c0-17.7-14.3-32-32-32zm45.3 493.3c12-12 18.7-28.3 18.7-45.3H160c0 17 6.7 33.3 18.7 45.3S207 512 224 512s33.3-6.7 45.3-18.7z".into(),
+///     view_box: "0 0 448 512".into(),
+/// });
+/// let logo = IconType::Custom(CustomIcon::Image(format!("/assets/{}.png", "logo")));
+///
+/// assert_eq!(bell.default_color(), "currentColor");
+/// assert_ne!(logo, IconType::None);
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum CustomIcon {
+    /// An SVG `path` drawn in a `viewBox`, filled with `icon_color`.
+    Svg { path: String, view_box: String },
+    /// An image URL, rendered as an `<img>` of `icon_width`.
+    Image(String),
+    /// The framework node passed to the alert's `icon` prop.
+    Node,
+}
+
+/// The geometry of an SVG icon: a single `path` drawn in a `viewBox`.
+///
+/// The built-in icons are `const` data shared by every framework. Each one sits behind its own
+/// cargo feature (`icon-warning`, `icon-error`, `icon-success`, `icon-info` and
+/// `icon-question`, all enabled by the default `icons` feature), so apps can ship only the
+/// icons they use. A [`CustomIcon::Svg`] lends its own strings.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct IconSvg<'a> {
+    /// The `viewBox` attribute of the `<svg>`.
+    pub view_box: &'a str,
+    /// The `d` attribute of the `<path>`.
+    pub path: &'a str,
+}
+
+// SVGs taken from: https://fontawesome.com/icons
+
+/// The warning triangle. Enabled by the `icon-warning` feature.
+#[cfg(feature = "icon-warning")]
+pub const WARNING_ICON: IconSvg<'static> = IconSvg {
+    view_box: "0 0 512 512",
+    path: "M248.4 84.3c1.6-2.7 4.5-4.3 7.6-4.3s6 1.6 7.6 4.3L461.9 410c1.4 2.3 2.1 4.9 2.1 7.5c0 8-6.5 14.5-14.5 14.5H62.5c-8 0-14.5-6.5-14.5-14.5c0-2.7 .7-5.3 2.1-7.5L248.4 84.3zm-41-25L9.1 385c-6 9.8-9.1 21-9.1 32.5C0 452 28 480 62.5 480h387c34.5 0 62.5-28 62.5-62.5c0-11.5-3.2-22.7-9.1-32.5L304.6 59.3C294.3 42.4 275.9 32 256 32s-38.3 10.4-48.6 27.3zM288 368a32 32 0 1 0 -64 0 32 32 0 1 0 64 0zm-8-184c0-13.3-10.7-24-24-24s-24 10.7-24 24v96c0 13.3 10.7 24 24 24s24-10.7 24-24V184z",
+};
+
+/// The error circle with an X. Enabled by the `icon-error` feature.
+#[cfg(feature = "icon-error")]
+pub const ERROR_ICON: IconSvg<'static> = IconSvg {
+    view_box: "0 0 20 20",
+    path: "M12.71,7.291c-0.15-0.15-0.393-0.15-0.542,0L10,9.458L7.833,7.291c-0.15-0.15-0.392-0.15-0.542,0c-0.149,0.149-0.149,0.392,0,0.541L9.458,10l-2.168,2.167c-0.149,0.15-0.149,0.393,0,0.542c0.15,0.149,0.392,0.149,0.542,0L10,10.542l2.168,2.167c0.149,0.149,0.392,0.149,0.542,0c0.148-0.149,0.148-0.392,0-0.542L10.542,10l2.168-2.168C12.858,7.683,12.858,7.44,12.71,7.291z M10,1.188c-4.867,0-8.812,3.946-8.812,8.812c0,4.867,3.945,8.812,8.812,8.812s8.812-3.945,8.812-8.812C18.812,5.133,14.867,1.188,10,1.188z M10,18.046c-4.444,0-8.046-3.603-8.046-8.046c0-4.444,3.603-8.046,8.046-8.046c4.443,0,8.046,3.602,8.046,8.046C18.046,14.443,14.443,18.046,10,18.046z",
+};
+
+/// The success circle with a checkmark. Enabled by the `icon-success` feature.
+#[cfg(feature = "icon-success")]
+pub const SUCCESS_ICON: IconSvg<'static> = IconSvg {
+    view_box: "0 0 512 512",
+    path: "M256 48a208 208 0 1 1 0 416 208 208 0 1 1 0-416zm0 464A256 256 0 1 0 256 0a256 256 0 1 0 0 512zM369 209c9.4-9.4 9.4-24.6 0-33.9s-24.6-9.4-33.9 0l-111 111-47-47c-9.4-9.4-24.6-9.4-33.9 0s-9.4 24.6 0 33.9l64 64c9.4 9.4 24.6 9.4 33.9 0L369 209z",
+};
+
+/// The info circle with an "i". Enabled by the `icon-info` feature.
+#[cfg(feature = "icon-info")]
+pub const INFO_ICON: IconSvg<'static> = IconSvg {
+    view_box: "0 0 16 16",
+    path: "M8 15A7 7 0 1 1 8 1a7 7 0 0 1 0 14zm0 1A8 8 0 1 0 8 0a8 8 0 0 0 0 16zM8.93 6.588-2.29.287-.082.38.45.083c.294.07.352.176.288.469l-.738 3.468c-.194.897.105 1.319.808 1.319.545 0 1.178-.252 1.465-.598l.088-.416c-.2.176-.492.246-.686.246-.275 0-.375-.193-.304-.533L8.93 6.588zM9 4.5a1 1 0 1 1-2 0 1 1 0 0 1 2 0z",
+};
+
+/// The question circle with a "?". Enabled by the `icon-question` feature.
+#[cfg(feature = "icon-question")]
+pub const QUESTION_ICON: IconSvg<'static> = IconSvg {
+    view_box: "0 0 16 16",
+    path: "M8 15A7 7 0 1 1 8 1a7 7 0 0 1 0 14zm0 1A8 8 0 1 0 8 0a8 8 0 0 0 0 16zM5.255 5.786a.237.237 0 0 0 .241.247h.825c.138 0 .248-.113.266-.25.09-.656.54-1.134 1.342-1.134.686 0 1.314.343 1.314 1.168 0 .635-.374.927-.965 1.371-.673.489-1.206 1.06-1.168 1.987l.003.217a.25.25 0 0 0 .25.246h.811a.25.25 0 0 0 .25-.25v-.105c0-.718.273-.927 1.01-1.486.609-.463 1.244-.977 1.244-2.056 0-1.511-1.276-2.241-2.673-2.241-1.267 0-2.655.59-2.75 2.286zm1.557 5.763c0 .533.425.927 1.01.927.609 0 1.028-.394 1.028-.927 0-.552-.42-.94-1.029-.94-.584 0-1.009.388-1.009.94z",
+};
+
+/// Rules and keyframes used by the SVGs of [`IconType::animated_svg`].
+///
+/// Strokes are drawn with `stroke-dashoffset` on shapes normalized to a `pathLength` of `100`,
+/// so the stylesheet is pure CSS and needs no JavaScript.
+pub const ANIMATED_ICON_STYLES: &str = ".alert-rs-icon { fill: none; stroke: currentColor; stroke-width: 4; stroke-linecap: round; stroke-linejoin: round; overflow: visible; } \
+.alert-rs-icon-ring, .alert-rs-icon-mark { stroke-dasharray: 100; animation: alert-rs-icon-draw 0.5s ease-out both; } \
+.alert-rs-icon-ring { transform: rotate(-90deg); transform-box: fill-box; transform-origin: center; } \
+.alert-rs-icon-mark { animation-delay: 0.4s; } \
+.alert-rs-icon-mark + .alert-rs-icon-mark { animation-delay: 0.6s; } \
+.alert-rs-icon-dot { fill: currentColor; stroke: none; transform-box: fill-box; transform-origin: center; animation: alert-rs-icon-pop 0.3s 0.6s ease-out both; } \
+.alert-rs-icon-pulse { animation: alert-rs-icon-pulse 0.75s ease-in-out infinite alternate; } \
+@keyframes alert-rs-icon-draw { from { stroke-dashoffset: 100; } to { stroke-dashoffset: 0; } } \
+@keyframes alert-rs-icon-pop { from { opacity: 0; scale: 0; } } \
+@keyframes alert-rs-icon-pulse { from { scale: 1; } to { scale: 1.08; } }";
+
+impl IconType {
+    /// The SVG of the icon: a built-in icon whose feature is enabled, or a
+    /// [`CustomIcon::Svg`]. Other icons return `None`.
+    ///
+    /// ```rust
+    /// use alert_rs::common::{CustomIcon, IconSvg};
+    /// use alert_rs::IconType;
+    ///
+    /// assert_eq!(
+    ///     IconType::Custom(CustomIcon::Svg {
+    ///         path: "M0 0h8v8H0z".into(),
+    ///         view_box: "0 0 8 8".into(),
+    ///     })
+    ///     .svg(),
+    ///     Some(IconSvg { view_box: "0 0 8 8", path: "M0 0h8v8H0z" })
+    /// );
+    /// assert_eq!(IconType::None.svg(), None);
+    /// #[cfg(feature = "icon-success")]
+    /// assert_eq!(IconType::Success.svg(), Some(alert_rs::common::SUCCESS_ICON));
+    /// #[cfg(not(feature = "icon-success"))]
+    /// assert_eq!(IconType::Success.svg(), None);
+    /// ```
+    pub fn svg(&self) -> Option<IconSvg<'_>> {
+        match self {
+            #[cfg(feature = "icon-warning")]
+            IconType::Warning => Some(WARNING_ICON),
+            #[cfg(feature = "icon-error")]
+            IconType::Error => Some(ERROR_ICON),
+            #[cfg(feature = "icon-success")]
+            IconType::Success => Some(SUCCESS_ICON),
+            #[cfg(feature = "icon-info")]
+            IconType::Info => Some(INFO_ICON),
+            #[cfg(feature = "icon-question")]
+            IconType::Question => Some(QUESTION_ICON),
+            IconType::Custom(CustomIcon::Svg { path, view_box }) => Some(IconSvg {
+                view_box: view_box.as_str(),
+                path: path.as_str(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// The animated SweetAlert-style SVG of the icon, styled by [`ANIMATED_ICON_STYLES`].
+    ///
+    /// Success draws a ring and a checkmark, error a ring and an X, warning pulses, and info
+    /// and question draw a ring around their glyph. Every framework injects this same markup.
+    /// Like [`IconType::svg`], each built-in icon is only compiled in with its `icon-*` feature.
+    /// Disabled icons, custom icons and [`IconType::None`] have no animated version and return
+    /// `None`, so adapters fall back to the static icon.
+    ///
+    /// ```rust
+    /// use alert_rs::common::IconType;
+    ///
+    /// assert_eq!(IconType::None.animated_svg("50", "red"), None);
+    ///
+    /// #[cfg(feature = "icon-success")]
+    /// {
+    ///     let svg = IconType::Success.animated_svg("50", "green").unwrap();
+    ///
+    ///     assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" class="alert-rs-icon" width="50""#));
+    ///     assert!(svg.contains(r#"style="color: green;""#));
+    ///     assert!(svg.contains(r#"class="alert-rs-icon-mark""#));
+    ///
+    ///     // Attribute values are escaped.
+    ///     assert!(IconType::Success
+    ///         .animated_svg("50", r#"red" onload="x"#)
+    ///         .is_some_and(|svg| svg.contains("red&quot;")));
+    /// }
+    /// #[cfg(not(feature = "icon-success"))]
+    /// assert_eq!(IconType::Success.animated_svg("50", "green"), None);
+    /// ```
+    pub fn animated_svg(&self, width: &str, color: &str) -> Option<String> {
+        let ring =
+            r#"<circle class="alert-rs-icon-ring" cx="40" cy="40" r="36" pathLength="100"/>"#;
+        let (class, shapes): (&str, &str) = match self {
+            #[cfg(feature = "icon-success")]
+            IconType::Success => Some((
+                "alert-rs-icon",
+                r#"<path class="alert-rs-icon-mark" d="M24 41l11 11 21-23" pathLength="100"/>"#,
+            )),
+            #[cfg(feature = "icon-error")]
+            IconType::Error => Some((
+                "alert-rs-icon",
+                r#"<path class="alert-rs-icon-mark" d="M27 27l26 26" pathLength="100"/><path class="alert-rs-icon-mark" d="M53 27l-26 26" pathLength="100"/>"#,
+            )),
+            #[cfg(feature = "icon-warning")]
+            IconType::Warning => Some((
+                "alert-rs-icon alert-rs-icon-pulse",
+                r#"<path class="alert-rs-icon-mark" d="M40 20v26" pathLength="100"/><circle class="alert-rs-icon-dot" cx="40" cy="58" r="3"/>"#,
+            )),
+            #[cfg(feature = "icon-info")]
+            IconType::Info => Some((
+                "alert-rs-icon",
+                r#"<circle class="alert-rs-icon-dot" cx="40" cy="22" r="3"/><path class="alert-rs-icon-mark" d="M40 34v26" pathLength="100"/>"#,
+            )),
+            #[cfg(feature = "icon-question")]
+            IconType::Question => Some((
+                "alert-rs-icon",
+                r#"<path class="alert-rs-icon-mark" d="M30 30a10 10 0 1 1 14 9c-3 1.5-4 3.5-4 7v2" pathLength="100"/><circle class="alert-rs-icon-dot" cx="40" cy="58" r="3"/>"#,
+            )),
+            _ => None,
+        }?;
+        Some(format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" class="{class}" width="{}" viewBox="0 0 80 80" style="color: {};" aria-hidden="true">{ring}{shapes}</svg>"#,
+            escape_attribute(width),
+            escape_attribute(color),
+        ))
+    }
+}
+
+/// Escapes a value placed inside a double-quoted attribute.
+fn escape_attribute(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+}
@@ -0,0 +1,24 @@
+/// The source an alert image shows, given the sources that already failed to load.
+///
+/// `url` is shown first, then `fallback` once `url` has failed. `None` means there is no image,
+/// or every source failed, and the image is not rendered at all rather than shown broken.
+///
+/// ```rust
+/// use alert_rs::common::image_source;
+///
+/// let url = "/assets/whats-new.png";
+/// let fallback = "/assets/placeholder.png";
+///
+/// assert_eq!(image_source(url, fallback, &[]), Some(url));
+/// assert_eq!(image_source(url, fallback, &[url.to_string()]), Some(fallback));
+/// assert_eq!(image_source(url, "", &[url.to_string()]), None);
+/// assert_eq!(image_source("", fallback, &[]), None);
+/// ```
+pub fn image_source<'a>(url: &'a str, fallback: &'a str, failed: &[String]) -> Option<&'a str> {
+    if url.is_empty() {
+        return None;
+    }
+    [url, fallback]
+        .into_iter()
+        .find(|src| !src.is_empty() && !failed.iter().any(|failed| failed == src))
+}
@@ -0,0 +1,174 @@
+use std::fmt;
+use std::sync::Arc;
+use wasm_bindgen::JsCast;
+use web_sys::Element;
+
+/// The kind of input rendered between the body and the buttons of a prompt alert.
+///
+/// ```rust
+/// use alert_rs::common::InputType;
+///
+/// assert!(InputType::default().is_none());
+/// assert_eq!(InputType::Email.html_type(), "email");
+/// assert!(InputType::Textarea.is_textarea());
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum InputType {
+    /// No input is rendered.
+    #[default]
+    None,
+    /// A single-line `<input type="text">`.
+    Text,
+    /// A multi-line `<textarea>`.
+    Textarea,
+    /// An `<input type="email">`.
+    Email,
+    /// An `<input type="password">`.
+    Password,
+    /// An `<input type="number">`.
+    Number,
+    /// An `<input type="url">`.
+    Url,
+    /// An `<input type="tel">`.
+    Tel,
+    /// A `<select>` built from the `(value, label)` input options.
+    Select,
+    /// A group of radio buttons built from the `(value, label)` input options.
+    Radio,
+    /// A single checkbox next to the input label. Its value is `"true"` or `"false"`.
+    Checkbox,
+    /// An `<input type="range">` slider.
+    Range { min: f64, max: f64, step: f64 },
+}
+
+impl InputType {
+    /// Whether no input should be rendered.
+    pub fn is_none(&self) -> bool {
+        *self == InputType::None
+    }
+
+    /// Whether the input is rendered as a `<textarea>`.
+    pub fn is_textarea(&self) -> bool {
+        *self == InputType::Textarea
+    }
+
+    /// The value of the `type` attribute of the rendered `<input>`.
+    pub fn html_type(&self) -> &'static str {
+        match self {
+            InputType::None | InputType::Text | InputType::Textarea | InputType::Select => "text",
+            InputType::Email => "email",
+            InputType::Password => "password",
+            InputType::Number => "number",
+            InputType::Url => "url",
+            InputType::Tel => "tel",
+            InputType::Radio => "radio",
+            InputType::Checkbox => "checkbox",
+            InputType::Range { .. } => "range",
+        }
+    }
+
+    /// The value the input starts with, given the `input_value` prop and the input options.
+    ///
+    /// A select without an initial value starts on its first option, a checkbox is only checked
+    /// by `"true"` and a range without an initial value starts at `min`.
+    ///
+    /// ```rust
+    /// use alert_rs::common::InputType;
+    ///
+    /// let options = [("dev", "Development"), ("prod", "Production")];
+    /// assert_eq!(InputType::Select.initial_value("", &options), "dev");
+    /// assert_eq!(InputType::Select.initial_value("prod", &options), "prod");
+    /// assert_eq!(InputType::Checkbox.initial_value("", &options), "false");
+    ///
+    /// // Options built at runtime, e.g. fetched from a server.
+    /// let regions = vec![("eu".to_string(), "Europe".to_string())];
+    /// assert_eq!(InputType::Radio.initial_value("eu", &regions), "eu");
+    ///
+    /// let volume = InputType::Range { min: 0.0, max: 100.0, step: 5.0 };
+    /// assert_eq!(volume.initial_value("", &options), "0");
+    /// ```
+    pub fn initial_value<V: AsRef<str>, L>(&self, value: &str, options: &[(V, L)]) -> String {
+        match self {
+            InputType::Select if value.is_empty() => options
+                .first()
+                .map(|(value, _)| value.as_ref().to_string())
+                .unwrap_or_default(),
+            InputType::Checkbox => (value == "true").to_string(),
+            InputType::Range { min, .. } if value.is_empty() => min.to_string(),
+            _ => value.to_string(),
+        }
+    }
+}
+
+/// Sets the prompt's `input_attributes` on `element`, the rendered prompt input.
+///
+/// A radio group passes them on to each of its radio buttons, so every button carries them and
+/// the group keeps only its own ARIA attributes.
+pub fn set_input_attributes<N: AsRef<str>, V: AsRef<str>>(
+    element: &Element,
+    attributes: &[(N, V)],
+) {
+    let targets = if element.get_attribute("role").as_deref() == Some("radiogroup") {
+        let Ok(nodes) = element.query_selector_all(r#"input[type="radio"]"#) else {
+            return;
+        };
+        (0..nodes.length())
+            .filter_map(|index| nodes.item(index))
+            .filter_map(|node| node.dyn_into::<Element>().ok())
+            .collect()
+    } else {
+        vec![element.clone()]
+    };
+    for target in targets {
+        for (name, value) in attributes {
+            let _ = target.set_attribute(name.as_ref(), value.as_ref());
+        }
+    }
+}
+
+/// A synchronous check run on the input value when an imperatively fired alert is confirmed.
+///
+/// Returning `Err(message)` keeps the alert open and shows `message` under the input.
+///
+/// ```rust
+/// use alert_rs::common::InputValidator;
+///
+/// let validator = InputValidator::new(|value| {
+///     if value.trim().is_empty() {
+///         Err("Name is required".to_string())
+///     } else {
+///         Ok(())
+///     }
+/// });
+///
+/// assert!(validator.validate("Ferris").is_ok());
+/// assert_eq!(validator.validate(" "), Err("Name is required".to_string()));
+/// ```
+#[derive(Clone)]
+pub struct InputValidator(Arc<ValidateFn>);
+
+type ValidateFn = dyn Fn(&str) -> Result<(), String> + Send + Sync;
+
+impl InputValidator {
+    /// Wraps a validation function.
+    pub fn new(validator: impl Fn(&str) -> Result<(), String> + Send + Sync + 'static) -> Self {
+        Self(Arc::new(validator))
+    }
+
+    /// Runs the validator on `value`.
+    pub fn validate(&self, value: &str) -> Result<(), String> {
+        (self.0)(value)
+    }
+}
+
+impl PartialEq for InputValidator {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for InputValidator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("InputValidator")
+    }
+}
@@ -0,0 +1,1133 @@
+use super::{AlertResult, CloseReason, InputType};
+
+/// Lifecycle states of an alert.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum AlertState {
+    /// Nothing is rendered.
+    #[default]
+    Closed,
+    /// The alert was requested and `will_open` has fired, but it is not yet shown.
+    Opening,
+    /// The alert is shown and accepts user interaction.
+    Open,
+    /// The alert was confirmed and waits for `pre_confirm` to finish.
+    Loading,
+    /// The alert was dismissed and is waiting to be hidden.
+    Closing,
+    /// The alert was hidden and stays mounted while its exit animation plays.
+    Exiting,
+}
+
+/// Inputs that drive an [`AlertMachine`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum AlertEvent {
+    /// The visibility handle was switched on.
+    Show,
+    /// The alert finished rendering (or the native dialog is about to run).
+    Shown,
+    /// The value of the prompt input changed.
+    Input(String),
+    /// The input validator rejected the value on confirm, with the message to show.
+    Invalid(String),
+    /// The `pre_confirm` future finished.
+    PreConfirmed(Result<(), String>),
+    /// The confirm button was clicked.
+    Confirm,
+    /// The deny button was clicked.
+    Deny,
+    /// The cancel button was clicked.
+    Cancel,
+    /// The close ("X") button was clicked.
+    Close,
+    /// An action button was clicked.
+    Action {
+        /// The id of the action.
+        id: String,
+        /// Whether the action closes the alert.
+        closes: bool,
+    },
+    /// The backdrop around the alert was clicked.
+    Backdrop,
+    /// The Escape key was pressed.
+    Escape,
+    /// The auto-close timer elapsed.
+    Timeout,
+    /// The alert closed without being answered, such as a `native` alert whose buttons have no
+    /// browser dialog.
+    Dismiss,
+    /// The visibility handle was switched off.
+    Hidden,
+    /// The exit animation finished.
+    Exited,
+}
+
+/// Side effects an adapter must perform after a transition.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum AlertEffect {
+    /// Emit `will_open`.
+    WillOpen,
+    /// Emit `did_open`.
+    DidOpen,
+    /// Start the auto-close timer and send [`AlertEvent::Timeout`] when it elapses.
+    StartTimer(u32),
+    /// Cancel a pending auto-close timer.
+    ClearTimer,
+    /// Run `pre_confirm` with the input value and send [`AlertEvent::PreConfirmed`] when done.
+    RunPreConfirm(Option<String>),
+    /// Show or hide the loading state: spinner on the confirm button, buttons disabled.
+    Loading(bool),
+    /// Emit `on_confirm`.
+    Confirm,
+    /// Emit `on_deny`.
+    Deny,
+    /// Emit `on_cancel`.
+    Cancel,
+    /// Emit `on_close`.
+    Close,
+    /// Emit `on_action` with the id of the clicked action.
+    Action(String),
+    /// Show the given validation message under the input, or hide it on `None`.
+    ValidationMessage(Option<String>),
+    /// Switch the visibility handle off.
+    Hide,
+    /// Keep the alert mounted, play its exit animation and send [`AlertEvent::Exited`] after
+    /// the given number of milliseconds.
+    StartExit(u32),
+    /// Remove the alert kept mounted by [`AlertEffect::StartExit`].
+    Unmount,
+    /// Emit `did_close` with the outcome of the alert.
+    DidClose(AlertResult),
+}
+
+/// Framework-agnostic alert state machine.
+///
+/// Every adapter (`yew`, `dioxus` and `leptos`) owns one machine per `Alert`, feeds it
+/// [`AlertEvent`]s and performs the returned [`AlertEffect`]s in order. Keeping the
+/// transitions here guarantees all frameworks behave identically.
+///
+/// A `timeout` of `0` disables the auto-close timer. The last [`AlertEvent::Input`] value is
+/// returned in the [`AlertResult`] when the alert is confirmed. With an animation duration,
+/// hiding the alert first plays the exit animation and `did_close` fires once it is over.
+///
+/// # Examples
+///
+/// ```rust
+/// use alert_rs::common::{
+///     AlertEffect, AlertEvent, AlertMachine, AlertResult, AlertState, CloseReason,
+/// };
+///
+/// let mut machine = AlertMachine::new(2500);
+///
+/// assert_eq!(machine.send(AlertEvent::Show), vec![AlertEffect::WillOpen]);
+/// assert_eq!(
+///     machine.send(AlertEvent::Shown),
+///     vec![AlertEffect::DidOpen, AlertEffect::StartTimer(2500)]
+/// );
+/// assert_eq!(machine.state(), AlertState::Open);
+///
+/// assert_eq!(
+///     machine.send(AlertEvent::Confirm),
+///     vec![AlertEffect::ClearTimer, AlertEffect::Confirm, AlertEffect::Hide]
+/// );
+/// assert_eq!(machine.state(), AlertState::Closing);
+///
+/// assert_eq!(
+///     machine.send(AlertEvent::Hidden),
+///     vec![
+///         AlertEffect::ClearTimer,
+///         AlertEffect::DidClose(AlertResult::new(CloseReason::Confirm))
+///     ]
+/// );
+/// assert_eq!(machine.state(), AlertState::Closed);
+/// ```
+///
+/// Events that make no sense in the current state are ignored:
+///
+/// ```rust
+/// use alert_rs::common::{AlertEvent, AlertMachine, AlertState};
+///
+/// let mut machine = AlertMachine::new(0);
+///
+/// assert!(machine.send(AlertEvent::Confirm).is_empty());
+/// assert!(machine.send(AlertEvent::Hidden).is_empty());
+///
+/// machine.send(AlertEvent::Show);
+/// assert!(machine.send(AlertEvent::Shown).len() == 1);
+/// assert!(machine.send(AlertEvent::Show).is_empty());
+/// assert_eq!(machine.state(), AlertState::Open);
+/// ```
+///
+/// Prompt values are only returned on confirm:
+///
+/// ```rust
+/// use alert_rs::common::{AlertEffect, AlertEvent, AlertMachine, CloseReason};
+///
+/// let mut machine = AlertMachine::new(0);
+/// machine.send(AlertEvent::Show);
+/// machine.send(AlertEvent::Shown);
+/// machine.send(AlertEvent::Input("Ferris".into()));
+/// machine.send(AlertEvent::Confirm);
+///
+/// let effects = machine.send(AlertEvent::Hidden);
+/// let Some(AlertEffect::DidClose(result)) = effects.last() else {
+///     panic!("expected did_close");
+/// };
+/// assert_eq!(result.reason, CloseReason::Confirm);
+/// assert_eq!(result.value.as_deref(), Some("Ferris"));
+/// ```
+///
+/// A rejected value keeps the alert open until the input changes:
+///
+/// ```rust
+/// use alert_rs::common::{AlertEffect, AlertEvent, AlertMachine, AlertState};
+///
+/// let mut machine = AlertMachine::new(0);
+/// machine.send(AlertEvent::Show);
+/// machine.send(AlertEvent::Shown);
+///
+/// assert_eq!(
+///     machine.send(AlertEvent::Invalid("Name is required".into())),
+///     vec![AlertEffect::ValidationMessage(Some("Name is required".into()))]
+/// );
+/// assert_eq!(machine.state(), AlertState::Open);
+///
+/// assert_eq!(
+///     machine.send(AlertEvent::Input("Ferris".into())),
+///     vec![AlertEffect::ValidationMessage(None)]
+/// );
+/// assert_eq!(machine.value(), Some("Ferris"));
+/// ```
+///
+/// Actions report their id, and closing ones return it in the result:
+///
+/// ```rust
+/// use alert_rs::common::{AlertEffect, AlertEvent, AlertMachine, CloseReason};
+///
+/// let mut machine = AlertMachine::new(0);
+/// machine.send(AlertEvent::Show);
+/// machine.send(AlertEvent::Shown);
+///
+/// let logs = AlertEvent::Action { id: "logs".into(), closes: false };
+/// assert_eq!(machine.send(logs), vec![AlertEffect::Action("logs".into())]);
+///
+/// let retry = AlertEvent::Action { id: "retry".into(), closes: true };
+/// assert_eq!(
+///     machine.send(retry),
+///     vec![
+///         AlertEffect::ClearTimer,
+///         AlertEffect::Action("retry".into()),
+///         AlertEffect::Hide
+///     ]
+/// );
+///
+/// let effects = machine.send(AlertEvent::Hidden);
+/// let Some(AlertEffect::DidClose(result)) = effects.last() else {
+///     panic!("expected did_close");
+/// };
+/// assert_eq!(result.reason, CloseReason::Action);
+/// assert!(result.is_action("retry"));
+/// ```
+///
+/// With `pre_confirm`, confirming waits for it in the [`AlertState::Loading`] state with the
+/// timer stopped; an error reopens the alert and restarts the timer:
+///
+/// ```rust
+/// use alert_rs::common::{AlertEffect, AlertEvent, AlertMachine, AlertState};
+///
+/// let mut machine = AlertMachine::new(2500);
+/// machine.set_pre_confirm(true);
+/// machine.send(AlertEvent::Show);
+/// machine.send(AlertEvent::Shown);
+///
+/// assert_eq!(
+///     machine.send(AlertEvent::Confirm),
+///     vec![
+///         AlertEffect::ClearTimer,
+///         AlertEffect::Loading(true),
+///         AlertEffect::RunPreConfirm(None)
+///     ]
+/// );
+/// assert_eq!(machine.state(), AlertState::Loading);
+/// assert!(machine.send(AlertEvent::Cancel).is_empty());
+///
+/// assert_eq!(
+///     machine.send(AlertEvent::PreConfirmed(Err("Server unavailable".into()))),
+///     vec![
+///         AlertEffect::Loading(false),
+///         AlertEffect::ValidationMessage(Some("Server unavailable".into())),
+///         AlertEffect::StartTimer(2500)
+///     ]
+/// );
+/// assert_eq!(machine.state(), AlertState::Open);
+///
+/// machine.send(AlertEvent::Confirm);
+/// assert_eq!(
+///     machine.send(AlertEvent::PreConfirmed(Ok(()))),
+///     vec![
+///         AlertEffect::Loading(false),
+///         AlertEffect::ClearTimer,
+///         AlertEffect::Confirm,
+///         AlertEffect::Hide
+///     ]
+/// );
+/// ```
+///
+/// With an animation duration, the alert stays mounted in [`AlertState::Exiting`] until the
+/// exit animation finishes:
+///
+/// ```rust
+/// use alert_rs::common::{
+///     AlertEffect, AlertEvent, AlertMachine, AlertResult, AlertState, CloseReason,
+/// };
+///
+/// let mut machine = AlertMachine::new(0);
+/// machine.set_animation_duration(300);
+/// machine.send(AlertEvent::Show);
+/// machine.send(AlertEvent::Shown);
+/// machine.send(AlertEvent::Cancel);
+///
+/// assert_eq!(
+///     machine.send(AlertEvent::Hidden),
+///     vec![AlertEffect::ClearTimer, AlertEffect::StartExit(300)]
+/// );
+/// assert_eq!(machine.state(), AlertState::Exiting);
+/// assert!(machine.is_visible());
+///
+/// assert_eq!(
+///     machine.send(AlertEvent::Exited),
+///     vec![
+///         AlertEffect::Unmount,
+///         AlertEffect::ClearTimer,
+///         AlertEffect::DidClose(AlertResult::new(CloseReason::Cancel))
+///     ]
+/// );
+/// assert_eq!(machine.state(), AlertState::Closed);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct AlertMachine {
+    state: AlertState,
+    timeout: u32,
+    reason: Option<CloseReason>,
+    value: Option<String>,
+    error: Option<String>,
+    action: Option<String>,
+    pre_confirm: bool,
+    animation_duration: u32,
+}
+
+impl AlertMachine {
+    /// Creates a closed machine that auto-closes after `timeout` milliseconds.
+    pub fn new(timeout: u32) -> Self {
+        Self {
+            state: AlertState::Closed,
+            timeout,
+            reason: None,
+            value: None,
+            error: None,
+            action: None,
+            pre_confirm: false,
+            animation_duration: 0,
+        }
+    }
+
+    /// The current state.
+    pub fn state(&self) -> AlertState {
+        self.state
+    }
+
+    /// The auto-close timeout in milliseconds.
+    pub fn timeout(&self) -> u32 {
+        self.timeout
+    }
+
+    /// Updates the auto-close timeout used the next time the alert opens.
+    pub fn set_timeout(&mut self, timeout: u32) {
+        self.timeout = timeout;
+    }
+
+    /// Sets whether confirming runs `pre_confirm` before the alert closes.
+    pub fn set_pre_confirm(&mut self, pre_confirm: bool) {
+        self.pre_confirm = pre_confirm;
+    }
+
+    /// Sets how long the exit animation keeps the alert mounted, in milliseconds. `0` unmounts it
+    /// as soon as it is hidden.
+    pub fn set_animation_duration(&mut self, animation_duration: u32) {
+        self.animation_duration = animation_duration;
+    }
+
+    /// The current value of the prompt input, if the alert has one.
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+
+    /// Whether the alert should currently be rendered.
+    pub fn is_visible(&self) -> bool {
+        self.state != AlertState::Closed
+    }
+
+    /// Whether [`AlertEvent::Show`] would open the alert: it is closed or only playing its exit
+    /// animation. Adapters check this so that showing an alert that is already open resets
+    /// nothing.
+    pub fn can_show(&self) -> bool {
+        matches!(self.state, AlertState::Closed | AlertState::Exiting)
+    }
+
+    /// Applies `event` and returns the effects the adapter must perform, in order.
+    pub fn send(&mut self, event: AlertEvent) -> Vec<AlertEffect> {
+        use AlertEffect as Fx;
+        use AlertEvent as Ev;
+        use AlertState as St;
+
+        match (self.state, event) {
+            (St::Closed, Ev::Show) => {
+                self.state = St::Opening;
+                self.value = None;
+                vec![Fx::WillOpen]
+            }
+            (St::Opening | St::Open, Ev::Input(value)) => {
+                self.value = Some(value);
+                match self.error.take() {
+                    Some(_) => vec![Fx::ValidationMessage(None)],
+                    None => Vec::new(),
+                }
+            }
+            (St::Open, Ev::Invalid(message)) => {
+                self.error = Some(message.clone());
+                vec![Fx::ValidationMessage(Some(message))]
+            }
+            (St::Opening, Ev::Shown) => {
+                self.state = St::Open;
+                if self.timeout > 0 {
+                    vec![Fx::DidOpen, Fx::StartTimer(self.timeout)]
+                } else {
+                    vec![Fx::DidOpen]
+                }
+            }
+            (St::Open, Ev::Confirm) if self.pre_confirm => {
+                self.state = St::Loading;
+                let mut effects = vec![Fx::ClearTimer, Fx::Loading(true)];
+                if self.error.take().is_some() {
+                    effects.push(Fx::ValidationMessage(None));
+                }
+                effects.push(Fx::RunPreConfirm(self.value.clone()));
+                effects
+            }
+            (St::Open, Ev::Confirm) => self.dismiss(CloseReason::Confirm, &[Fx::Confirm]),
+            (St::Loading, Ev::PreConfirmed(Ok(()))) => {
+                let mut effects = vec![Fx::Loading(false)];
+                effects.extend(self.dismiss(CloseReason::Confirm, &[Fx::Confirm]));
+                effects
+            }
+            (St::Loading, Ev::PreConfirmed(Err(message))) => {
+                self.state = St::Open;
+                self.error = Some(message.clone());
+                let mut effects = vec![Fx::Loading(false), Fx::ValidationMessage(Some(message))];
+                if self.timeout > 0 {
+                    effects.push(Fx::StartTimer(self.timeout));
+                }
+                effects
+            }
+            (St::Open, Ev::Deny) => self.dismiss(CloseReason::Deny, &[Fx::Deny]),
+            (St::Open, Ev::Cancel) => self.dismiss(CloseReason::Cancel, &[Fx::Cancel]),
+            (St::Open, Ev::Close) => self.dismiss(CloseReason::Close, &[Fx::Close, Fx::Cancel]),
+            (St::Open, Ev::Action { id, closes: false }) => vec![Fx::Action(id)],
+            (St::Open, Ev::Action { id, closes: true }) => {
+                self.action = Some(id.clone());
+                self.dismiss(CloseReason::Action, &[Fx::Action(id)])
+            }
+            (St::Open, Ev::Backdrop) => self.dismiss(CloseReason::Backdrop, &[Fx::Close]),
+            (St::Open, Ev::Escape) => self.dismiss(CloseReason::Escape, &[Fx::Close]),
+            (St::Open, Ev::Dismiss) => self.dismiss(CloseReason::Hidden, &[]),
+            // The timer is still running its callback, so it is only cleared once hidden.
+            (St::Open, Ev::Timeout) => {
+                self.state = St::Closing;
+                self.reason = Some(CloseReason::Timer);
+                vec![Fx::Hide]
+            }
+            (St::Opening | St::Open | St::Loading | St::Closing, Ev::Hidden)
+                if self.animation_duration > 0 =>
+            {
+                let mut effects = vec![Fx::ClearTimer];
+                if self.state == St::Loading {
+                    effects.push(Fx::Loading(false));
+                }
+                self.state = St::Exiting;
+                effects.push(Fx::StartExit(self.animation_duration));
+                effects
+            }
+            (St::Exiting, Ev::Exited) => {
+                let mut effects = vec![Fx::Unmount];
+                effects.extend(self.finish(false));
+                effects
+            }
+            // Reopened during the exit animation: close the previous alert right away.
+            (St::Exiting, Ev::Show) => {
+                let mut effects = vec![Fx::Unmount];
+                effects.extend(self.finish(false));
+                effects.extend(self.send(Ev::Show));
+                effects
+            }
+            (St::Opening | St::Open | St::Loading | St::Closing, Ev::Hidden) => {
+                let loading = self.state == St::Loading;
+                self.finish(loading)
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Closes the alert and reports its outcome.
+    fn finish(&mut self, loading: bool) -> Vec<AlertEffect> {
+        self.state = AlertState::Closed;
+        let reason = self.reason.take().unwrap_or(CloseReason::Hidden);
+        let value = self.value.take().filter(|_| reason == CloseReason::Confirm);
+        let mut effects = vec![AlertEffect::ClearTimer];
+        if loading {
+            effects.push(AlertEffect::Loading(false));
+        }
+        if self.error.take().is_some() {
+            effects.push(AlertEffect::ValidationMessage(None));
+        }
+        let action = self.action.take();
+        effects.push(AlertEffect::DidClose(AlertResult {
+            reason,
+            value,
+            action,
+        }));
+        effects
+    }
+
+    fn dismiss(&mut self, reason: CloseReason, callbacks: &[AlertEffect]) -> Vec<AlertEffect> {
+        self.state = AlertState::Closing;
+        self.reason = Some(reason);
+
+        let mut effects = vec![AlertEffect::ClearTimer];
+        effects.extend_from_slice(callbacks);
+        effects.push(AlertEffect::Hide);
+        effects
+    }
+}
+
+/// The browser dialog used when an alert runs in `native` mode.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum NativeDialog {
+    /// `window.alert()`: only acknowledges the message.
+    Alert,
+    /// `window.confirm()`: rejecting it closes the alert with the `rejected` reason.
+    Confirm { rejected: CloseReason },
+    /// `window.prompt()`: rejecting it closes the alert with the `rejected` reason.
+    Prompt { rejected: CloseReason },
+    /// No dialog is shown for this button combination.
+    None,
+}
+
+impl NativeDialog {
+    /// Picks the native dialog matching the visible buttons.
+    ///
+    /// Rejecting the dialog counts as a cancel when a cancel button is shown and as a close when
+    /// a close button is shown, so "Save / Don't save / Cancel" never discards anything by
+    /// accident. Only a dialog offering confirm and deny alone counts it as a deny.
+    ///
+    /// ```rust
+    /// use alert_rs::common::{AlertEvent, CloseReason, NativeDialog};
+    ///
+    /// let dialog = NativeDialog::for_buttons(true, false, true, false);
+    /// assert_eq!(dialog, NativeDialog::Confirm { rejected: CloseReason::Cancel });
+    /// assert_eq!(dialog.event(false), AlertEvent::Cancel);
+    ///
+    /// let dialog = NativeDialog::for_buttons(true, true, false, false);
+    /// assert_eq!(dialog.event(false), AlertEvent::Deny);
+    ///
+    /// let dialog = NativeDialog::for_buttons(true, false, false, false);
+    /// assert_eq!(dialog.event(false), AlertEvent::Confirm);
+    /// ```
+    pub fn for_buttons(confirm: bool, deny: bool, cancel: bool, close: bool) -> Self {
+        let rejected = Self::rejected(deny, cancel, close);
+        match (confirm, deny, cancel, close) {
+            (true, false, false, false) => Self::Alert,
+            (true, true, _, _) | (true, _, true, _) => Self::Confirm { rejected },
+            _ => Self::None,
+        }
+    }
+
+    /// Picks `window.prompt()` when the alert has an input, the button-based dialog otherwise.
+    ///
+    /// Rejecting the prompt follows the same precedence as [`NativeDialog::for_buttons`].
+    ///
+    /// ```rust
+    /// use alert_rs::common::{AlertEvent, CloseReason, InputType, NativeDialog};
+    ///
+    /// let dialog = NativeDialog::for_input(InputType::Text, true, false, true, false);
+    /// assert_eq!(dialog, NativeDialog::Prompt { rejected: CloseReason::Cancel });
+    /// assert_eq!(dialog.event(true), AlertEvent::Confirm);
+    ///
+    /// let dialog = NativeDialog::for_input(InputType::None, true, false, false, false);
+    /// assert_eq!(dialog, NativeDialog::Alert);
+    /// ```
+    pub fn for_input(
+        input: InputType,
+        confirm: bool,
+        deny: bool,
+        cancel: bool,
+        close: bool,
+    ) -> Self {
+        if input.is_none() {
+            return Self::for_buttons(confirm, deny, cancel, close);
+        }
+        Self::Prompt {
+            rejected: Self::rejected(deny, cancel, close),
+        }
+    }
+
+    /// Why a rejected dialog closes: cancel, then close, then deny. Without any of these
+    /// buttons, it counts as a close.
+    fn rejected(deny: bool, cancel: bool, close: bool) -> CloseReason {
+        match (deny, cancel, close) {
+            (_, true, _) => CloseReason::Cancel,
+            (_, false, true) => CloseReason::Close,
+            (true, false, false) => CloseReason::Deny,
+            (false, false, false) => CloseReason::Close,
+        }
+    }
+
+    /// Maps the dialog outcome to the event to send to the [`AlertMachine`].
+    ///
+    /// `accepted` is whether `window.confirm()` returned `true` or `window.prompt()` returned a
+    /// value, and is ignored otherwise. [`NativeDialog::None`] shows nothing, so the alert is
+    /// dismissed and closes with [`CloseReason::Hidden`].
+    pub fn event(self, accepted: bool) -> AlertEvent {
+        match self {
+            Self::Alert => AlertEvent::Confirm,
+            Self::Confirm { .. } | Self::Prompt { .. } if accepted => AlertEvent::Confirm,
+            Self::Confirm { rejected } | Self::Prompt { rejected } => match rejected {
+                CloseReason::Deny => AlertEvent::Deny,
+                CloseReason::Cancel => AlertEvent::Cancel,
+                _ => AlertEvent::Close,
+            },
+            Self::None => AlertEvent::Dismiss,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::AlertAnimation;
+    use AlertEffect as Fx;
+    use AlertEvent as Ev;
+    use AlertState as St;
+
+    /// A machine opened with `timeout`, its opening effects already consumed.
+    fn open(timeout: u32) -> AlertMachine {
+        let mut machine = AlertMachine::new(timeout);
+        machine.send(Ev::Show);
+        machine.send(Ev::Shown);
+        machine
+    }
+
+    /// A machine waiting for `pre_confirm`.
+    fn loading(timeout: u32) -> AlertMachine {
+        let mut machine = open(timeout);
+        machine.set_pre_confirm(true);
+        machine.send(Ev::Confirm);
+        machine
+    }
+
+    fn closed_with(machine: &mut AlertMachine, event: Ev) -> AlertResult {
+        machine.send(event);
+        match machine.send(Ev::Hidden).pop() {
+            Some(Fx::DidClose(result)) => result,
+            effect => panic!("expected did_close, got {effect:?}"),
+        }
+    }
+
+    #[test]
+    fn show_opens_and_resets_the_value() {
+        let mut machine = open(0);
+        machine.send(Ev::Input("stale".into()));
+        machine.send(Ev::Cancel);
+        machine.send(Ev::Hidden);
+
+        assert_eq!(machine.send(Ev::Show), vec![Fx::WillOpen]);
+        assert_eq!(machine.state(), St::Opening);
+        assert_eq!(machine.value(), None);
+    }
+
+    #[test]
+    fn shown_starts_the_timer_only_with_a_timeout() {
+        let mut machine = AlertMachine::new(2500);
+        machine.send(Ev::Show);
+        assert_eq!(
+            machine.send(Ev::Shown),
+            vec![Fx::DidOpen, Fx::StartTimer(2500)]
+        );
+        assert_eq!(machine.state(), St::Open);
+
+        let mut machine = AlertMachine::new(0);
+        machine.send(Ev::Show);
+        assert_eq!(machine.send(Ev::Shown), vec![Fx::DidOpen]);
+    }
+
+    #[test]
+    fn input_is_kept_while_opening_and_open() {
+        let mut machine = AlertMachine::new(0);
+        machine.send(Ev::Show);
+        assert!(machine.send(Ev::Input("a".into())).is_empty());
+        assert_eq!(machine.value(), Some("a"));
+
+        machine.send(Ev::Shown);
+        assert!(machine.send(Ev::Input("b".into())).is_empty());
+        assert_eq!(machine.value(), Some("b"));
+    }
+
+    #[test]
+    fn input_clears_the_validation_message() {
+        let mut machine = open(0);
+        assert_eq!(
+            machine.send(Ev::Invalid("Required".into())),
+            vec![Fx::ValidationMessage(Some("Required".into()))]
+        );
+        assert_eq!(machine.state(), St::Open);
+        assert_eq!(
+            machine.send(Ev::Input("Ferris".into())),
+            vec![Fx::ValidationMessage(None)]
+        );
+        assert!(machine.send(Ev::Input("Ferris!".into())).is_empty());
+    }
+
+    #[test]
+    fn confirm_closes_with_the_value() {
+        let mut machine = open(2500);
+        machine.send(Ev::Input("Ferris".into()));
+        assert_eq!(
+            machine.send(Ev::Confirm),
+            vec![Fx::ClearTimer, Fx::Confirm, Fx::Hide]
+        );
+        assert_eq!(machine.state(), St::Closing);
+        assert_eq!(
+            machine.send(Ev::Hidden),
+            vec![
+                Fx::ClearTimer,
+                Fx::DidClose(AlertResult {
+                    reason: CloseReason::Confirm,
+                    value: Some("Ferris".into()),
+                    action: None,
+                })
+            ]
+        );
+        assert_eq!(machine.state(), St::Closed);
+    }
+
+    #[test]
+    fn confirm_with_pre_confirm_loads_and_clears_the_validation_message() {
+        let mut machine = open(2500);
+        machine.set_pre_confirm(true);
+        machine.send(Ev::Invalid("Required".into()));
+        machine.send(Ev::Input("Ferris".into()));
+        machine.send(Ev::Invalid("Taken".into()));
+        assert_eq!(
+            machine.send(Ev::Confirm),
+            vec![
+                Fx::ClearTimer,
+                Fx::Loading(true),
+                Fx::ValidationMessage(None),
+                Fx::RunPreConfirm(Some("Ferris".into()))
+            ]
+        );
+        assert_eq!(machine.state(), St::Loading);
+    }
+
+    #[test]
+    fn pre_confirmed_ok_closes_as_confirmed() {
+        let mut machine = loading(2500);
+        assert_eq!(
+            machine.send(Ev::PreConfirmed(Ok(()))),
+            vec![Fx::Loading(false), Fx::ClearTimer, Fx::Confirm, Fx::Hide]
+        );
+        assert_eq!(machine.state(), St::Closing);
+    }
+
+    #[test]
+    fn pre_confirmed_err_reopens_and_restarts_the_timer() {
+        let mut machine = loading(2500);
+        assert_eq!(
+            machine.send(Ev::PreConfirmed(Err("Offline".into()))),
+            vec![
+                Fx::Loading(false),
+                Fx::ValidationMessage(Some("Offline".into())),
+                Fx::StartTimer(2500)
+            ]
+        );
+        assert_eq!(machine.state(), St::Open);
+
+        let mut machine = loading(0);
+        assert_eq!(
+            machine.send(Ev::PreConfirmed(Err("Offline".into()))),
+            vec![
+                Fx::Loading(false),
+                Fx::ValidationMessage(Some("Offline".into()))
+            ]
+        );
+    }
+
+    #[test]
+    fn buttons_close_with_their_reason() {
+        let cases = [
+            (Ev::Deny, vec![Fx::Deny], CloseReason::Deny),
+            (Ev::Cancel, vec![Fx::Cancel], CloseReason::Cancel),
+            (Ev::Close, vec![Fx::Close, Fx::Cancel], CloseReason::Close),
+            (Ev::Backdrop, vec![Fx::Close], CloseReason::Backdrop),
+            (Ev::Escape, vec![Fx::Close], CloseReason::Escape),
+            (Ev::Dismiss, vec![], CloseReason::Hidden),
+        ];
+        for (event, callbacks, reason) in cases {
+            let mut machine = open(2500);
+            machine.send(Ev::Input("Ferris".into()));
+            let mut expected = vec![Fx::ClearTimer];
+            expected.extend(callbacks);
+            expected.push(Fx::Hide);
+            assert_eq!(machine.send(event.clone()), expected, "{event:?}");
+            assert_eq!(machine.state(), St::Closing);
+
+            let mut machine = open(2500);
+            machine.send(Ev::Input("Ferris".into()));
+            assert_eq!(
+                closed_with(&mut machine, event),
+                AlertResult::new(reason),
+                "the value is only returned on confirm"
+            );
+        }
+    }
+
+    #[test]
+    fn actions_close_only_when_asked_to() {
+        let mut machine = open(2500);
+        let logs = Ev::Action {
+            id: "logs".into(),
+            closes: false,
+        };
+        assert_eq!(machine.send(logs), vec![Fx::Action("logs".into())]);
+        assert_eq!(machine.state(), St::Open);
+
+        let retry = Ev::Action {
+            id: "retry".into(),
+            closes: true,
+        };
+        assert_eq!(
+            machine.send(retry),
+            vec![Fx::ClearTimer, Fx::Action("retry".into()), Fx::Hide]
+        );
+        assert_eq!(machine.state(), St::Closing);
+        let Some(Fx::DidClose(result)) = machine.send(Ev::Hidden).pop() else {
+            panic!("expected did_close");
+        };
+        assert_eq!(result.reason, CloseReason::Action);
+        assert_eq!(result.action.as_deref(), Some("retry"));
+    }
+
+    #[test]
+    fn timeout_hides_without_clearing_the_running_timer() {
+        let mut machine = open(2500);
+        assert_eq!(machine.send(Ev::Timeout), vec![Fx::Hide]);
+        assert_eq!(machine.state(), St::Closing);
+        assert_eq!(
+            machine.send(Ev::Hidden),
+            vec![
+                Fx::ClearTimer,
+                Fx::DidClose(AlertResult::new(CloseReason::Timer))
+            ]
+        );
+    }
+
+    #[test]
+    fn hidden_closes_from_every_visible_state() {
+        let mut opening = AlertMachine::new(0);
+        opening.send(Ev::Show);
+        for mut machine in [opening, open(0)] {
+            assert_eq!(
+                machine.send(Ev::Hidden),
+                vec![
+                    Fx::ClearTimer,
+                    Fx::DidClose(AlertResult::new(CloseReason::Hidden))
+                ]
+            );
+            assert_eq!(machine.state(), St::Closed);
+        }
+
+        let mut machine = loading(0);
+        machine.send(Ev::Invalid("ignored while loading".into()));
+        assert_eq!(
+            machine.send(Ev::Hidden),
+            vec![
+                Fx::ClearTimer,
+                Fx::Loading(false),
+                Fx::DidClose(AlertResult::new(CloseReason::Hidden))
+            ]
+        );
+
+        let mut machine = open(0);
+        machine.send(Ev::Invalid("Required".into()));
+        assert_eq!(
+            machine.send(Ev::Hidden),
+            vec![
+                Fx::ClearTimer,
+                Fx::ValidationMessage(None),
+                Fx::DidClose(AlertResult::new(CloseReason::Hidden))
+            ]
+        );
+    }
+
+    #[test]
+    fn hidden_closes_at_once_without_animation_or_with_reduced_motion() {
+        for duration in [
+            AlertAnimation::default().exit_duration(300, "", false),
+            AlertAnimation::None.exit_duration(300, "", false),
+            AlertAnimation::Fade.exit_duration(300, "", true),
+            AlertAnimation::Bounce.exit_duration(300, "animate__fadeOut", true),
+        ] {
+            let mut machine = open(0);
+            machine.set_animation_duration(duration);
+            machine.send(Ev::Confirm);
+            let effects = machine.send(Ev::Hidden);
+            assert!(!effects
+                .iter()
+                .any(|effect| matches!(effect, Fx::StartExit(_))));
+            assert!(matches!(effects.last(), Some(Fx::DidClose(_))));
+            assert_eq!(machine.state(), St::Closed);
+        }
+    }
+
+    #[test]
+    fn hidden_plays_the_exit_animation_from_every_visible_state() {
+        let mut opening = AlertMachine::new(0);
+        opening.send(Ev::Show);
+        let mut closing = open(0);
+        closing.send(Ev::Cancel);
+        for mut machine in [opening, open(0), closing] {
+            machine.set_animation_duration(300);
+            assert_eq!(
+                machine.send(Ev::Hidden),
+                vec![Fx::ClearTimer, Fx::StartExit(300)]
+            );
+            assert_eq!(machine.state(), St::Exiting);
+            assert!(machine.is_visible());
+        }
+
+        let mut machine = loading(0);
+        machine.set_animation_duration(300);
+        assert_eq!(
+            machine.send(Ev::Hidden),
+            vec![Fx::ClearTimer, Fx::Loading(false), Fx::StartExit(300)]
+        );
+    }
+
+    #[test]
+    fn exited_unmounts_and_reports_the_result() {
+        let mut machine = open(0);
+        machine.set_animation_duration(300);
+        machine.send(Ev::Escape);
+        machine.send(Ev::Hidden);
+        assert_eq!(
+            machine.send(Ev::Exited),
+            vec![
+                Fx::Unmount,
+                Fx::ClearTimer,
+                Fx::DidClose(AlertResult::new(CloseReason::Escape))
+            ]
+        );
+        assert_eq!(machine.state(), St::Closed);
+        assert!(!machine.is_visible());
+    }
+
+    #[test]
+    fn show_while_exiting_closes_the_previous_alert_first() {
+        let mut machine = open(0);
+        machine.set_animation_duration(300);
+        machine.send(Ev::Cancel);
+        machine.send(Ev::Hidden);
+        assert_eq!(
+            machine.send(Ev::Show),
+            vec![
+                Fx::Unmount,
+                Fx::ClearTimer,
+                Fx::DidClose(AlertResult::new(CloseReason::Cancel)),
+                Fx::WillOpen
+            ]
+        );
+        assert_eq!(machine.state(), St::Opening);
+    }
+
+    #[test]
+    fn show_while_open_resets_nothing() {
+        let mut machine = open(2500);
+        machine.send(Ev::Input("Ferris".into()));
+        machine.send(Ev::Invalid("Too short".into()));
+        assert!(!machine.can_show());
+        assert!(machine.send(Ev::Show).is_empty());
+        assert_eq!(machine.state(), St::Open);
+        assert_eq!(machine.value(), Some("Ferris"));
+        assert_eq!(
+            machine.send(Ev::Input("Ferris the crab".into())),
+            vec![Fx::ValidationMessage(None)]
+        );
+    }
+
+    #[test]
+    fn can_show_only_when_closed_or_exiting() {
+        let mut machine = AlertMachine::new(0);
+        machine.set_animation_duration(300);
+        assert!(machine.can_show());
+        machine.send(Ev::Show);
+        assert!(!machine.can_show());
+        machine.send(Ev::Shown);
+        assert!(!machine.can_show());
+        machine.send(Ev::Cancel);
+        assert!(!machine.can_show());
+        machine.send(Ev::Hidden);
+        assert_eq!(machine.state(), St::Exiting);
+        assert!(machine.can_show());
+    }
+
+    #[test]
+    fn events_out_of_place_are_ignored() {
+        let mut closed = AlertMachine::new(2500);
+        for event in [
+            Ev::Shown,
+            Ev::Input("Ferris".into()),
+            Ev::Invalid("Required".into()),
+            Ev::PreConfirmed(Ok(())),
+            Ev::Confirm,
+            Ev::Deny,
+            Ev::Cancel,
+            Ev::Close,
+            Ev::Backdrop,
+            Ev::Escape,
+            Ev::Timeout,
+            Ev::Dismiss,
+            Ev::Hidden,
+            Ev::Exited,
+        ] {
+            assert!(
+                closed.send(event.clone()).is_empty(),
+                "{event:?} while closed"
+            );
+            assert_eq!(closed.state(), St::Closed);
+        }
+
+        let mut opening = AlertMachine::new(2500);
+        opening.send(Ev::Show);
+        for event in [Ev::Show, Ev::Confirm, Ev::Escape, Ev::Timeout, Ev::Exited] {
+            assert!(
+                opening.send(event.clone()).is_empty(),
+                "{event:?} while opening"
+            );
+            assert_eq!(opening.state(), St::Opening);
+        }
+
+        let mut open = open(2500);
+        for event in [Ev::Show, Ev::Shown, Ev::PreConfirmed(Ok(())), Ev::Exited] {
+            assert!(open.send(event.clone()).is_empty(), "{event:?} while open");
+            assert_eq!(open.state(), St::Open);
+        }
+
+        let mut loading = loading(2500);
+        for event in [
+            Ev::Confirm,
+            Ev::Deny,
+            Ev::Cancel,
+            Ev::Close,
+            Ev::Backdrop,
+            Ev::Escape,
+            Ev::Timeout,
+            Ev::Dismiss,
+            Ev::Input("Ferris".into()),
+            Ev::Invalid("Required".into()),
+        ] {
+            assert!(
+                loading.send(event.clone()).is_empty(),
+                "{event:?} while loading"
+            );
+            assert_eq!(loading.state(), St::Loading);
+        }
+
+        let mut closing = self::open(2500);
+        closing.send(Ev::Confirm);
+        for event in [Ev::Confirm, Ev::Cancel, Ev::Escape, Ev::Timeout, Ev::Show] {
+            assert!(
+                closing.send(event.clone()).is_empty(),
+                "{event:?} while closing"
+            );
+            assert_eq!(closing.state(), St::Closing);
+        }
+
+        let mut exiting = self::open(0);
+        exiting.set_animation_duration(300);
+        exiting.send(Ev::Cancel);
+        exiting.send(Ev::Hidden);
+        for event in [Ev::Confirm, Ev::Escape, Ev::Timeout, Ev::Hidden] {
+            assert!(
+                exiting.send(event.clone()).is_empty(),
+                "{event:?} while exiting"
+            );
+            assert_eq!(exiting.state(), St::Exiting);
+        }
+    }
+
+    #[test]
+    fn native_rejection_prefers_cancel_then_close_then_deny() {
+        let cases = [
+            ((true, true, true), CloseReason::Cancel),
+            ((false, true, true), CloseReason::Cancel),
+            ((true, true, false), CloseReason::Cancel),
+            ((true, false, true), CloseReason::Close),
+            ((false, false, true), CloseReason::Close),
+            ((true, false, false), CloseReason::Deny),
+        ];
+        for ((deny, cancel, close), rejected) in cases {
+            // A close button alone has no `window.confirm()` counterpart.
+            if deny || cancel {
+                assert_eq!(
+                    NativeDialog::for_buttons(true, deny, cancel, close),
+                    NativeDialog::Confirm { rejected },
+                    "buttons {deny} {cancel} {close}"
+                );
+            }
+            assert_eq!(
+                NativeDialog::for_input(InputType::Text, true, deny, cancel, close),
+                NativeDialog::Prompt { rejected },
+                "prompt {deny} {cancel} {close}"
+            );
+        }
+        assert_eq!(
+            NativeDialog::for_input(InputType::Text, true, false, false, false),
+            NativeDialog::Prompt {
+                rejected: CloseReason::Close
+            }
+        );
+    }
+
+    #[test]
+    fn native_dialog_outcomes_map_to_events() {
+        assert_eq!(NativeDialog::Alert.event(false), Ev::Confirm);
+        let rejected = CloseReason::Close;
+        assert_eq!(NativeDialog::Confirm { rejected }.event(true), Ev::Confirm);
+        assert_eq!(NativeDialog::Confirm { rejected }.event(false), Ev::Close);
+        let rejected = CloseReason::Deny;
+        assert_eq!(NativeDialog::Prompt { rejected }.event(false), Ev::Deny);
+        assert_eq!(NativeDialog::None.event(true), Ev::Dismiss);
+        assert_eq!(
+            NativeDialog::for_buttons(false, false, false, true),
+            NativeDialog::None
+        );
+    }
+
+    #[test]
+    fn a_native_alert_without_a_dialog_closes_as_hidden() {
+        let mut machine = open(2500);
+        let event = NativeDialog::for_buttons(false, false, false, false).event(false);
+        assert_eq!(
+            closed_with(&mut machine, event),
+            AlertResult::new(CloseReason::Hidden)
+        );
+    }
+}
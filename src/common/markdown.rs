@@ -0,0 +1,181 @@
+use pulldown_cmark::{Event, Parser, Tag};
+
+/// A node of a Markdown `body`, ready to be rendered by any framework.
+///
+/// Raw HTML in the source is kept as [`MarkdownNode::Text`], so frameworks render it escaped
+/// instead of injecting it into the page.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum MarkdownNode {
+    /// Plain text.
+    Text(String),
+    /// Inline code, rendered as `<code>`.
+    Code(String),
+    /// A hard line break, rendered as `<br>`.
+    LineBreak,
+    /// A paragraph, rendered as `<p>`. Headings become a paragraph with strong text.
+    Paragraph(Vec<MarkdownNode>),
+    /// Bold text, rendered as `<strong>`.
+    Strong(Vec<MarkdownNode>),
+    /// Italic text, rendered as `<em>`.
+    Emphasis(Vec<MarkdownNode>),
+    /// A bulleted list, or a numbered one when `start` is set.
+    List {
+        start: Option<u64>,
+        items: Vec<MarkdownNode>,
+    },
+    /// An item of a [`MarkdownNode::List`], rendered as `<li>`.
+    ListItem(Vec<MarkdownNode>),
+    /// A link, rendered as `<a>`. `href` is `None` when the URL uses an unsafe scheme.
+    Link {
+        href: Option<String>,
+        children: Vec<MarkdownNode>,
+    },
+    /// A fenced or indented code block, rendered as `<pre><code>`.
+    CodeBlock(String),
+}
+
+/// Markdown containers that are still open while parsing.
+enum Container {
+    Paragraph,
+    Heading,
+    Strong,
+    Emphasis,
+    List(Option<u64>),
+    ListItem,
+    Link(Option<String>),
+    CodeBlock,
+    /// Containers without a node of their own, such as images or quotes, keep only their content.
+    Transparent,
+}
+
+impl Container {
+    fn from_tag(tag: Tag) -> Self {
+        match tag {
+            Tag::Paragraph => Container::Paragraph,
+            Tag::Heading { .. } => Container::Heading,
+            Tag::Strong => Container::Strong,
+            Tag::Emphasis => Container::Emphasis,
+            Tag::List(start) => Container::List(start),
+            Tag::Item => Container::ListItem,
+            Tag::Link { dest_url, .. } => Container::Link(safe_href(&dest_url)),
+            Tag::CodeBlock(_) => Container::CodeBlock,
+            _ => Container::Transparent,
+        }
+    }
+
+    fn close(self, children: Vec<MarkdownNode>, parent: &mut Vec<MarkdownNode>) {
+        let node = match self {
+            Container::Paragraph => MarkdownNode::Paragraph(children),
+            Container::Heading => MarkdownNode::Paragraph(vec![MarkdownNode::Strong(children)]),
+            Container::Strong => MarkdownNode::Strong(children),
+            Container::Emphasis => MarkdownNode::Emphasis(children),
+            Container::List(start) => MarkdownNode::List {
+                start,
+                items: children,
+            },
+            Container::ListItem => MarkdownNode::ListItem(children),
+            Container::Link(href) => MarkdownNode::Link { href, children },
+            Container::CodeBlock => MarkdownNode::CodeBlock(
+                children
+                    .into_iter()
+                    .filter_map(|child| match child {
+                        MarkdownNode::Text(text) => Some(text),
+                        _ => None,
+                    })
+                    .collect(),
+            ),
+            Container::Transparent => {
+                for child in children {
+                    push(parent, child);
+                }
+                return;
+            }
+        };
+        parent.push(node);
+    }
+}
+
+/// Appends a node, merging consecutive text.
+fn push(nodes: &mut Vec<MarkdownNode>, node: MarkdownNode) {
+    match (nodes.last_mut(), node) {
+        (Some(MarkdownNode::Text(last)), MarkdownNode::Text(text)) => last.push_str(&text),
+        (_, node) => nodes.push(node),
+    }
+}
+
+/// Returns the URL when it is relative or uses the `http`, `https`, `mailto` or `tel` scheme.
+fn safe_href(url: &str) -> Option<String> {
+    let scheme_end = url.find([':', '/', '?', '#']);
+    match scheme_end {
+        Some(end) if url[end..].starts_with(':') => {
+            let scheme = url[..end].trim().to_ascii_lowercase();
+            matches!(scheme.as_str(), "http" | "https" | "mailto" | "tel").then(|| url.to_string())
+        }
+        _ => Some(url.to_string()),
+    }
+}
+
+/// Parses a Markdown `body` into nodes.
+///
+/// Supports paragraphs, headings, bold, italic, links, lists, inline code and code blocks.
+/// Raw HTML is kept as text and links with a scheme other than `http`, `https`, `mailto` or
+/// `tel` lose their `href`, so user-provided text cannot inject markup or scripts.
+///
+/// ```rust
+/// use alert_rs::common::{parse_markdown, MarkdownNode};
+///
+/// let nodes = parse_markdown("Delete **3 files**?\n\n- `a.txt`\n- [b](javascript:alert(1))");
+///
+/// assert_eq!(
+///     nodes,
+///     vec![
+///         MarkdownNode::Paragraph(vec![
+///             MarkdownNode::Text("Delete ".to_string()),
+///             MarkdownNode::Strong(vec![MarkdownNode::Text("3 files".to_string())]),
+///             MarkdownNode::Text("?".to_string()),
+///         ]),
+///         MarkdownNode::List {
+///             start: None,
+///             items: vec![
+///                 MarkdownNode::ListItem(vec![MarkdownNode::Code("a.txt".to_string())]),
+///                 MarkdownNode::ListItem(vec![MarkdownNode::Link {
+///                     href: None,
+///                     children: vec![MarkdownNode::Text("b".to_string())],
+///                 }]),
+///             ],
+///         },
+///     ]
+/// );
+///
+/// assert_eq!(
+///     parse_markdown("<script>alert(1)</script>"),
+///     vec![MarkdownNode::Text("<script>alert(1)</script>".to_string())]
+/// );
+/// ```
+pub fn parse_markdown(source: &str) -> Vec<MarkdownNode> {
+    let mut stack = vec![(Container::Transparent, Vec::new())];
+    for event in Parser::new(source) {
+        let nodes = &mut stack.last_mut().expect("the root is never closed").1;
+        match event {
+            Event::Start(tag) => stack.push((Container::from_tag(tag), Vec::new())),
+            Event::End(_) => {
+                if stack.len() > 1 {
+                    let (container, children) = stack.pop().expect("checked above");
+                    let parent = &mut stack.last_mut().expect("checked above").1;
+                    container.close(children, parent);
+                }
+            }
+            Event::Text(text)
+            | Event::Html(text)
+            | Event::InlineHtml(text)
+            | Event::InlineMath(text)
+            | Event::DisplayMath(text)
+            | Event::FootnoteReference(text) => push(nodes, MarkdownNode::Text(text.to_string())),
+            Event::Code(code) => push(nodes, MarkdownNode::Code(code.to_string())),
+            Event::SoftBreak => push(nodes, MarkdownNode::Text(" ".to_string())),
+            Event::HardBreak => push(nodes, MarkdownNode::LineBreak),
+            Event::Rule | Event::TaskListMarker(_) => {}
+        }
+    }
+    stack.pop().map(|(_, nodes)| nodes).unwrap_or_default()
+}
@@ -0,0 +1,583 @@
+use super::{
+    AlertAction, AlertAnimation, AlertClasses, AlertResult, AlertStyles, CloseReason, IconType,
+    InitialFocus, InputType, InputValidator, Position, PreConfirm, ReducedMotion,
+};
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+/// Describes an alert fired imperatively through a `use_alert()` handle.
+///
+/// Defaults match the `Alert` component props. The position, timeout, icon type, button texts
+/// and visibility, initial focus, animation and reduced motion setting are left unset, so they
+/// follow the app-wide `AlertDefaults` unless set here.
+///
+/// ```rust
+/// use alert_rs::common::AlertConfig;
+/// use alert_rs::IconType;
+///
+/// let config = AlertConfig::new("Delete this file?")
+///     .title("Are you sure?")
+///     .icon_type(IconType::Warning)
+///     .confirm_button_text("Delete")
+///     .timeout(0);
+///
+/// assert_eq!(config.body, "Delete this file?");
+/// assert_eq!(config.confirm_button_text.as_deref(), Some("Delete"));
+/// assert_eq!(config.cancel_button_text, None);
+/// ```
+///
+/// A "Save / Don't save / Cancel" dialog, resolving to [`CloseReason::Deny`] on "Don't save":
+///
+/// ```rust
+/// use alert_rs::common::AlertConfig;
+///
+/// let config = AlertConfig::new("Save your changes before closing?")
+///     .confirm_button_text("Save")
+///     .deny_button_text("Don't save")
+///     .show_deny_button(true)
+///     .timeout(0);
+///
+/// assert_eq!(config.show_deny_button, Some(true));
+/// ```
+///
+/// Any number of actions, resolving to [`CloseReason::Action`] with the clicked id:
+///
+/// ```rust
+/// use alert_rs::common::{ActionVariant, AlertAction, AlertConfig};
+///
+/// let config = AlertConfig::new("The upload failed.")
+///     .show_confirm_button(false)
+///     .show_cancel_button(false)
+///     .action(AlertAction::new("retry", "Retry"))
+///     .action(AlertAction::new("logs", "Open logs").variant(ActionVariant::Secondary))
+///     .action(AlertAction::new("dismiss", "Dismiss").variant(ActionVariant::Danger));
+///
+/// assert_eq!(config.actions.len(), 3);
+/// ```
+///
+/// A prompt resolving to the entered value on confirm, pre-filled at runtime:
+///
+/// ```rust
+/// use alert_rs::common::{AlertConfig, InputType};
+///
+/// let current_name = String::from("Ferris");
+/// let config = AlertConfig::new("What is your name?")
+///     .input(InputType::Text)
+///     .input_value(current_name)
+///     .input_placeholder("Your name")
+///     .input_attribute("maxlength", "20")
+///     .input_validator(|name| match name.is_empty() {
+///         true => Err("Please enter a name".to_string()),
+///         false => Ok(()),
+///     });
+///
+/// assert_eq!(
+///     config.input_attributes,
+///     vec![("maxlength".to_string(), "20".to_string())]
+/// );
+///
+/// assert_eq!(config.input_value, "Ferris");
+///
+/// let config = AlertConfig::new("Choose a workspace")
+///     .input(InputType::Select)
+///     .input_option("personal", "Personal")
+///     .input_option("team", "Team");
+///
+/// assert_eq!(config.input_options.len(), 2);
+/// ```
+///
+/// An image whose URL and alt text come from runtime data:
+///
+/// ```rust
+/// use alert_rs::common::AlertConfig;
+///
+/// let (name, avatar) = ("Ferris", "/avatars/ferris.png".to_string());
+/// let config = AlertConfig::new(format!("{name} joined the team"))
+///     .image_url(avatar)
+///     .image_alt(format!("Avatar of {name}"))
+///     .image_width("64");
+///
+/// assert_eq!(config.image_alt, "Avatar of Ferris");
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct AlertConfig {
+    /// The title text.
+    pub title: String,
+    /// The body text.
+    pub body: String,
+    /// The icon type, or `None` to inherit it.
+    pub icon_type: Option<IconType>,
+    /// Whether to render the animated icon instead of the static one.
+    pub animated_icon: bool,
+    /// URL of the image shown above the title. Empty for no image.
+    pub image_url: String,
+    /// Alternative text of the image.
+    pub image_alt: String,
+    /// Width of the image, such as `"400"`.
+    pub image_width: String,
+    /// Height of the image, such as `"200"`.
+    pub image_height: String,
+    /// URL shown instead of `image_url` when it fails to load.
+    pub image_fallback_url: String,
+    /// The position of the alert on the screen, or `None` to inherit it.
+    pub position: Option<Position>,
+    /// The duration in milliseconds before the alert auto-closes. `0` disables the timer.
+    pub timeout: Option<u32>,
+    /// Text for the confirm button.
+    pub confirm_button_text: Option<String>,
+    /// Text for the cancel button.
+    pub cancel_button_text: Option<String>,
+    /// Text for the deny button.
+    pub deny_button_text: Option<String>,
+    /// Whether to show the confirm button, or `None` to inherit it.
+    pub show_confirm_button: Option<bool>,
+    /// Whether to show the cancel button, or `None` to inherit it.
+    pub show_cancel_button: Option<bool>,
+    /// Whether to show the deny button, or `None` to inherit it.
+    pub show_deny_button: Option<bool>,
+    /// Whether to show the close button, or `None` to inherit it.
+    pub show_close_button: Option<bool>,
+    /// Extra buttons rendered after the confirm, deny and cancel buttons.
+    pub actions: Vec<AlertAction>,
+    /// The prompt input rendered between the body and the buttons.
+    pub input: InputType,
+    /// Placeholder of the prompt input.
+    pub input_placeholder: String,
+    /// Initial value of the prompt input.
+    pub input_value: String,
+    /// Extra HTML attributes of the prompt input, such as `("maxlength", "20")`.
+    pub input_attributes: Vec<(String, String)>,
+    /// `(value, label)` options of a select or radio input.
+    pub input_options: Vec<(String, String)>,
+    /// Label shown next to a checkbox input.
+    pub input_label: String,
+    /// Check run on the input value on confirm. A failure keeps the alert open.
+    pub input_validator: Option<InputValidator>,
+    /// Async check run on confirm, before the alert closes.
+    pub pre_confirm: Option<PreConfirm>,
+    /// CSS classes of the alert.
+    pub classes: AlertClasses,
+    /// Inline styles of the alert.
+    pub styles: AlertStyles,
+    /// The enter and exit animation, or `None` to inherit it.
+    pub animation: Option<AlertAnimation>,
+    /// Duration of the enter and exit animations in milliseconds, or `None` to inherit it.
+    pub animation_duration: Option<u32>,
+    /// Whether the alert honors the system's reduced motion setting.
+    pub reduced_motion: Option<ReducedMotion>,
+    /// The element focused when the alert opens, or `None` to inherit it.
+    pub initial_focus: Option<InitialFocus>,
+}
+
+impl Default for AlertConfig {
+    fn default() -> Self {
+        Self {
+            title: "Info".to_string(),
+            body: String::new(),
+            icon_type: None,
+            animated_icon: false,
+            image_url: String::new(),
+            image_alt: String::new(),
+            image_width: String::new(),
+            image_height: String::new(),
+            image_fallback_url: String::new(),
+            position: None,
+            timeout: None,
+            confirm_button_text: None,
+            cancel_button_text: None,
+            deny_button_text: None,
+            show_confirm_button: None,
+            show_cancel_button: None,
+            show_deny_button: None,
+            show_close_button: None,
+            actions: Vec::new(),
+            input: InputType::None,
+            input_placeholder: String::new(),
+            input_value: String::new(),
+            input_attributes: Vec::new(),
+            input_options: Vec::new(),
+            input_label: String::new(),
+            input_validator: None,
+            pre_confirm: None,
+            classes: AlertClasses::default(),
+            styles: AlertStyles::default(),
+            animation: None,
+            animation_duration: None,
+            reduced_motion: None,
+            initial_focus: None,
+        }
+    }
+}
+
+impl AlertConfig {
+    /// Creates a config with the given body and default settings.
+    pub fn new(body: impl Into<String>) -> Self {
+        Self {
+            body: body.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the title text.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Sets the icon type.
+    pub fn icon_type(mut self, icon_type: IconType) -> Self {
+        self.icon_type = Some(icon_type);
+        self
+    }
+
+    /// Sets whether to render the animated icon instead of the static one.
+    pub fn animated_icon(mut self, animated_icon: bool) -> Self {
+        self.animated_icon = animated_icon;
+        self
+    }
+
+    /// Sets the URL of the image shown above the title.
+    pub fn image_url(mut self, url: impl Into<String>) -> Self {
+        self.image_url = url.into();
+        self
+    }
+
+    /// Sets the alternative text of the image.
+    pub fn image_alt(mut self, alt: impl Into<String>) -> Self {
+        self.image_alt = alt.into();
+        self
+    }
+
+    /// Sets the width of the image.
+    pub fn image_width(mut self, width: impl Into<String>) -> Self {
+        self.image_width = width.into();
+        self
+    }
+
+    /// Sets the height of the image.
+    pub fn image_height(mut self, height: impl Into<String>) -> Self {
+        self.image_height = height.into();
+        self
+    }
+
+    /// Sets the URL shown instead of the image when it fails to load.
+    pub fn image_fallback_url(mut self, url: impl Into<String>) -> Self {
+        self.image_fallback_url = url.into();
+        self
+    }
+
+    /// Sets the position.
+    pub fn position(mut self, position: Position) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    /// Sets the auto-close timeout in milliseconds.
+    pub fn timeout(mut self, timeout: u32) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the confirm button text.
+    pub fn confirm_button_text(mut self, text: impl Into<String>) -> Self {
+        self.confirm_button_text = Some(text.into());
+        self
+    }
+
+    /// Sets the cancel button text.
+    pub fn cancel_button_text(mut self, text: impl Into<String>) -> Self {
+        self.cancel_button_text = Some(text.into());
+        self
+    }
+
+    /// Sets the deny button text.
+    pub fn deny_button_text(mut self, text: impl Into<String>) -> Self {
+        self.deny_button_text = Some(text.into());
+        self
+    }
+
+    /// Shows or hides the confirm button.
+    pub fn show_confirm_button(mut self, show: bool) -> Self {
+        self.show_confirm_button = Some(show);
+        self
+    }
+
+    /// Shows or hides the cancel button.
+    pub fn show_cancel_button(mut self, show: bool) -> Self {
+        self.show_cancel_button = Some(show);
+        self
+    }
+
+    /// Shows or hides the deny button.
+    pub fn show_deny_button(mut self, show: bool) -> Self {
+        self.show_deny_button = Some(show);
+        self
+    }
+
+    /// Shows or hides the close button.
+    pub fn show_close_button(mut self, show: bool) -> Self {
+        self.show_close_button = Some(show);
+        self
+    }
+
+    /// Adds an extra button after the confirm, deny and cancel buttons.
+    pub fn action(mut self, action: AlertAction) -> Self {
+        self.actions.push(action);
+        self
+    }
+
+    /// Sets the prompt input type.
+    pub fn input(mut self, input: InputType) -> Self {
+        self.input = input;
+        self
+    }
+
+    /// Sets the prompt input placeholder.
+    pub fn input_placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.input_placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the initial value of the prompt input.
+    pub fn input_value(mut self, value: impl Into<String>) -> Self {
+        self.input_value = value.into();
+        self
+    }
+
+    /// Adds an HTML attribute to the prompt input.
+    pub fn input_attribute(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.input_attributes.push((name.into(), value.into()));
+        self
+    }
+
+    /// Adds a `(value, label)` option to a select or radio input.
+    pub fn input_option(mut self, value: impl Into<String>, label: impl Into<String>) -> Self {
+        self.input_options.push((value.into(), label.into()));
+        self
+    }
+
+    /// Sets the label shown next to a checkbox input.
+    pub fn input_label(mut self, label: impl Into<String>) -> Self {
+        self.input_label = label.into();
+        self
+    }
+
+    /// Sets the check run on the input value on confirm.
+    pub fn input_validator(
+        mut self,
+        validator: impl Fn(&str) -> Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        self.input_validator = Some(InputValidator::new(validator));
+        self
+    }
+
+    /// Sets the async check run on confirm, before the alert closes.
+    ///
+    /// The alert shows a loading state until the returned future resolves. `Err(message)` keeps it
+    /// open and shows `message`.
+    pub fn pre_confirm<F, Fut>(mut self, pre_confirm: F) -> Self
+    where
+        F: Fn(Option<String>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), String>> + 'static,
+    {
+        self.pre_confirm = Some(PreConfirm::new(pre_confirm));
+        self
+    }
+
+    /// Sets the CSS classes of the alert.
+    pub fn classes(mut self, classes: AlertClasses) -> Self {
+        self.classes = classes;
+        self
+    }
+
+    /// Sets the inline styles of the alert.
+    pub fn styles(mut self, styles: AlertStyles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Sets the enter and exit animation.
+    pub fn animation(mut self, animation: AlertAnimation) -> Self {
+        self.animation = Some(animation);
+        self
+    }
+
+    /// Sets the duration of the enter and exit animations in milliseconds.
+    pub fn animation_duration(mut self, animation_duration: u32) -> Self {
+        self.animation_duration = Some(animation_duration);
+        self
+    }
+
+    /// Sets whether the alert honors the system's reduced motion setting.
+    pub fn reduced_motion(mut self, reduced_motion: ReducedMotion) -> Self {
+        self.reduced_motion = Some(reduced_motion);
+        self
+    }
+
+    /// Sets the element focused when the alert opens.
+    pub fn initial_focus(mut self, initial_focus: InitialFocus) -> Self {
+        self.initial_focus = Some(initial_focus);
+        self
+    }
+}
+
+#[derive(Debug, Default)]
+struct Slot {
+    result: Option<AlertResult>,
+    waker: Option<Waker>,
+}
+
+/// Resolves to the user's answer once a fired alert closes.
+///
+/// Returned by the `fire` method of each adapter's `use_alert()` handle.
+#[derive(Debug)]
+pub struct AlertFuture {
+    slot: Arc<Mutex<Slot>>,
+}
+
+impl Future for AlertFuture {
+    type Output = AlertResult;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut slot = self.slot.lock().unwrap_or_else(|e| e.into_inner());
+        match slot.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                slot.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// The sending half of an [`AlertFuture`]. Resolves as hidden when dropped unanswered.
+#[derive(Debug)]
+struct Resolver {
+    slot: Arc<Mutex<Slot>>,
+    resolved: bool,
+}
+
+impl Resolver {
+    fn resolve(&mut self, result: AlertResult) {
+        self.resolved = true;
+        let mut slot = self.slot.lock().unwrap_or_else(|e| e.into_inner());
+        slot.result = Some(result);
+        if let Some(waker) = slot.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl Drop for Resolver {
+    fn drop(&mut self) {
+        if !self.resolved {
+            self.resolve(AlertResult::new(CloseReason::Hidden));
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Entry {
+    id: u64,
+    config: AlertConfig,
+    resolver: Resolver,
+}
+
+/// First-in, first-out queue of imperatively fired alerts.
+///
+/// Only the front alert is shown. Each adapter's `AlertProvider` renders
+/// [`AlertQueue::current`] and calls [`AlertQueue::resolve`] when it closes, which completes
+/// the matching [`AlertFuture`] and moves on to the next alert.
+///
+/// ```rust
+/// use alert_rs::common::{AlertConfig, AlertQueue, AlertResult, CloseReason};
+/// use std::future::Future;
+/// use std::pin::pin;
+/// use std::sync::Arc;
+/// use std::task::{Context, Poll, Wake};
+///
+/// struct Noop;
+/// impl Wake for Noop {
+///     fn wake(self: Arc<Self>) {}
+/// }
+/// let waker = Arc::new(Noop).into();
+/// let mut cx = Context::from_waker(&waker);
+///
+/// let mut queue = AlertQueue::new();
+/// let mut first = pin!(queue.push(AlertConfig::new("first")));
+/// let mut second = pin!(queue.push(AlertConfig::new("second")));
+///
+/// assert_eq!(queue.current().unwrap().body, "first");
+/// assert!(first.as_mut().poll(&mut cx).is_pending());
+///
+/// let next = queue.resolve(AlertResult::new(CloseReason::Confirm));
+/// assert_eq!(next.unwrap().body, "second");
+/// assert_eq!(
+///     first.as_mut().poll(&mut cx),
+///     Poll::Ready(AlertResult::new(CloseReason::Confirm))
+/// );
+///
+/// drop(queue);
+/// assert_eq!(
+///     second.as_mut().poll(&mut cx),
+///     Poll::Ready(AlertResult::new(CloseReason::Hidden))
+/// );
+/// ```
+#[derive(Debug, Default)]
+pub struct AlertQueue {
+    entries: VecDeque<Entry>,
+    next_id: u64,
+}
+
+impl AlertQueue {
+    /// Creates an empty queue.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues an alert and returns a future resolving to the user's answer.
+    pub fn push(&mut self, config: AlertConfig) -> AlertFuture {
+        let slot = Arc::new(Mutex::new(Slot::default()));
+        self.entries.push_back(Entry {
+            id: self.next_id,
+            config,
+            resolver: Resolver {
+                slot: slot.clone(),
+                resolved: false,
+            },
+        });
+        self.next_id += 1;
+        AlertFuture { slot }
+    }
+
+    /// The alert that should currently be shown.
+    pub fn current(&self) -> Option<&AlertConfig> {
+        self.entries.front().map(|entry| &entry.config)
+    }
+
+    /// A unique id of the current alert, changing every time the queue advances.
+    pub fn current_id(&self) -> Option<u64> {
+        self.entries.front().map(|entry| entry.id)
+    }
+
+    /// Completes the current alert with `result` and returns the next one, if any.
+    pub fn resolve(&mut self, result: AlertResult) -> Option<&AlertConfig> {
+        if let Some(mut entry) = self.entries.pop_front() {
+            entry.resolver.resolve(result);
+        }
+        self.current()
+    }
+
+    /// The number of alerts shown or waiting.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether no alert is shown or waiting.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
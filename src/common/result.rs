@@ -0,0 +1,84 @@
+/// Why an alert closed.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum CloseReason {
+    /// The confirm button was clicked.
+    Confirm,
+    /// The cancel button was clicked.
+    Cancel,
+    /// The deny button was clicked.
+    Deny,
+    /// The close ("X") button was clicked.
+    Close,
+    /// The auto-close timer elapsed.
+    Timer,
+    /// The backdrop around the alert was clicked.
+    Backdrop,
+    /// The Escape key was pressed.
+    Escape,
+    /// A closing [`AlertAction`](super::AlertAction) was clicked; its id is in
+    /// [`AlertResult::action`].
+    Action,
+    /// The application switched the visibility handle off, or the alert was torn down.
+    Hidden,
+}
+
+/// The outcome of an alert, passed to `did_close` and returned by `use_alert().fire()`.
+///
+/// ```rust
+/// use alert_rs::common::{AlertResult, CloseReason};
+///
+/// let result = AlertResult::new(CloseReason::Escape);
+/// assert!(result.is_dismissed());
+/// assert!(!result.is_confirmed());
+/// assert_eq!(result.value, None);
+///
+/// let result = AlertResult::new(CloseReason::Deny);
+/// assert!(result.is_denied());
+/// assert!(!result.is_dismissed());
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct AlertResult {
+    /// Why the alert closed.
+    pub reason: CloseReason,
+    /// The value entered by the user, if the alert collected one.
+    pub value: Option<String>,
+    /// The id of the action that closed the alert, for [`CloseReason::Action`].
+    pub action: Option<String>,
+}
+
+impl AlertResult {
+    /// Creates a result without a value.
+    pub fn new(reason: CloseReason) -> Self {
+        Self {
+            reason,
+            value: None,
+            action: None,
+        }
+    }
+
+    /// Whether the user confirmed the alert.
+    pub fn is_confirmed(&self) -> bool {
+        self.reason == CloseReason::Confirm
+    }
+
+    /// Whether the user cancelled the alert.
+    pub fn is_cancelled(&self) -> bool {
+        self.reason == CloseReason::Cancel
+    }
+
+    /// Whether the user denied the alert.
+    pub fn is_denied(&self) -> bool {
+        self.reason == CloseReason::Deny
+    }
+
+    /// Whether the alert was closed by the action with the given id.
+    pub fn is_action(&self, id: &str) -> bool {
+        self.action.as_deref() == Some(id)
+    }
+
+    /// Whether the alert closed without an explicit answer: close button, backdrop, Escape,
+    /// timer or the application hiding it.
+    pub fn is_dismissed(&self) -> bool {
+        !self.is_confirmed() && !self.is_cancelled() && !self.is_denied() && self.action.is_none()
+    }
+}
@@ -0,0 +1,21 @@
+/// Cleans an HTML snippet with an allow-list before it is injected into the page.
+///
+/// Formatting tags such as `<b>`, `<a>`, `<ul>` or `<table>` are kept, while scripts, styles,
+/// event handler attributes and `javascript:` URLs are removed. Links get
+/// `rel="noopener noreferrer"`.
+///
+/// ```rust
+/// use alert_rs::common::sanitize_html;
+///
+/// assert_eq!(
+///     sanitize_html(r#"<b onclick="steal()">3 files</b> removed<script>alert(1)</script>"#),
+///     "<b>3 files</b> removed"
+/// );
+/// assert_eq!(
+///     sanitize_html(r#"<a href="javascript:alert(1)">Undo</a>"#),
+///     r#"<a rel="noopener noreferrer">Undo</a>"#
+/// );
+/// ```
+pub fn sanitize_html(html: &str) -> String {
+    ammonia::clean(html)
+}
@@ -0,0 +1,167 @@
+/// The inline styles of an `Alert`, grouped so they can be built once and passed around.
+///
+/// Accepted as the `styles` prop by every adapter. A flat `*_style` prop set on the same alert
+/// wins over the matching field, and unset fields fall back to the app-wide `AlertDefaults`, then
+/// to the prop's default.
+///
+/// ```rust
+/// use alert_rs::common::AlertStyles;
+///
+/// let compact = AlertStyles::default()
+///     .title("font-size: 18px;")
+///     .message("font-size: 12px;");
+/// let weight = 600;
+/// let custom = compact.merge(
+///     AlertStyles::default().title(format!("font-size: 20px; font-weight: {weight};")),
+/// );
+///
+/// assert_eq!(custom.title.as_deref(), Some("font-size: 20px; font-weight: 600;"));
+/// assert_eq!(custom.message.as_deref(), Some("font-size: 12px;"));
+/// assert_eq!(custom.alert, None);
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Default)]
+pub struct AlertStyles {
+    /// Inline styles of the overlay holding the alert, replacing `DEFAULT_ALERT_STYLE`.
+    pub alert: Option<String>,
+    /// Inline styles of the backdrop, replacing `DEFAULT_BACKDROP_STYLE`.
+    pub backdrop: Option<String>,
+    /// Inline styles of the close button, replacing `DEFAULT_CLOSE_BUTTON_STYLE`.
+    pub close_button: Option<String>,
+    /// Inline styles of the confirm button, replacing `DEFAULT_CONFIRM_BUTTON_STYLE`.
+    pub confirm_button: Option<String>,
+    /// Inline styles of the cancel button, replacing `DEFAULT_CANCEL_BUTTON_STYLE`.
+    pub cancel_button: Option<String>,
+    /// Inline styles of the deny button, replacing `DEFAULT_DENY_BUTTON_STYLE`.
+    pub deny_button: Option<String>,
+    /// Inline styles of the icon, replacing `DEFAULT_ICON_STYLE`.
+    pub icon: Option<String>,
+    /// Inline styles of the image, replacing `DEFAULT_IMAGE_STYLE`.
+    pub image: Option<String>,
+    /// Inline styles of the title, replacing `DEFAULT_TITLE_STYLE`.
+    pub title: Option<String>,
+    /// Inline styles of the separator, replacing `DEFAULT_SEPARATOR_STYLE`.
+    pub separator: Option<String>,
+    /// Inline styles of the body, replacing `DEFAULT_MESSAGE_STYLE`.
+    pub message: Option<String>,
+    /// Inline styles of the prompt input, replacing `DEFAULT_INPUT_STYLE`.
+    pub input: Option<String>,
+    /// Inline styles of the radio and checkbox labels, replacing `DEFAULT_INPUT_LABEL_STYLE`.
+    pub input_label: Option<String>,
+    /// Inline styles of the validation message, replacing `DEFAULT_VALIDATION_MESSAGE_STYLE`.
+    pub validation_message: Option<String>,
+    /// Inline styles of the loading spinner, replacing `DEFAULT_SPINNER_STYLE`.
+    pub spinner: Option<String>,
+}
+
+impl AlertStyles {
+    /// Sets the inline styles of the overlay holding the alert.
+    pub fn alert(mut self, style: impl Into<String>) -> Self {
+        self.alert = Some(style.into());
+        self
+    }
+
+    /// Sets the inline styles of the backdrop.
+    pub fn backdrop(mut self, style: impl Into<String>) -> Self {
+        self.backdrop = Some(style.into());
+        self
+    }
+
+    /// Sets the inline styles of the close button.
+    pub fn close_button(mut self, style: impl Into<String>) -> Self {
+        self.close_button = Some(style.into());
+        self
+    }
+
+    /// Sets the inline styles of the confirm button.
+    pub fn confirm_button(mut self, style: impl Into<String>) -> Self {
+        self.confirm_button = Some(style.into());
+        self
+    }
+
+    /// Sets the inline styles of the cancel button.
+    pub fn cancel_button(mut self, style: impl Into<String>) -> Self {
+        self.cancel_button = Some(style.into());
+        self
+    }
+
+    /// Sets the inline styles of the deny button.
+    pub fn deny_button(mut self, style: impl Into<String>) -> Self {
+        self.deny_button = Some(style.into());
+        self
+    }
+
+    /// Sets the inline styles of the icon.
+    pub fn icon(mut self, style: impl Into<String>) -> Self {
+        self.icon = Some(style.into());
+        self
+    }
+
+    /// Sets the inline styles of the image.
+    pub fn image(mut self, style: impl Into<String>) -> Self {
+        self.image = Some(style.into());
+        self
+    }
+
+    /// Sets the inline styles of the title.
+    pub fn title(mut self, style: impl Into<String>) -> Self {
+        self.title = Some(style.into());
+        self
+    }
+
+    /// Sets the inline styles of the separator.
+    pub fn separator(mut self, style: impl Into<String>) -> Self {
+        self.separator = Some(style.into());
+        self
+    }
+
+    /// Sets the inline styles of the body.
+    pub fn message(mut self, style: impl Into<String>) -> Self {
+        self.message = Some(style.into());
+        self
+    }
+
+    /// Sets the inline styles of the prompt input.
+    pub fn input(mut self, style: impl Into<String>) -> Self {
+        self.input = Some(style.into());
+        self
+    }
+
+    /// Sets the inline styles of the radio and checkbox labels.
+    pub fn input_label(mut self, style: impl Into<String>) -> Self {
+        self.input_label = Some(style.into());
+        self
+    }
+
+    /// Sets the inline styles of the validation message.
+    pub fn validation_message(mut self, style: impl Into<String>) -> Self {
+        self.validation_message = Some(style.into());
+        self
+    }
+
+    /// Sets the inline styles of the loading spinner.
+    pub fn spinner(mut self, style: impl Into<String>) -> Self {
+        self.spinner = Some(style.into());
+        self
+    }
+
+    /// Returns these styles with every field set in `other` replaced by its value.
+    pub fn merge(self, other: Self) -> Self {
+        Self {
+            alert: other.alert.or(self.alert),
+            backdrop: other.backdrop.or(self.backdrop),
+            close_button: other.close_button.or(self.close_button),
+            confirm_button: other.confirm_button.or(self.confirm_button),
+            cancel_button: other.cancel_button.or(self.cancel_button),
+            deny_button: other.deny_button.or(self.deny_button),
+            icon: other.icon.or(self.icon),
+            image: other.image.or(self.image),
+            title: other.title.or(self.title),
+            separator: other.separator.or(self.separator),
+            message: other.message.or(self.message),
+            input: other.input.or(self.input),
+            input_label: other.input_label.or(self.input_label),
+            validation_message: other.validation_message.or(self.validation_message),
+            spinner: other.spinner.or(self.spinner),
+        }
+    }
+}
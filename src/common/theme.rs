@@ -0,0 +1,242 @@
+/// Class set on every alert box, used by theme stylesheets.
+pub const ALERT_CLASS: &str = "alert-rs";
+
+/// Colors, sizes and fonts of the alerts, emitted as CSS custom properties.
+///
+/// The default styles read these `--alert-rs-*` variables and fall back to the light look when
+/// no theme is set, so a theme provider restyles every alert and toast at once. Every value is
+/// an owned string, so a theme can be built at runtime, for example from user settings.
+///
+/// ```rust
+/// use alert_rs::common::AlertTheme;
+///
+/// let radius = 12;
+/// let theme = AlertTheme::dark()
+///     .confirm_color("#7c3aed")
+///     .radius(format!("{radius}px"));
+///
+/// assert!(theme.css_vars().contains("--alert-rs-confirm-color: #7c3aed;"));
+/// assert!(theme.css_vars().contains("--alert-rs-radius: 12px;"));
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct AlertTheme {
+    /// Background of the alert box and toasts.
+    pub background: String,
+    /// Text color of the alert box and toasts.
+    pub color: String,
+    /// Background of the confirm button.
+    pub confirm_color: String,
+    /// Background of the cancel button.
+    pub cancel_color: String,
+    /// Background of the deny button.
+    pub deny_color: String,
+    /// Text color of the buttons.
+    pub button_text_color: String,
+    /// Border color of the prompt input.
+    pub border_color: String,
+    /// Color of the validation message.
+    pub error_color: String,
+    /// Corner radius of the alert box, buttons, inputs and toasts.
+    pub radius: String,
+    /// Padding of the alert box.
+    pub spacing: String,
+    /// Font family of the alert box.
+    pub font_family: String,
+    /// Shadow of the alert box and toasts.
+    pub shadow: String,
+    /// Color of the backdrop behind the alert.
+    pub backdrop: String,
+}
+
+impl Default for AlertTheme {
+    fn default() -> Self {
+        Self::light()
+    }
+}
+
+impl AlertTheme {
+    /// The light theme, matching the default styles.
+    pub fn light() -> Self {
+        Self {
+            background: "#fff".into(),
+            color: "#333".into(),
+            confirm_color: "green".into(),
+            cancel_color: "red".into(),
+            deny_color: "gray".into(),
+            button_text_color: "white".into(),
+            border_color: "#ccc".into(),
+            error_color: "#d33".into(),
+            radius: "5px".into(),
+            spacing: "16px".into(),
+            font_family: "inherit".into(),
+            shadow: "0 2px 8px rgba(0, 0, 0, 0.2)".into(),
+            backdrop: "rgba(0, 0, 0, 0.75)".into(),
+        }
+    }
+
+    /// The dark theme.
+    pub fn dark() -> Self {
+        Self {
+            background: "#1f2937".into(),
+            color: "#f3f4f6".into(),
+            confirm_color: "#16a34a".into(),
+            cancel_color: "#dc2626".into(),
+            deny_color: "#6b7280".into(),
+            button_text_color: "white".into(),
+            border_color: "#4b5563".into(),
+            error_color: "#f87171".into(),
+            shadow: "0 2px 8px rgba(0, 0, 0, 0.6)".into(),
+            backdrop: "rgba(0, 0, 0, 0.85)".into(),
+            ..Self::light()
+        }
+    }
+
+    /// Sets the background of the alert box and toasts.
+    pub fn background(mut self, background: impl Into<String>) -> Self {
+        self.background = background.into();
+        self
+    }
+
+    /// Sets the text color of the alert box and toasts.
+    pub fn color(mut self, color: impl Into<String>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Sets the background of the confirm button.
+    pub fn confirm_color(mut self, confirm_color: impl Into<String>) -> Self {
+        self.confirm_color = confirm_color.into();
+        self
+    }
+
+    /// Sets the background of the cancel button.
+    pub fn cancel_color(mut self, cancel_color: impl Into<String>) -> Self {
+        self.cancel_color = cancel_color.into();
+        self
+    }
+
+    /// Sets the background of the deny button.
+    pub fn deny_color(mut self, deny_color: impl Into<String>) -> Self {
+        self.deny_color = deny_color.into();
+        self
+    }
+
+    /// Sets the text color of the buttons.
+    pub fn button_text_color(mut self, button_text_color: impl Into<String>) -> Self {
+        self.button_text_color = button_text_color.into();
+        self
+    }
+
+    /// Sets the border color of the prompt input.
+    pub fn border_color(mut self, border_color: impl Into<String>) -> Self {
+        self.border_color = border_color.into();
+        self
+    }
+
+    /// Sets the color of the validation message.
+    pub fn error_color(mut self, error_color: impl Into<String>) -> Self {
+        self.error_color = error_color.into();
+        self
+    }
+
+    /// Sets the corner radius.
+    pub fn radius(mut self, radius: impl Into<String>) -> Self {
+        self.radius = radius.into();
+        self
+    }
+
+    /// Sets the padding of the alert box.
+    pub fn spacing(mut self, spacing: impl Into<String>) -> Self {
+        self.spacing = spacing.into();
+        self
+    }
+
+    /// Sets the font family of the alert box.
+    pub fn font_family(mut self, font_family: impl Into<String>) -> Self {
+        self.font_family = font_family.into();
+        self
+    }
+
+    /// Sets the shadow of the alert box and toasts.
+    pub fn shadow(mut self, shadow: impl Into<String>) -> Self {
+        self.shadow = shadow.into();
+        self
+    }
+
+    /// Sets the color of the backdrop.
+    pub fn backdrop(mut self, backdrop: impl Into<String>) -> Self {
+        self.backdrop = backdrop.into();
+        self
+    }
+
+    /// The theme as CSS custom property declarations.
+    pub fn css_vars(&self) -> String {
+        [
+            ("background", &self.background),
+            ("color", &self.color),
+            ("confirm-color", &self.confirm_color),
+            ("cancel-color", &self.cancel_color),
+            ("deny-color", &self.deny_color),
+            ("button-text-color", &self.button_text_color),
+            ("border-color", &self.border_color),
+            ("error-color", &self.error_color),
+            ("radius", &self.radius),
+            ("spacing", &self.spacing),
+            ("font-family", &self.font_family),
+            ("shadow", &self.shadow),
+            ("backdrop", &self.backdrop),
+        ]
+        .iter()
+        .map(|(name, value)| format!("--alert-rs-{name}: {value};"))
+        .collect::<Vec<_>>()
+        .join(" ")
+    }
+}
+
+/// Which [`AlertTheme`] a theme provider applies.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub enum ThemeMode {
+    /// Always the light theme.
+    Light,
+    /// Always the dark theme.
+    Dark,
+    /// The dark theme when the system prefers a dark color scheme, the light one otherwise.
+    #[default]
+    Auto,
+}
+
+impl ThemeMode {
+    /// The stylesheet a theme provider renders for this mode.
+    ///
+    /// It sets the theme variables on `:root` and styles the alert box through a zero-specificity
+    /// `:where(.alert-rs)` rule, so any `alert_class` still wins.
+    ///
+    /// ```rust
+    /// use alert_rs::common::{AlertTheme, ThemeMode};
+    ///
+    /// let (light, dark) = (AlertTheme::light(), AlertTheme::dark());
+    ///
+    /// assert!(!ThemeMode::Light.stylesheet(&light, &dark).contains("@media"));
+    /// assert!(ThemeMode::Dark.stylesheet(&light, &dark).contains(&dark.css_vars()));
+    /// assert!(ThemeMode::Auto
+    ///     .stylesheet(&light, &dark)
+    ///     .contains("@media (prefers-color-scheme: dark)"));
+    /// ```
+    pub fn stylesheet(&self, light: &AlertTheme, dark: &AlertTheme) -> String {
+        let root = match self {
+            ThemeMode::Light | ThemeMode::Auto => light,
+            ThemeMode::Dark => dark,
+        };
+        let mut css = format!(
+            ":root {{ {} }} :where(.{ALERT_CLASS}) {{ background: var(--alert-rs-background); color: var(--alert-rs-color); border-radius: var(--alert-rs-radius); padding: var(--alert-rs-spacing); box-shadow: var(--alert-rs-shadow); font-family: var(--alert-rs-font-family); }}",
+            root.css_vars()
+        );
+        if *self == ThemeMode::Auto {
+            css.push_str(&format!(
+                " @media (prefers-color-scheme: dark) {{ :root {{ {} }} }}",
+                dark.css_vars()
+            ));
+        }
+        css
+    }
+}
@@ -0,0 +1,336 @@
+use super::{IconType, Position};
+
+/// Identifier of a toast inside a [`ToastManager`].
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy)]
+pub struct ToastId(u64);
+
+impl ToastId {
+    /// The raw numeric id, unique within its manager. Useful as a render key.
+    pub fn get(self) -> u64 {
+        self.0
+    }
+}
+
+/// A single toast notification.
+///
+/// Unlike an alert, a toast does not block the page: several toasts can be visible at
+/// once, stacked per [`Position`], and each one disappears after its own `timeout`.
+///
+/// ```rust
+/// use alert_rs::common::Toast;
+/// use alert_rs::{IconType, Position};
+///
+/// let toast = Toast::new("Deleted 3 files")
+///     .title("Done")
+///     .icon_type(IconType::Success)
+///     .position(Position::BottomRight)
+///     .timeout(4000);
+///
+/// assert_eq!(toast.body, "Deleted 3 files");
+/// assert_eq!(toast.timeout, 4000);
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Toast {
+    /// The title text. Not rendered when empty.
+    pub title: String,
+    /// The body text.
+    pub body: String,
+    /// The icon type, used for the accent color.
+    pub icon_type: IconType,
+    /// The stack the toast is added to.
+    pub position: Position,
+    /// The duration in milliseconds before the toast is removed. `0` keeps it until dismissed.
+    pub timeout: u32,
+}
+
+impl Default for Toast {
+    fn default() -> Self {
+        Self {
+            title: String::new(),
+            body: String::new(),
+            icon_type: IconType::Info,
+            position: Position::TopRight,
+            timeout: 2500,
+        }
+    }
+}
+
+impl Toast {
+    /// Creates a toast with the given body and default settings.
+    pub fn new(body: impl Into<String>) -> Self {
+        Self {
+            body: body.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the title text.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Sets the icon type.
+    pub fn icon_type(mut self, icon_type: IconType) -> Self {
+        self.icon_type = icon_type;
+        self
+    }
+
+    /// Sets the stack position.
+    pub fn position(mut self, position: Position) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Sets the auto-dismiss timeout in milliseconds.
+    pub fn timeout(mut self, timeout: u32) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+/// The positions that have a toast stack before any toast is shown.
+const STACK_POSITIONS: [Position; 9] = [
+    Position::TopLeft,
+    Position::TopCenter,
+    Position::TopRight,
+    Position::LeftCenter,
+    Position::Center,
+    Position::RightCenter,
+    Position::BottomLeft,
+    Position::BottomCenter,
+    Position::BottomRight,
+];
+
+/// Framework-agnostic queue of toasts.
+///
+/// Toasts are grouped in one stack per [`Position`]. Only the oldest `max_visible` toasts of
+/// each stack are shown; the rest wait in the queue and move up as visible toasts are
+/// dismissed. Each adapter's `ToastProvider` owns a manager, renders [`ToastManager::stacks`]
+/// and starts one timer per entry of [`ToastManager::visible_timeouts`].
+///
+/// Every stack is a single live region that stays in the page while empty, so screen readers
+/// announce the toasts inserted into it instead of missing a region created along with them.
+///
+/// ```rust
+/// use alert_rs::common::{Toast, ToastManager};
+/// use alert_rs::Position;
+///
+/// let mut manager = ToastManager::new().with_max_visible(2);
+///
+/// let first = manager.push(Toast::new("one"));
+/// let second = manager.push(Toast::new("two"));
+/// let third = manager.push(Toast::new("three"));
+/// manager.push(Toast::new("elsewhere").position(Position::BottomLeft));
+///
+/// assert!(manager.is_visible(second));
+/// assert!(!manager.is_visible(third));
+/// assert_eq!(manager.queued(&Position::TopRight), 1);
+///
+/// manager.dismiss(first);
+/// assert!(manager.is_visible(third));
+///
+/// let stacks = manager.stacks();
+/// let (_, top_right) = stacks.iter().find(|(position, _)| *position == Position::TopRight).unwrap();
+/// assert_eq!(top_right.len(), 2);
+/// let count = stacks.len();
+///
+/// manager.clear();
+/// assert_eq!(manager.stacks().len(), count);
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct ToastManager {
+    toasts: Vec<(ToastId, Toast)>,
+    positions: Vec<Position>,
+    next_id: u64,
+    gap: u32,
+    max_visible: usize,
+}
+
+impl Default for ToastManager {
+    fn default() -> Self {
+        Self {
+            toasts: Vec::new(),
+            positions: STACK_POSITIONS.to_vec(),
+            next_id: 0,
+            gap: 10,
+            max_visible: 5,
+        }
+    }
+}
+
+impl ToastManager {
+    /// Creates an empty manager with a `10px` gap and five visible toasts per stack.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the gap in pixels between stacked toasts.
+    pub fn with_gap(mut self, gap: u32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Sets how many toasts each stack shows at once. `0` is treated as `1`.
+    pub fn with_max_visible(mut self, max_visible: usize) -> Self {
+        self.max_visible = max_visible.max(1);
+        self
+    }
+
+    /// The gap in pixels between stacked toasts.
+    pub fn gap(&self) -> u32 {
+        self.gap
+    }
+
+    /// How many toasts each stack shows at once.
+    pub fn max_visible(&self) -> usize {
+        self.max_visible
+    }
+
+    /// Updates the gap and max-visible count in place.
+    pub fn configure(&mut self, gap: u32, max_visible: usize) {
+        self.gap = gap;
+        self.max_visible = max_visible.max(1);
+    }
+
+    /// Adds a toast to the end of its stack and returns its id.
+    pub fn push(&mut self, toast: Toast) -> ToastId {
+        let id = ToastId(self.next_id);
+        self.next_id += 1;
+        if !self.positions.contains(&toast.position) {
+            self.positions.push(toast.position.clone());
+        }
+        self.toasts.push((id, toast));
+        id
+    }
+
+    /// Removes a toast, returning it if it was still present.
+    pub fn dismiss(&mut self, id: ToastId) -> Option<Toast> {
+        let index = self
+            .toasts
+            .iter()
+            .position(|(toast_id, _)| *toast_id == id)?;
+        Some(self.toasts.remove(index).1)
+    }
+
+    /// Removes every toast.
+    pub fn clear(&mut self) {
+        self.toasts.clear();
+    }
+
+    /// Whether the toast is still queued or visible.
+    pub fn contains(&self, id: ToastId) -> bool {
+        self.toasts.iter().any(|(toast_id, _)| *toast_id == id)
+    }
+
+    /// Looks up a toast by id.
+    pub fn get(&self, id: ToastId) -> Option<&Toast> {
+        self.toasts
+            .iter()
+            .find(|(toast_id, _)| *toast_id == id)
+            .map(|(_, toast)| toast)
+    }
+
+    /// The number of toasts, visible or queued.
+    pub fn len(&self) -> usize {
+        self.toasts.len()
+    }
+
+    /// Whether there are no toasts at all.
+    pub fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+
+    /// The visible toasts of the stack at `position`, oldest first.
+    pub fn visible(&self, position: &Position) -> Vec<(ToastId, &Toast)> {
+        self.toasts
+            .iter()
+            .filter(|(_, toast)| toast.position == *position)
+            .take(self.max_visible)
+            .map(|(id, toast)| (*id, toast))
+            .collect()
+    }
+
+    /// Whether the toast is currently shown rather than waiting in the queue.
+    pub fn is_visible(&self, id: ToastId) -> bool {
+        self.get(id)
+            .is_some_and(|toast| self.visible(&toast.position).iter().any(|(v, _)| *v == id))
+    }
+
+    /// The number of toasts waiting behind the visible ones at `position`.
+    pub fn queued(&self, position: &Position) -> usize {
+        let total = self
+            .toasts
+            .iter()
+            .filter(|(_, toast)| toast.position == *position)
+            .count();
+        total.saturating_sub(self.max_visible)
+    }
+
+    /// Every stack with its visible toasts: one per built-in position, then the custom
+    /// positions in order of first use. Stacks are kept while empty.
+    pub fn stacks(&self) -> Vec<(Position, Vec<(ToastId, &Toast)>)> {
+        self.positions
+            .iter()
+            .map(|position| (position.clone(), self.visible(position)))
+            .collect()
+    }
+
+    /// The `aria-live` politeness of the stack at `position`: `assertive` while it shows a
+    /// warning or an error, `polite` otherwise.
+    ///
+    /// ```rust
+    /// use alert_rs::common::{Toast, ToastManager};
+    /// use alert_rs::{IconType, Position};
+    ///
+    /// let mut manager = ToastManager::new();
+    /// assert_eq!(manager.stack_aria_live(&Position::TopRight), "polite");
+    ///
+    /// manager.push(Toast::new("Saved"));
+    /// let error = manager.push(Toast::new("Upload failed").icon_type(IconType::Error));
+    /// assert_eq!(manager.stack_aria_live(&Position::TopRight), "assertive");
+    ///
+    /// manager.dismiss(error);
+    /// assert_eq!(manager.stack_aria_live(&Position::TopRight), "polite");
+    /// ```
+    pub fn stack_aria_live(&self, position: &Position) -> &'static str {
+        let assertive = self
+            .visible(position)
+            .iter()
+            .any(|(_, toast)| toast.icon_type.aria_live() == "assertive");
+        if assertive {
+            "assertive"
+        } else {
+            "polite"
+        }
+    }
+
+    /// The visible toasts that auto-dismiss, with their timeouts in milliseconds.
+    ///
+    /// Adapters start a timer for every id they are not already tracking.
+    pub fn visible_timeouts(&self) -> Vec<(ToastId, u32)> {
+        self.stacks()
+            .into_iter()
+            .flat_map(|(_, toasts)| toasts)
+            .filter(|(_, toast)| toast.timeout > 0)
+            .map(|(id, toast)| (id, toast.timeout))
+            .collect()
+    }
+
+    /// Inline CSS for the container of the stack at `position`.
+    ///
+    /// Bottom stacks grow upwards, so the oldest toast always sits at the screen edge.
+    pub fn stack_style(&self, position: &Position) -> String {
+        let direction = if position.is_bottom() {
+            "column-reverse"
+        } else {
+            "column"
+        };
+        format!(
+            "position: fixed; z-index: 20; margin: 10px; display: flex; flex-direction: {}; gap: {}px; {}",
+            direction,
+            self.gap,
+            position.style()
+        )
+    }
+}
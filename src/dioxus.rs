@@ -1,8 +1,22 @@
 use crate::common::*;
 use dioxus::prelude::*;
+use gloo::events::EventListener;
 use gloo::timers::callback::Timeout;
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
 use web_sys::window;
 
+#[cfg(feature = "markdown")]
+mod markdown;
+mod queue;
+mod theme;
+mod toast;
+
+pub use queue::*;
+pub use theme::*;
+pub use toast::*;
+
 /// Properties for configuring the `Alert` component.
 ///
 /// This component supports customizable alerts with flexible behaviors, icons, buttons,
@@ -12,8 +26,32 @@ pub struct AlertProps {
     /// The body text displayed in the alert.
     ///
     /// Defaults to an empty string.
-    #[props(default = "")]
-    pub body: &'static str,
+    #[props(into, default = "".to_string())]
+    pub body: String,
+
+    /// An HTML snippet rendered below the body text, such as a legacy SweetAlert2 `html:` message.
+    ///
+    /// With the `sanitize` feature it is cleaned with an allow-list and injected as HTML. Without
+    /// it, the snippet is shown as plain text and never injected. Defaults to an empty string.
+    #[props(into, default = "".to_string())]
+    pub html_body: String,
+
+    /// Rich content rendered below the body text, such as links, lists or components.
+    ///
+    /// Combine it with `body` or leave `body` empty to replace it. Defaults to nothing.
+    pub children: Element,
+
+    /// Content rendered at the top of the alert box, above the icon.
+    ///
+    /// Defaults to nothing.
+    #[props(default = VNode::empty())]
+    pub header: Element,
+
+    /// Content rendered at the bottom of the alert box, below the buttons.
+    ///
+    /// Defaults to nothing.
+    #[props(default = VNode::empty())]
+    pub footer: Element,
 
     /// A signal controlling the visibility of the alert.
     ///
@@ -22,153 +60,263 @@ pub struct AlertProps {
 
     /// The duration in milliseconds before the alert automatically closes.
     ///
-    /// Defaults to `2500` milliseconds.
-    #[props(default = 2500)]
-    pub timeout: u32,
+    /// Defaults to the app-wide `AlertDefaults` value, or `2500` milliseconds.
+    #[props(default)]
+    pub timeout: Option<u32>,
 
     /// The title text displayed at the top of the alert.
     ///
     /// Defaults to `"Info"`.
-    #[props(default = "Info")]
-    pub title: &'static str,
+    #[props(into, default = "Info".to_string())]
+    pub title: String,
+
+    /// The `id` of the alert box, also the prefix of its title and body `id`s.
+    ///
+    /// They are referenced by `aria-labelledby` and `aria-describedby`. Defaults to an empty
+    /// string, which generates a unique `alert-rs-N` once per mounted alert.
+    #[props(default, into)]
+    pub id: String,
 
     /// The text displayed on the confirm button.
     ///
-    /// Defaults to `"Okay"`.
-    #[props(default = "Okay")]
-    pub confirm_button_text: &'static str,
+    /// Defaults to the app-wide `AlertDefaults` value, or `"Okay"`.
+    #[props(default, into)]
+    pub confirm_button_text: Option<String>,
 
     /// The text displayed on the cancel button.
     ///
-    /// Defaults to `"Cancel"`.
-    #[props(default = "Cancel")]
-    pub cancel_button_text: &'static str,
+    /// Defaults to the app-wide `AlertDefaults` value, or `"Cancel"`.
+    #[props(default, into)]
+    pub cancel_button_text: Option<String>,
+
+    /// The text displayed on the deny button.
+    ///
+    /// Defaults to the app-wide `AlertDefaults` value, or `"No"`.
+    #[props(default, into)]
+    pub deny_button_text: Option<String>,
 
     /// Whether to show the confirm button.
     ///
-    /// Defaults to `true`.
-    #[props(default = true)]
-    pub show_confirm_button: bool,
+    /// Defaults to the app-wide `AlertDefaults` value, or `true`.
+    #[props(default)]
+    pub show_confirm_button: Option<bool>,
 
     /// Whether to show the cancel button.
     ///
-    /// Defaults to `true`.
-    #[props(default = true)]
-    pub show_cancel_button: bool,
+    /// Defaults to the app-wide `AlertDefaults` value, or `true`.
+    #[props(default)]
+    pub show_cancel_button: Option<bool>,
+
+    /// Whether to show the deny button, placed between the confirm and cancel buttons.
+    ///
+    /// Defaults to the app-wide `AlertDefaults` value, or `false`.
+    #[props(default)]
+    pub show_deny_button: Option<bool>,
+
+    /// Extra buttons rendered after the confirm, deny and cancel buttons.
+    ///
+    /// Each click calls `on_action` with the id of the action, and closes the alert with
+    /// `CloseReason::Action` unless the action was built with `closes(false)`. Hide the confirm
+    /// and cancel buttons to use the actions alone. Ignored in `native` mode.
+    ///
+    /// Defaults to no actions.
+    #[props(default)]
+    pub actions: Vec<AlertAction>,
 
     /// Whether to show the close button.
     ///
-    /// Defaults to `false`.
-    #[props(default = false)]
-    pub show_close_button: bool,
+    /// Defaults to the app-wide `AlertDefaults` value, or `false`.
+    #[props(default)]
+    pub show_close_button: Option<bool>,
 
     /// The position of the alert on the screen.
     ///
-    /// Defaults to `Position::TopRight`.
-    #[props(default = Position::TopRight)]
-    pub position: Position,
+    /// Defaults to the app-wide `AlertDefaults` value, or `Position::TopRight`.
+    #[props(default)]
+    pub position: Option<Position>,
 
     /// The icon type displayed in the alert.
     ///
-    /// Defaults to `IconType::Info`.
-    #[props(default = IconType::Info)]
-    pub icon_type: IconType,
+    /// Defaults to the app-wide `AlertDefaults` value, or `IconType::Info`.
+    #[props(default)]
+    pub icon_type: Option<IconType>,
 
     /// The color of the icon.
     ///
     /// Defaults to an empty string.
-    #[props(default = "")]
-    pub icon_color: &'static str,
+    #[props(into, default = "".to_string())]
+    pub icon_color: String,
 
     /// The width of the icon in the alert.
     ///
     /// Defaults to `"50"`.
-    #[props(default = "50")]
-    pub icon_width: &'static str,
+    #[props(into, default = "50".to_string())]
+    pub icon_width: String,
 
-    /// Additional CSS classes for the alert container.
+    /// Whether to render the animated SweetAlert-style icon instead of the static one.
+    ///
+    /// The animation is pure CSS and falls back to the static icon with reduced motion.
+    /// Defaults to `false`.
+    #[props(default = false)]
+    pub animated_icon: bool,
+
+    /// The node rendered as the icon when `icon_type` is `IconType::Custom(CustomIcon::Node)`.
+    ///
+    /// Defaults to nothing.
+    #[props(default = VNode::empty())]
+    pub icon: Element,
+
+    /// URL of an image shown above the title, alongside or instead of the icon.
+    ///
+    /// The image is lazy-loaded. Defaults to an empty string, which shows no image.
+    #[props(into, default = "".to_string())]
+    pub image_url: String,
+
+    /// Alternative text of the image.
     ///
     /// Defaults to an empty string.
-    #[props(default = "")]
-    pub alert_class: &'static str,
+    #[props(into, default = "".to_string())]
+    pub image_alt: String,
+
+    /// Width of the image, such as `"400"`.
+    ///
+    /// Defaults to an empty string, which keeps the image's own width.
+    #[props(into, default = "".to_string())]
+    pub image_width: String,
+
+    /// Height of the image, such as `"200"`.
+    ///
+    /// Defaults to an empty string, which keeps the image's own height.
+    #[props(into, default = "".to_string())]
+    pub image_height: String,
+
+    /// URL of the image shown when `image_url` fails to load.
+    ///
+    /// When both fail, or no fallback is set, the image is removed. Defaults to an empty string.
+    #[props(into, default = "".to_string())]
+    pub image_fallback_url: String,
+
+    /// All CSS classes of the alert in one value.
+    ///
+    /// A flat `*_class` prop set on the alert wins over the matching field, which wins over the
+    /// app-wide `AlertDefaults` value. Defaults to no classes.
+    #[props(default)]
+    pub classes: AlertClasses,
+
+    /// All inline styles of the alert in one value.
+    ///
+    /// A flat `*_style` prop set on the alert wins over the matching field, which wins over the
+    /// app-wide `AlertDefaults` value. Defaults to no styles.
+    #[props(default)]
+    pub styles: AlertStyles,
+
+    /// Additional CSS classes for the alert container.
+    ///
+    /// Defaults to the matching `classes` field, the app-wide `AlertDefaults` value, or an empty string.
+    #[props(default, into)]
+    pub alert_class: Option<String>,
 
     /// Additional CSS classes for the icon in the alert.
     ///
-    /// Defaults to an empty string.
-    #[props(default = "")]
-    pub icon_class: &'static str,
+    /// Defaults to the matching `classes` field, the app-wide `AlertDefaults` value, or an empty string.
+    #[props(default, into)]
+    pub icon_class: Option<String>,
+
+    /// Additional CSS classes for the image in the alert.
+    ///
+    /// Defaults to the matching `classes` field, the app-wide `AlertDefaults` value, or an empty string.
+    #[props(default, into)]
+    pub image_class: Option<String>,
 
     /// Additional CSS classes for the confirm button.
     ///
-    /// Defaults to an empty string.
-    #[props(default = "")]
-    pub confirm_button_class: &'static str,
+    /// Defaults to the matching `classes` field, the app-wide `AlertDefaults` value, or an empty string.
+    #[props(default, into)]
+    pub confirm_button_class: Option<String>,
 
     /// Additional CSS classes for the cancel button.
     ///
-    /// Defaults to an empty string.
-    #[props(default = "")]
-    pub cancel_button_class: &'static str,
+    /// Defaults to the matching `classes` field, the app-wide `AlertDefaults` value, or an empty string.
+    #[props(default, into)]
+    pub cancel_button_class: Option<String>,
+
+    /// Additional CSS classes for the deny button.
+    ///
+    /// Defaults to the matching `classes` field, the app-wide `AlertDefaults` value, or an empty string.
+    #[props(default, into)]
+    pub deny_button_class: Option<String>,
 
     /// Additional CSS classes for the alert title.
     ///
-    /// Defaults to an empty string.
-    #[props(default = "")]
-    pub title_class: &'static str,
+    /// Defaults to the matching `classes` field, the app-wide `AlertDefaults` value, or an empty string.
+    #[props(default, into)]
+    pub title_class: Option<String>,
 
     /// Additional CSS classes for the alert body.
     ///
-    /// Defaults to an empty string.
-    #[props(default = "")]
-    pub body_class: &'static str,
+    /// Defaults to the matching `classes` field, the app-wide `AlertDefaults` value, or an empty string.
+    #[props(default, into)]
+    pub body_class: Option<String>,
 
     /// Inline styles for the alert container.
     ///
-    /// Defaults to `DEFAULT_ALERT_STYLE`.
-    #[props(default = DEFAULT_ALERT_STYLE)]
-    pub alert_style: &'static str,
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_ALERT_STYLE`.
+    #[props(default, into)]
+    pub alert_style: Option<String>,
 
     /// Inline styles for the close button.
     ///
-    /// Defaults to `DEFAULT_CLOSE_BUTTON_STYLE`.
-    #[props(default = DEFAULT_CLOSE_BUTTON_STYLE)]
-    pub close_button_style: &'static str,
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_CLOSE_BUTTON_STYLE`.
+    #[props(default, into)]
+    pub close_button_style: Option<String>,
 
     /// Inline styles for the confirm button.
     ///
-    /// Defaults to `DEFAULT_CONFIRM_BUTTON_STYLE`.
-    #[props(default = DEFAULT_CONFIRM_BUTTON_STYLE)]
-    pub confirm_button_style: &'static str,
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_CONFIRM_BUTTON_STYLE`.
+    #[props(default, into)]
+    pub confirm_button_style: Option<String>,
 
     /// Inline styles for the cancel button.
     ///
-    /// Defaults to `DEFAULT_CANCEL_BUTTON_STYLE`.
-    #[props(default = DEFAULT_CANCEL_BUTTON_STYLE)]
-    pub cancel_button_style: &'static str,
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_CANCEL_BUTTON_STYLE`.
+    #[props(default, into)]
+    pub cancel_button_style: Option<String>,
+
+    /// Inline styles for the deny button.
+    ///
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_DENY_BUTTON_STYLE`.
+    #[props(default, into)]
+    pub deny_button_style: Option<String>,
 
     /// Inline styles for the icon in the alert.
     ///
-    /// Defaults to `DEFAULT_ICON_STYLE`.
-    #[props(default = DEFAULT_ICON_STYLE)]
-    pub icon_style: &'static str,
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_ICON_STYLE`.
+    #[props(default, into)]
+    pub icon_style: Option<String>,
+
+    /// Inline styles for the image in the alert.
+    ///
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_IMAGE_STYLE`.
+    #[props(default, into)]
+    pub image_style: Option<String>,
 
     /// Inline styles for the alert title.
     ///
-    /// Defaults to `DEFAULT_TITLE_STYLE`.
-    #[props(default = DEFAULT_TITLE_STYLE)]
-    pub title_style: &'static str,
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_TITLE_STYLE`.
+    #[props(default, into)]
+    pub title_style: Option<String>,
 
     /// Inline styles for the separator between title and body.
     ///
-    /// Defaults to `DEFAULT_SEPARATOR_STYLE`.
-    #[props(default = DEFAULT_SEPARATOR_STYLE)]
-    pub separator_style: &'static str,
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_SEPARATOR_STYLE`.
+    #[props(default, into)]
+    pub separator_style: Option<String>,
 
     /// Inline styles for the alert body message.
     ///
-    /// Defaults to `DEFAULT_MESSAGE_STYLE`.
-    #[props(default = DEFAULT_MESSAGE_STYLE)]
-    pub message_style: &'static str,
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_MESSAGE_STYLE`.
+    #[props(default, into)]
+    pub message_style: Option<String>,
 
     /// Whether to use the native browser alert.
     ///
@@ -176,6 +324,158 @@ pub struct AlertProps {
     #[props(default = false)]
     pub native: bool,
 
+    /// The prompt input rendered between the body and the buttons.
+    ///
+    /// Its value is returned in the `AlertResult` passed to `did_close` when the alert is
+    /// confirmed. In `native` mode, `window.prompt()` is used instead.
+    ///
+    /// Defaults to `InputType::None`.
+    #[props(default)]
+    pub input: InputType,
+
+    /// Placeholder text of the prompt input.
+    ///
+    /// Defaults to an empty string.
+    #[props(default, into)]
+    pub input_placeholder: String,
+
+    /// Initial value of the prompt input.
+    ///
+    /// Defaults to an empty string.
+    #[props(default, into)]
+    pub input_value: String,
+
+    /// Extra HTML attributes of the prompt input, such as `("maxlength", "20")`.
+    ///
+    /// Defaults to no attributes.
+    #[props(default)]
+    pub input_attributes: Vec<(String, String)>,
+
+    /// The `(value, label)` options of a select or radio input.
+    ///
+    /// Defaults to no options.
+    #[props(default)]
+    pub input_options: Vec<(String, String)>,
+
+    /// The label shown next to a checkbox input.
+    ///
+    /// Defaults to an empty string.
+    #[props(default, into)]
+    pub input_label: String,
+
+    /// Inline styles for the labels of radio and checkbox inputs.
+    ///
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_INPUT_LABEL_STYLE`.
+    #[props(default, into)]
+    pub input_label_style: Option<String>,
+
+    /// Check run on the input value when the confirm button is clicked.
+    ///
+    /// Returning `Err(message)` keeps the alert open, shows `message` under the input and marks
+    /// the input with `aria-invalid`.
+    ///
+    /// Defaults to no validation.
+    #[props(default)]
+    pub input_validator: Option<Callback<String, Result<(), String>>>,
+
+    /// Async check run when the confirm button is clicked, before the alert closes.
+    ///
+    /// It receives the input value and puts the alert into a loading state: a spinner on the
+    /// confirm button, all buttons disabled and the auto-close timer stopped. `Ok` closes the
+    /// alert, `Err(message)` keeps it open and shows `message` in the validation area.
+    ///
+    /// Defaults to closing immediately.
+    #[props(default)]
+    pub pre_confirm: Option<Callback<Option<String>, PreConfirmFuture>>,
+
+    /// Inline styles for the spinner shown on the confirm button while `pre_confirm` runs.
+    ///
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_SPINNER_STYLE`.
+    #[props(default, into)]
+    pub spinner_style: Option<String>,
+
+    /// Custom CSS class for the validation message.
+    ///
+    /// Defaults to the matching `classes` field, the app-wide `AlertDefaults` value, or an empty string.
+    #[props(default, into)]
+    pub validation_message_class: Option<String>,
+
+    /// Inline styles for the validation message.
+    ///
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_VALIDATION_MESSAGE_STYLE`.
+    #[props(default, into)]
+    pub validation_message_style: Option<String>,
+
+    /// Custom CSS class for the prompt input.
+    ///
+    /// Defaults to the matching `classes` field, the app-wide `AlertDefaults` value, or an empty string.
+    #[props(default, into)]
+    pub input_class: Option<String>,
+
+    /// Inline styles for the prompt input.
+    ///
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_INPUT_STYLE`.
+    #[props(default, into)]
+    pub input_style: Option<String>,
+
+    /// Whether clicking the backdrop around the alert closes it with `CloseReason::Backdrop`.
+    ///
+    /// Defaults to the app-wide `AlertDefaults` value, or `false`.
+    #[props(default)]
+    pub close_on_backdrop_click: Option<bool>,
+
+    /// Whether pressing the Escape key closes the alert with `CloseReason::Escape`.
+    ///
+    /// Defaults to the app-wide `AlertDefaults` value, or `true`.
+    #[props(default)]
+    pub close_on_escape: Option<bool>,
+
+    /// The element focused when the alert opens.
+    ///
+    /// While the alert is open, Tab and Shift+Tab cycle through its controls, and focus moves
+    /// back to the previously focused element when it closes. Defaults to the app-wide `AlertDefaults` value, or `InitialFocus::Confirm`.
+    #[props(default)]
+    pub initial_focus: Option<InitialFocus>,
+
+    /// Inline styles for the clickable backdrop behind the alert box.
+    ///
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_BACKDROP_STYLE`.
+    #[props(default, into)]
+    pub backdrop_style: Option<String>,
+
+    /// The animation played when the alert opens and closes.
+    ///
+    /// On close, the alert stays mounted until the exit animation finishes.
+    /// Defaults to the app-wide `AlertDefaults` value, or `AlertAnimation::None`, so `did_close` fires as soon as the alert is hidden.
+    #[props(default)]
+    pub animation: Option<AlertAnimation>,
+
+    /// Duration of the enter and exit animations in milliseconds.
+    ///
+    /// Defaults to the app-wide `AlertDefaults` value, or `300`.
+    #[props(default)]
+    pub animation_duration: Option<u32>,
+
+    /// Custom CSS class added to the alert box while it opens and stays open.
+    ///
+    /// Use it to plug in animation libraries such as Animate.css. Defaults to an empty string.
+    #[props(into, default = "".to_string())]
+    pub enter_class: String,
+
+    /// Custom CSS class added to the alert box while its exit animation plays.
+    ///
+    /// The alert is kept mounted for `animation_duration`, even with `AlertAnimation::None`.
+    /// Defaults to an empty string.
+    #[props(into, default = "".to_string())]
+    pub exit_class: String,
+
+    /// Whether the alert honors the system's reduced motion setting.
+    ///
+    /// With reduced motion, animations, the animation class hooks and the spinner animation are
+    /// turned off. Defaults to the app-wide `AlertDefaults` value, or `ReducedMotion::System`, which follows `prefers-reduced-motion`.
+    #[props(default)]
+    pub reduced_motion: Option<ReducedMotion>,
+
     /// Callback triggered before the alert opens.
     ///
     /// Defaults to an empty callback.
@@ -188,11 +488,11 @@ pub struct AlertProps {
     #[props(default)]
     pub did_open: Callback<()>,
 
-    /// Callback triggered after the alert closes.
+    /// Callback triggered after the alert closes, with an `AlertResult` describing why.
     ///
     /// Defaults to an empty callback.
     #[props(default)]
-    pub did_close: Callback<()>,
+    pub did_close: Callback<AlertResult>,
 
     /// Callback triggered when the confirm button is clicked.
     ///
@@ -211,6 +511,124 @@ pub struct AlertProps {
     /// Defaults to an empty callback.
     #[props(default)]
     pub on_cancel: Callback<()>,
+
+    /// Callback triggered when the deny button is clicked.
+    ///
+    /// Defaults to an empty callback.
+    #[props(default)]
+    pub on_deny: Callback<()>,
+
+    /// Callback triggered when one of the `actions` is clicked, with its id.
+    ///
+    /// Defaults to an empty callback.
+    #[props(default)]
+    pub on_action: Callback<String>,
+}
+
+/// Performs the effects emitted by the shared [`AlertMachine`].
+#[derive(Clone)]
+struct Driver {
+    machine: Rc<RefCell<AlertMachine>>,
+    timer: Rc<RefCell<Option<Timeout>>>,
+    show_alert: Signal<bool>,
+    will_open: Callback<()>,
+    did_open: Callback<()>,
+    did_close: Callback<AlertResult>,
+    on_confirm: Callback<()>,
+    on_close: Callback<()>,
+    on_cancel: Callback<()>,
+    on_deny: Callback<()>,
+    on_action: Callback<String>,
+    input_validator: Option<Callback<String, Result<(), String>>>,
+    validation_message: Signal<Option<String>>,
+    pre_confirm: Option<Callback<Option<String>, PreConfirmFuture>>,
+    loading: Signal<bool>,
+    leaving: Signal<bool>,
+    native: bool,
+}
+
+impl Driver {
+    fn validate(&self, value: &str) -> Result<(), String> {
+        match self.input_validator {
+            Some(validator) => validator.call(value.to_string()),
+            None => Ok(()),
+        }
+    }
+
+    fn confirm(&self) {
+        let value = self.machine.borrow().value().map(str::to_string);
+        if let Some(Err(message)) = value.map(|value| self.validate(&value)) {
+            self.send(AlertEvent::Invalid(message));
+        } else {
+            self.send(AlertEvent::Confirm);
+        }
+    }
+
+    fn send(&self, event: AlertEvent) {
+        let effects = self.machine.borrow_mut().send(event);
+        for effect in effects {
+            match effect {
+                AlertEffect::WillOpen => self.will_open.call(()),
+                AlertEffect::DidOpen => self.did_open.call(()),
+                AlertEffect::StartTimer(millis) => {
+                    let driver = self.clone();
+                    let handle = Timeout::new(millis, move || driver.send(AlertEvent::Timeout));
+                    self.timer.borrow_mut().replace(handle);
+                }
+                AlertEffect::ClearTimer => {
+                    self.timer.borrow_mut().take();
+                }
+                AlertEffect::RunPreConfirm(value) => {
+                    let driver = self.clone();
+                    let future = self.pre_confirm.map(|hook| hook.call(value));
+                    spawn(async move {
+                        let result = match future {
+                            Some(future) => future.await,
+                            None => Ok(()),
+                        };
+                        // A native dialog is already gone, so report the error and close.
+                        let failed = result.as_ref().err().cloned();
+                        driver.send(AlertEvent::PreConfirmed(result));
+                        if let (true, Some(message)) = (driver.native, failed) {
+                            if let Some(win) = window() {
+                                let _ = win.alert_with_message(&message);
+                            }
+                            driver.send(AlertEvent::Close);
+                        }
+                    });
+                }
+                AlertEffect::Loading(loading) => {
+                    let mut signal = self.loading;
+                    signal.set(loading);
+                }
+                AlertEffect::Confirm => self.on_confirm.call(()),
+                AlertEffect::Deny => self.on_deny.call(()),
+                AlertEffect::Cancel => self.on_cancel.call(()),
+                AlertEffect::Close => self.on_close.call(()),
+                AlertEffect::Action(id) => self.on_action.call(id),
+                AlertEffect::ValidationMessage(message) => {
+                    let mut validation_message = self.validation_message;
+                    validation_message.set(message);
+                }
+                AlertEffect::Hide => {
+                    let mut show_alert = self.show_alert;
+                    show_alert.set(false);
+                }
+                AlertEffect::StartExit(millis) => {
+                    let mut leaving = self.leaving;
+                    leaving.set(true);
+                    let driver = self.clone();
+                    let handle = Timeout::new(millis, move || driver.send(AlertEvent::Exited));
+                    self.timer.borrow_mut().replace(handle);
+                }
+                AlertEffect::Unmount => {
+                    let mut leaving = self.leaving;
+                    leaving.set(false);
+                }
+                AlertEffect::DidClose(result) => self.did_close.call(result),
+            }
+        }
+    }
 }
 
 /// Alert Component
@@ -222,48 +640,108 @@ pub struct AlertProps {
 /// # Properties
 /// The component uses the `AlertProps` struct for configuration. Key properties include:
 ///
-/// - **body**: The text content of the alert message (`&'static str`). Default: `""`.
+/// - **body**: The text content of the alert message (`String`). Default: `""`.
+/// - **html_body**: HTML snippet rendered below the body text, sanitized with the `sanitize` feature and shown as text without it (`String`). Default: `""`.
+/// - **children**: Rich content rendered below the body text (`Element`). Default: nothing.
+/// - **header**: Content rendered above the icon (`Element`). Default: nothing.
+/// - **footer**: Content rendered below the buttons (`Element`). Default: nothing.
 /// - **show_alert**: A `Signal<bool>` controlling the alert's visibility. This is a required prop.
-/// - **timeout**: The duration in milliseconds before the alert auto-closes (`u32`). Default: `2500`.
-/// - **title**: The heading text for the alert (`&'static str`). Default: `"Info"`.
-/// - **confirm_button_text**: The text for the confirm button (`&'static str`). Default: `"Okay"`.
-/// - **cancel_button_text**: The text for the cancel button (`&'static str`). Default: `"Cancel"`.
-/// - **show_confirm_button**: Determines whether the confirm button is visible (`bool`). Default: `true`.
-/// - **show_cancel_button**: Determines whether the cancel button is visible (`bool`). Default: `true`.
-/// - **show_close_button**: Determines whether a close button is included (`bool`). Default: `false`.
-/// - **position**: The screen position of the alert (`Position`). Default: `Position::TopRight`.
-/// - **icon_type**: The type of icon to display (`IconType`). Default: `IconType::Info`.
-/// - **icon_color**: CSS color for the alert icon (`&'static str`). Default: `""`.
-/// - **icon_width**: Width of the alert icon (`&'static str`). Default: `"50"`.
-/// - **alert_class**: Custom CSS class for the alert container (`&'static str`). Default: `""`.
-/// - **icon_class**: Custom CSS class for the alert icon (`&'static str`). Default: `""`.
-/// - **confirm_button_class**: Custom CSS class for the confirm button (`&'static str`). Default: `""`.
-/// - **cancel_button_class**: Custom CSS class for the cancel button (`&'static str`). Default: `""`.
-/// - **title_class**: Custom CSS class for the alert title (`&'static str`). Default: `""`.
-/// - **body_class**: Custom CSS class for the alert body (`&'static str`). Default: `""`.
-/// - **alert_style**: Inline style for the alert component (`&'static str`). Default: `DEFAULT_ALERT_STYLE`.
-/// - **close_button_style**: Inline style for the close button (`&'static str`). Default: `DEFAULT_CLOSE_BUTTON_STYLE`.
-/// - **confirm_button_style**: Inline style for the confirm button (`&'static str`). Default: `DEFAULT_CONFIRM_BUTTON_STYLE`.
-/// - **cancel_button_style**: Inline style for the cancel button (`&'static str`). Default: `DEFAULT_CANCEL_BUTTON_STYLE`.
-/// - **icon_style**: Inline style for the alert icon (`&'static str`). Default: `DEFAULT_ICON_STYLE`.
-/// - **title_style**: Inline style for the alert title (`&'static str`). Default: `DEFAULT_TITLE_STYLE`.
-/// - **separator_style**: Inline style for the separator line (`&'static str`). Default: `DEFAULT_SEPARATOR_STYLE`.
-/// - **message_style**: Inline style for the alert message text (`&'static str`). Default: `DEFAULT_MESSAGE_STYLE`.
+/// - **timeout**: The duration in milliseconds before the alert auto-closes (`Option<u32>`). Default: `2500`.
+/// - **title**: The heading text for the alert (`String`). Default: `"Info"`.
+/// - **id**: The `id` of the alert box and prefix of its title and body `id`s (`String`). Default: a generated `alert-rs-N`.
+/// - **confirm_button_text**: The text for the confirm button (`Option<String>`). Default: `"Okay"`.
+/// - **cancel_button_text**: The text for the cancel button (`Option<String>`). Default: `"Cancel"`.
+/// - **deny_button_text**: The text for the deny button (`Option<String>`). Default: `"No"`.
+/// - **show_confirm_button**: Determines whether the confirm button is visible (`Option<bool>`). Default: `true`.
+/// - **show_cancel_button**: Determines whether the cancel button is visible (`Option<bool>`). Default: `true`.
+/// - **show_deny_button**: Determines whether the deny button is visible (`Option<bool>`). Default: `false`.
+/// - **actions**: Extra buttons after the built-in ones (`Vec<AlertAction>`). Default: `[]`.
+/// - **show_close_button**: Determines whether a close button is included (`Option<bool>`). Default: `false`.
+/// - **position**: The screen position of the alert (`Option<Position>`). Default: `Position::TopRight`.
+/// - **icon_type**: The type of icon to display (`Option<IconType>`). Default: `IconType::Info`. Built-in icons render only with their `icon-*` feature enabled (all of them by default).
+/// - **icon_color**: CSS color for the alert icon (`String`). Default: `""`.
+/// - **icon_width**: Width of the alert icon (`String`). Default: `"50"`.
+/// - **animated_icon**: Whether to render the animated SweetAlert-style icon (`bool`). Default: `false`.
+/// - **icon**: Node rendered as a `IconType::Custom(CustomIcon::Node)` icon (`Element`). Default: nothing.
+/// - **image_url**: URL of a lazy-loaded image shown above the title (`String`). Default: `""`.
+/// - **image_alt**: Alternative text of the image (`String`). Default: `""`.
+/// - **image_width**: Width of the image (`String`). Default: `""`.
+/// - **image_height**: Height of the image (`String`). Default: `""`.
+/// - **image_fallback_url**: Image shown when `image_url` fails to load (`String`). Default: `""`.
+/// - **classes**: Every `*_class` prop in one value; flat props win (`AlertClasses`). Default: empty.
+/// - **styles**: Every `*_style` prop in one value; flat props win (`AlertStyles`). Default: empty.
+/// - **alert_class**: Custom CSS class for the alert container (`Option<String>`). Default: `""`.
+/// - **icon_class**: Custom CSS class for the alert icon (`Option<String>`). Default: `""`.
+/// - **image_class**: Custom CSS class for the alert image (`Option<String>`). Default: `""`.
+/// - **confirm_button_class**: Custom CSS class for the confirm button (`Option<String>`). Default: `""`.
+/// - **cancel_button_class**: Custom CSS class for the cancel button (`Option<String>`). Default: `""`.
+/// - **deny_button_class**: Custom CSS class for the deny button (`Option<String>`). Default: `""`.
+/// - **title_class**: Custom CSS class for the alert title (`Option<String>`). Default: `""`.
+/// - **body_class**: Custom CSS class for the alert body (`Option<String>`). Default: `""`.
+/// - **alert_style**: Inline style for the alert component (`Option<String>`). Default: `DEFAULT_ALERT_STYLE`.
+/// - **close_button_style**: Inline style for the close button (`Option<String>`). Default: `DEFAULT_CLOSE_BUTTON_STYLE`.
+/// - **confirm_button_style**: Inline style for the confirm button (`Option<String>`). Default: `DEFAULT_CONFIRM_BUTTON_STYLE`.
+/// - **cancel_button_style**: Inline style for the cancel button (`Option<String>`). Default: `DEFAULT_CANCEL_BUTTON_STYLE`.
+/// - **deny_button_style**: Inline style for the deny button (`Option<String>`). Default: `DEFAULT_DENY_BUTTON_STYLE`.
+/// - **icon_style**: Inline style for the alert icon (`Option<String>`). Default: `DEFAULT_ICON_STYLE`.
+/// - **image_style**: Inline style for the alert image (`Option<String>`). Default: `DEFAULT_IMAGE_STYLE`.
+/// - **title_style**: Inline style for the alert title (`Option<String>`). Default: `DEFAULT_TITLE_STYLE`.
+/// - **separator_style**: Inline style for the separator line (`Option<String>`). Default: `DEFAULT_SEPARATOR_STYLE`.
+/// - **message_style**: Inline style for the alert message text (`Option<String>`). Default: `DEFAULT_MESSAGE_STYLE`.
 /// - **native**: If `true`, uses the browser's native alert instead of the custom component (`bool`). Default: `false`.
 /// - **will_open**: Callback invoked before the alert is displayed (`Callback<()>`). Default: no-op.
 /// - **did_open**: Callback invoked after the alert is displayed (`Callback<()>`). Default: no-op.
-/// - **did_close**: Callback invoked after the alert is closed (`Callback<()>`). Default: no-op.
+/// - **input**: The prompt input shown below the body (`InputType`). Default: `InputType::None`.
+/// - **input_placeholder**: Placeholder of the prompt input (`String`). Default: `""`.
+/// - **input_value**: Initial value of the prompt input (`String`). Default: `""`.
+/// - **input_attributes**: Extra HTML attributes of the prompt input (`Vec<(String, String)>`). Default: `[]`.
+/// - **input_options**: `(value, label)` options of a select or radio input (`Vec<(String, String)>`). Default: `[]`.
+/// - **input_label**: Label next to a checkbox input (`String`). Default: `""`.
+/// - **input_label_style**: Inline style for radio and checkbox labels (`Option<String>`). Default: `DEFAULT_INPUT_LABEL_STYLE`.
+/// - **input_validator**: Check run on the input value on confirm; an `Err` keeps the alert open (`Option<Callback<String, Result<(), String>>>`). Default: `None`.
+/// - **pre_confirm**: Async check run on confirm with a loading state; an `Err` keeps the alert open (`Option<Callback<Option<String>, PreConfirmFuture>>`). Default: `None`.
+/// - **spinner_style**: Inline style for the loading spinner (`Option<String>`). Default: `DEFAULT_SPINNER_STYLE`.
+/// - **validation_message_class**: Custom CSS class for the validation message (`Option<String>`). Default: `""`.
+/// - **validation_message_style**: Inline style for the validation message (`Option<String>`). Default: `DEFAULT_VALIDATION_MESSAGE_STYLE`.
+/// - **input_class**: Custom CSS class for the prompt input (`Option<String>`). Default: `""`.
+/// - **input_style**: Inline style for the prompt input (`Option<String>`). Default: `DEFAULT_INPUT_STYLE`.
+/// - **close_on_backdrop_click**: If `true`, clicking the backdrop closes the alert (`Option<bool>`). Default: `false`.
+/// - **close_on_escape**: If `true`, pressing Escape closes the alert (`Option<bool>`). Default: `true`.
+/// - **initial_focus**: The element focused when the alert opens; focus is trapped inside and restored on close (`Option<InitialFocus>`). Default: `InitialFocus::Confirm`.
+/// - **backdrop_style**: Inline style for the backdrop (`Option<String>`). Default: `DEFAULT_BACKDROP_STYLE`.
+/// - **animation**: The enter and exit animation (`Option<AlertAnimation>`). Default: `AlertAnimation::None`.
+/// - **animation_duration**: Duration of the enter and exit animations in milliseconds (`Option<u32>`). Default: `300`.
+/// - **enter_class**: CSS class added to the alert box while it is open (`String`). Default: `""`.
+/// - **exit_class**: CSS class added to the alert box while it leaves (`String`). Default: `""`.
+/// - **reduced_motion**: Whether the alert honors the system's reduced motion setting (`Option<ReducedMotion>`). Default: `ReducedMotion::System`.
+/// - **did_close**: Callback invoked after the alert is closed, with the reason (`Callback<AlertResult>`). Default: no-op.
 /// - **on_confirm**: Callback invoked when the confirm button is clicked (`Callback<()>`). Default: no-op.
 /// - **on_close**: Callback invoked when the close button is clicked (`Callback<()>`). Default: no-op.
 /// - **on_cancel**: Callback invoked when the cancel button is clicked (`Callback<()>`). Default: no-op.
+/// - **on_deny**: Callback invoked when the deny button is clicked (`Callback<()>`). Default: no-op.
+/// - **on_action**: Callback invoked when an action is clicked, with its id (`Callback<String>`). Default: no-op.
 ///
 /// # Features
 /// - Highly customizable appearance and behavior.
+/// - Rich content through `children`, `header` and `footer`; native dialogs only show `title` and `body`.
+/// - Props left unset take the value of the nearest `AlertDefaults` context, if any.
+/// - Renders `body` as Markdown with the `markdown` feature, showing raw HTML as text.
 /// - Supports dynamic positioning and icon configuration.
 /// - Configurable buttons for confirmation, cancellation, and closing.
 /// - Optional timeout for auto-closing the alert.
 /// - Native or custom alert rendering options.
 /// - Built-in callback support for interactive handling of user actions.
+/// - Shares its open, close and timer transitions with the other adapters through `AlertMachine`.
+/// - Reports why the alert closed through the `CloseReason` of the `AlertResult` given to `did_close`.
+/// - Keeps a hidden alert mounted while its exit animation plays, calling `did_close` once it is over.
+/// - Renders animated SweetAlert-style icons with `animated_icon`, using the same markup as the other adapters.
+/// - Shows a lazy-loaded `image_url`, swapped for `image_fallback_url` or removed when it fails to load.
+/// - Renders a modal `dialog` or `alertdialog`, labelled by its title and described by its body through
+///   stable generated `id`s.
+/// - Keeps Tab and Shift+Tab inside the open alert, focuses `initial_focus` on open and gives focus back
+///   to the previously focused element on close.
+/// - Opens and closes instantly, with a still spinner, when `prefers-reduced-motion` is set or
+///   `reduced_motion` forces it.
 ///
 /// # Examples
 ///
@@ -315,258 +793,1137 @@ pub struct AlertProps {
 /// }
 /// ```
 ///
-/// ## Native Alert
+/// ## Runtime Text
 /// ```rust
 /// use dioxus::prelude::*;
 /// use alert_rs::dioxus::Alert;
 ///
 /// fn App() -> Element {
 ///     let mut show_alert = use_signal(|| false);
+///     let count = use_signal(|| 3);
 ///
 ///     rsx! {
 ///         button {
 ///             onclick: move |_| show_alert.set(true),
-///             "Native Alert"
+///             "Delete"
 ///         }
 ///         Alert {
 ///             show_alert: show_alert.clone(),
-///             native: true,
-///             body: "This uses the browser's native alert.",
+///             title: "Done",
+///             body: format!("Deleted {count} files"),
 ///         }
 ///     }
 /// }
 /// ```
-#[component]
-pub fn Alert(props: AlertProps) -> Element {
-    let timeout = props.timeout;
-    let native = props.native;
-    let mut show_alert = props.show_alert;
-
-    let title = props.title.to_string();
-    let body = props.body.to_string();
-
-    let show_confirm_button = props.show_confirm_button;
-    let show_cancel_button = props.show_cancel_button;
-    let show_close_button = props.show_close_button;
-    let icon_color = props.icon_color;
-    let icon_type = props.icon_type;
-    let icon_width = props.icon_width;
-    let icon_style = props.icon_style;
-
-    use_effect(move || {
-        if show_alert() && !native {
-            props.will_open.call(());
-
-            let _ = Timeout::new(timeout, move || {
-                show_alert.set(false);
-                props.did_close.call(());
-            })
-            .forget();
-
-            props.did_open.call(());
-        } else if show_alert() && native {
-            if let Some(win) = window() {
-                props.will_open.call(());
-
-                let full_message = if !title.is_empty() {
-                    format!("{}\n\n{}", title, body)
-                } else {
-                    body.clone()
-                };
-
-                match (show_confirm_button, show_cancel_button, show_close_button) {
-                    (true, true, true) => {
-                        if win.confirm_with_message(&full_message).unwrap_or(false) {
-                            props.on_confirm.call(());
-                        } else {
-                            props.on_close.call(());
-                            props.on_cancel.call(());
-                        }
-                    }
-                    (true, true, false) => {
-                        if win.confirm_with_message(&full_message).unwrap_or(false) {
-                            props.on_confirm.call(());
-                        } else {
-                            props.on_cancel.call(());
-                        }
-                    }
-                    (true, false, false) => {
-                        win.alert_with_message(&full_message).ok();
-                        props.on_confirm.call(());
-                    }
-                    _ => {}
-                }
-
-                Timeout::new(timeout, move || {
-                    show_alert.set(false);
-                    props.did_close.call(());
-                })
-                .forget();
-
-                props.did_open.call(());
-            }
-        }
-    });
-
-    let position_style = match props.position {
-        Position::TopLeft => "top: 0; left: 0;",
-        Position::TopCenter => "top: 0; left: 50%; transform: translateX(-50%);",
-        Position::TopRight => "top: 0; right: 0;",
-        Position::LeftCenter => "top: 50%; left: 0; transform: translateY(-50%);",
-        Position::Center => "top: 50%; left: 50%; transform: translate(-50%, -50%);",
-        Position::BottomCenter => "bottom: 0; left: 50%; transform: translateX(-50%);",
-        Position::RightCenter => "top: 50%; right: 0; transform: translateY(-50%);",
-        Position::BottomRight => "bottom: 0; right: 0;",
-        Position::BottomLeft => "bottom: 0; left: 0;",
-        Position::Custom(x, y) => &format!("top: {}; left: {};", y, x),
-    };
-
-    let icon_color = if icon_color.is_empty() {
-        match icon_type {
-            IconType::Warning => "orange",
-            IconType::Error => "red",
-            IconType::Success => "green",
-            IconType::Info => "blue",
-            IconType::Question => "gray",
-        }
-    } else {
-        props.icon_color
-    };
-
-    // SVGs taken from: https://fontawesome.com/icons
-    let icon_tag = match icon_type {
-        IconType::Warning => rsx! {
-            svg {
-                xmlns: "http://www.w3.org/2000/svg",
-                width: "{icon_width}",
-                style: "{icon_style}",
-                class: "p-2 m-2",
-                fill: "{icon_color}",
-                view_box: "0 0 512 512",
-                path {
-                    d: "M248.4 84.3c1.6-2.7 4.5-4.3 7.6-4.3s6 1.6 7.6 4.3L461.9 410c1.4 2.3 2.1 4.9 2.1 7.5c0 8-6.5 14.5-14.5 14.5H62.5c-8 0-14.5-6.5-14.5-14.5c0-2.7 .7-5.3 2.1-7.5L248.4 84.3zm-41-25L9.1 385c-6 9.8-9.1 21-9.1 32.5C0 452 28 480 62.5 480h387c34.5 0 62.5-28 62.5-62.5c0-11.5-3.2-22.7-9.1-32.5L304.6 59.3C294.3 42.4 275.9 32 256 32s-38.3 10.4-48.6 27.3zM288 368a32 32 0 1 0 -64 0 32 32 0 1 0 64 0zm-8-184c0-13.3-10.7-24-24-24s-24 10.7-24 24v96c0 13.3 10.7 24 24 24s24-10.7 24-24V184z"
-                }
-            }
-        },
-        IconType::Error => rsx! {
-            svg {
-                xmlns: "http://www.w3.org/2000/svg",
-                width: "{icon_width}",
-                style: "{icon_style}",
-                class: "p-2 m-2",
-                fill: "{icon_color}",
-                view_box: "0 0 20 20",
-                path {
-                    d: "M12.71,7.291c-0.15-0.15-0.393-0.15-0.542,0L10,9.458L7.833,7.291c-0.15-0.15-0.392-0.15-0.542,0c-0.149,0.149-0.149,0.392,0,0.541L9.458,10l-2.168,2.167c-0.149,0.15-0.149,0.393,0,0.542c0.15,0.149,0.392,0.149,0.542,0L10,10.542l2.168,2.167c0.149,0.149,0.392,0.149,0.542,0c0.148-0.149,0.148-0.392,0-0.542L10.542,10l2.168-2.168C12.858,7.683,12.858,7.44,12.71,7.291z M10,1.188c-4.867,0-8.812,3.946-8.812,8.812c0,4.867,3.945,8.812,8.812,8.812s8.812-3.945,8.812-8.812C18.812,5.133,14.867,1.188,10,1.188z M10,18.046c-4.444,0-8.046-3.603-8.046-8.046c0-4.444,3.603-8.046,8.046-8.046c4.443,0,8.046,3.602,8.046,8.046C18.046,14.443,14.443,18.046,10,18.046z"
-                }
-            }
-        },
-        IconType::Success => rsx! {
-            svg {
-                xmlns: "http://www.w3.org/2000/svg",
-                width: "{icon_width}",
-                style: "{icon_style}",
-                class: "p-2 m-2",
-                fill: "{icon_color}",
-                view_box: "0 0 512 512",
-                path {
-                    d: "M256 48a208 208 0 1 1 0 416 208 208 0 1 1 0-416zm0 464A256 256 0 1 0 256 0a256 256 0 1 0 0 512zM369 209c9.4-9.4 9.4-24.6 0-33.9s-24.6-9.4-33.9 0l-111 111-47-47c-9.4-9.4-24.6-9.4-33.9 0s-9.4 24.6 0 33.9l64 64c9.4 9.4 24.6 9.4 33.9 0L369 209z"
-                }
-            }
-        },
-        IconType::Info => rsx! {
-            svg {
-                xmlns: "http://www.w3.org/2000/svg",
-                width: "{icon_width}",
-                style: "{icon_style}",
-                class: "p-2 m-2",
-                fill: "{icon_color}",
-                view_box: "0 0 16 16",
-                path { d: "M8 15A7 7 0 1 1 8 1a7 7 0 0 1 0 14zm0 1A8 8 0 1 0 8 0a8 8 0 0 0 0 16z" }
-                path {
-                    d: "m8.93 6.588-2.29.287-.082.38.45.083c.294.07.352.176.288.469l-.738 3.468c-.194.897.105 1.319.808 1.319.545 0 1.178-.252 1.465-.598l.088-.416c-.2.176-.492.246-.686.246-.275 0-.375-.193-.304-.533L8.93 6.588zM9 4.5a1 1 0 1 1-2 0 1 1 0 0 1 2 0z"
-                }
-            }
-        },
-        IconType::Question => rsx! {
-            svg {
-                xmlns: "http://www.w3.org/2000/svg",
-                width: "{icon_width}",
-                style: "{icon_style}",
-                class: "p-2 m-2",
-                fill: "{icon_color}",
-                view_box: "0 0 16 16",
-                path { d: "M8 15A7 7 0 1 1 8 1a7 7 0 0 1 0 14zm0 1A8 8 0 1 0 8 0a8 8 0 0 0 0 16z" }
-                path {
-                    d: "M5.255 5.786a.237.237 0 0 0 .241.247h.825c.138 0 .248-.113.266-.25.09-.656.54-1.134 1.342-1.134.686 0 1.314.343 1.314 1.168 0 .635-.374.927-.965 1.371-.673.489-1.206 1.06-1.168 1.987l.003.217a.25.25 0 0 0 .25.246h.811a.25.25 0 0 0 .25-.25v-.105c0-.718.273-.927 1.01-1.486.609-.463 1.244-.977 1.244-2.056 0-1.511-1.276-2.241-2.673-2.241-1.267 0-2.655.59-2.75 2.286zm1.557 5.763c0 .533.425.927 1.01.927.609 0 1.028-.394 1.028-.927 0-.552-.42-.94-1.029-.94-.584 0-1.009.388-1.009.94z"
-                }
-            }
-        },
-    };
-
-    let on_cancel = {
-        move |_| {
-            props.on_cancel.call(());
-            show_alert.set(false);
-        }
-    };
-
-    let on_confirm = {
-        move |_| {
-            props.on_confirm.call(());
-        }
-    };
-
-    if !native {
-        rsx! {
-            if show_alert() {
-                div {
-                    style: props.alert_style,
-                    div {
-                        class: props.alert_class,
-                        style: format!("position: absolute; {}", position_style),
-                        if show_close_button {
-                            button {
-                                style: props.close_button_style,
-                                onclick: move |_| {
-                                    props.on_cancel.call(());
-                                    show_alert.set(false);
-                                },
+///
+/// ## Save / Don't Save / Cancel
+/// ```rust
+/// use dioxus::prelude::*;
+/// use alert_rs::dioxus::Alert;
+///
+/// fn App() -> Element {
+///     let mut show_alert = use_signal(|| false);
+///
+///     rsx! {
+///         button {
+///             onclick: move |_| show_alert.set(true),
+///             "Close Document"
+///         }
+///         Alert {
+///             show_alert: show_alert.clone(),
+///             title: "Save changes?",
+///             timeout: 0,
+///             confirm_button_text: "Save",
+///             deny_button_text: "Don't save",
+///             show_deny_button: true,
+///             on_confirm: |_| println!("Saved!"),
+///             on_deny: |_| println!("Discarded!"),
+///         }
+///     }
+/// }
+/// ```
+///
+/// ## Actions
+/// ```rust
+/// use dioxus::prelude::*;
+/// use alert_rs::common::{ActionVariant, AlertAction};
+/// use alert_rs::dioxus::Alert;
+///
+/// fn App() -> Element {
+///     let mut show_alert = use_signal(|| false);
+///
+///     rsx! {
+///         button {
+///             onclick: move |_| show_alert.set(true),
+///             "Upload"
+///         }
+///         Alert {
+///             show_alert: show_alert.clone(),
+///             title: "Upload failed",
+///             timeout: 0,
+///             show_confirm_button: false,
+///             show_cancel_button: false,
+///             actions: vec![
+///                 AlertAction::new("retry", "Retry"),
+///                 AlertAction::new("logs", "Open logs")
+///                     .variant(ActionVariant::Secondary)
+///                     .closes(false),
+///                 AlertAction::new("dismiss", "Dismiss").variant(ActionVariant::Danger),
+///             ],
+///             on_action: |id: String| println!("Clicked {id}"),
+///         }
+///     }
+/// }
+/// ```
+///
+/// ## Rich Content
+/// ```rust
+/// use dioxus::prelude::*;
+/// use alert_rs::dioxus::Alert;
+///
+/// fn App() -> Element {
+///     let mut show_alert = use_signal(|| false);
+///
+///     rsx! {
+///         button {
+///             onclick: move |_| show_alert.set(true),
+///             "Sign Up"
+///         }
+///         Alert {
+///             show_alert: show_alert.clone(),
+///             title: "Terms of Service",
+///             timeout: 0,
+///             header: rsx! { small { "Step 2 of 3" } },
+///             footer: rsx! { a { href: "/privacy", "Privacy policy" } },
+///             ul {
+///                 li { "Be kind." }
+///                 li { "No spam." }
+///             }
+///         }
+///     }
+/// }
+/// ```
+///
+/// ## HTML Body
+/// ```rust
+/// use dioxus::prelude::*;
+/// use alert_rs::dioxus::Alert;
+///
+/// fn App() -> Element {
+///     let mut show_alert = use_signal(|| false);
+///     // Messages coming from the backend as HTML, like SweetAlert2's `html:` option.
+///     let html_body = "Your <b>trial</b> ends in <i>3 days</i>.<script>alert(1)</script>";
+///
+///     rsx! {
+///         button {
+///             onclick: move |_| show_alert.set(true),
+///             "Show"
+///         }
+///         Alert {
+///             show_alert: show_alert.clone(),
+///             title: "Heads up",
+///             html_body,
+///         }
+///     }
+/// }
+/// ```
+///
+/// ## Grouped Classes and Styles
+/// ```rust
+/// use dioxus::prelude::*;
+/// use alert_rs::common::{AlertClasses, AlertStyles};
+/// use alert_rs::dioxus::Alert;
+///
+/// fn App() -> Element {
+///     let show_alert = use_signal(|| true);
+///     let classes = AlertClasses::default()
+///         .alert("rounded-lg shadow-xl p-6 bg-white")
+///         .confirm_button("btn btn-primary");
+///     let styles = AlertStyles::default().title("font-size: 20px;");
+///
+///     rsx! {
+///         Alert {
+///             show_alert,
+///             title: "Grouped",
+///             classes: classes.clone().merge(AlertClasses::default().confirm_button("btn btn-danger")),
+///             styles,
+///             // Flat props still win over the grouped ones.
+///             title_class: "text-xl",
+///         }
+///     }
+/// }
+/// ```
+///
+/// ## Animations
+/// ```rust
+/// use dioxus::prelude::*;
+/// use alert_rs::common::AlertAnimation;
+/// use alert_rs::dioxus::Alert;
+/// use alert_rs::{IconType, Position};
+///
+/// fn App() -> Element {
+///     let mut show_alert = use_signal(|| false);
+///
+///     rsx! {
+///         button {
+///             onclick: move |_| show_alert.set(true),
+///             "Show"
+///         }
+///         Alert {
+///             show_alert,
+///             title: "Sliding in",
+///             icon_type: IconType::Success,
+///             animated_icon: true,
+///             position: Position::BottomRight,
+///             animation: AlertAnimation::Slide,
+///             animation_duration: 500,
+///             // Fired once the exit animation is over.
+///             did_close: |_| log::info!("Gone"),
+///         }
+///         // Or bring your own keyframes, e.g. from Animate.css.
+///         Alert {
+///             show_alert,
+///             animation: AlertAnimation::None,
+///             enter_class: "animate__animated animate__backInDown",
+///             exit_class: "animate__animated animate__backOutDown",
+///         }
+///     }
+/// }
+/// ```
+///
+/// ## Custom Icons
+/// ```rust
+/// use dioxus::prelude::*;
+/// use alert_rs::common::CustomIcon;
+/// use alert_rs::dioxus::Alert;
+/// use alert_rs::IconType;
+///
+/// fn App() -> Element {
+///     let show_alert = use_signal(|| true);
+///
+///     rsx! {
+///         Alert {
+///             show_alert,
+///             title: "New message",
+///             icon_type: IconType::Custom(CustomIcon::Svg {
+///                 path: "M64 112c-8.8 0-16 7.2-16 16v22.1L220.5 291.7c20.7 17 50.4 17 71.1 0L464 150.1V128c0-8.8-7.2-16-16-16H64z".into(),
+///                 view_box: "0 0 512 512".into(),
+///             }),
+///             icon_color: "purple",
+///         }
+///         Alert {
+///             show_alert,
+///             title: "Welcome back",
+///             icon_type: IconType::Custom(CustomIcon::Image("/assets/avatar.png".into())),
+///         }
+///         Alert {
+///             show_alert,
+///             title: "Level up",
+///             icon_type: IconType::Custom(CustomIcon::Node),
+///             icon: rsx! { span { style: "font-size: 48px;", "🏆" } },
+///         }
+///         Alert { show_alert, title: "No icon", icon_type: IconType::None }
+///     }
+/// }
+/// ```
+///
+/// ## Image
+/// ```rust
+/// use dioxus::prelude::*;
+/// use alert_rs::dioxus::Alert;
+/// use alert_rs::IconType;
+///
+/// fn App() -> Element {
+///     let show_alert = use_signal(|| true);
+///
+///     rsx! {
+///         Alert {
+///             show_alert,
+///             title: "What's new",
+///             body: "Dark mode is here.",
+///             icon_type: IconType::None,
+///             image_url: "/assets/dark-mode.png",
+///             image_alt: "The dashboard in dark mode",
+///             image_width: "400",
+///             image_height: "200",
+///             image_fallback_url: "/assets/placeholder.png",
+///             image_class: "rounded-lg",
+///             timeout: 0,
+///         }
+///     }
+/// }
+/// ```
+///
+/// ## Accessibility
+/// ```rust
+/// use dioxus::prelude::*;
+/// use alert_rs::dioxus::Alert;
+/// use alert_rs::IconType;
+///
+/// fn App() -> Element {
+///     let show_alert = use_signal(|| true);
+///
+///     // Rendered as `role="alertdialog"` with `aria-labelledby="delete-files-title"`
+///     // and `aria-describedby="delete-files-body"`.
+///     rsx! {
+///         Alert {
+///             show_alert,
+///             id: "delete-files",
+///             title: "Delete files?",
+///             body: "This cannot be undone.",
+///             icon_type: IconType::Warning,
+///         }
+///     }
+/// }
+/// ```
+///
+/// ## Initial Focus
+/// ```rust
+/// use dioxus::prelude::*;
+/// use alert_rs::common::InitialFocus;
+/// use alert_rs::dioxus::Alert;
+/// use alert_rs::IconType;
+///
+/// fn App() -> Element {
+///     let show_alert = use_signal(|| true);
+///
+///     // Focus starts on Cancel, so pressing Enter right away does not delete anything.
+///     rsx! {
+///         Alert {
+///             show_alert,
+///             title: "Delete your account?",
+///             icon_type: IconType::Warning,
+///             initial_focus: InitialFocus::Cancel,
+///             timeout: 0,
+///         }
+///     }
+/// }
+/// ```
+///
+/// ## Reduced Motion
+/// ```rust
+/// use dioxus::prelude::*;
+/// use alert_rs::common::ReducedMotion;
+/// use alert_rs::dioxus::Alert;
+///
+/// #[component]
+/// fn SavedAlert(reduce_motion: bool) -> Element {
+///     let show_alert = use_signal(|| true);
+///     // Follows `prefers-reduced-motion` by default; an in-app setting can force it.
+///     let reduced_motion = if reduce_motion {
+///         ReducedMotion::Always
+///     } else {
+///         ReducedMotion::System
+///     };
+///
+///     rsx! {
+///         Alert { show_alert, title: "Saved", reduced_motion }
+///     }
+/// }
+/// ```
+///
+/// ## App-wide Defaults
+/// ```rust
+/// use dioxus::prelude::*;
+/// use alert_rs::common::{AlertClasses, AlertDefaults};
+/// use alert_rs::dioxus::Alert;
+/// use alert_rs::Position;
+///
+/// fn App() -> Element {
+///     let show_alert = use_signal(|| true);
+///     use_context_provider(|| {
+///         AlertDefaults::default()
+///             .position(Position::TopCenter)
+///             .confirm_button_text("Got it")
+///             .classes(AlertClasses::default().alert("rounded-lg shadow-xl p-6 bg-white"))
+///     });
+///
+///     rsx! {
+///         // Uses the app-wide position, button text and class.
+///         Alert { show_alert, title: "Saved" }
+///     }
+/// }
+/// ```
+///
+/// ## Prompt
+/// ```rust
+/// use dioxus::prelude::*;
+/// use alert_rs::dioxus::Alert;
+/// use alert_rs::InputType;
+///
+/// fn App() -> Element {
+///     let mut show_alert = use_signal(|| false);
+///
+///     rsx! {
+///         button {
+///             onclick: move |_| show_alert.set(true),
+///             "Ask Name"
+///         }
+///         Alert {
+///             show_alert: show_alert.clone(),
+///             title: "What is your name?",
+///             input: InputType::Text,
+///             input_placeholder: "Ferris",
+///             input_validator: |name: String| {
+///                 if name.trim().is_empty() {
+///                     Err("Please enter a name".to_string())
+///                 } else {
+///                     Ok(())
+///                 }
+///             },
+///             timeout: 0,
+///             did_close: |result: alert_rs::common::AlertResult| {
+///                 if let Some(name) = result.value {
+///                     println!("Hello, {name}!");
+///                 }
+///             },
+///         }
+///     }
+/// }
+/// ```
+///
+/// ## Async Confirmation
+/// ```rust
+/// use dioxus::prelude::*;
+/// use alert_rs::common::PreConfirmFuture;
+/// use alert_rs::dioxus::Alert;
+///
+/// fn App() -> Element {
+///     let mut show_alert = use_signal(|| false);
+///
+///     rsx! {
+///         button {
+///             onclick: move |_| show_alert.set(true),
+///             "Save"
+///         }
+///         Alert {
+///             show_alert: show_alert.clone(),
+///             title: "Save changes?",
+///             timeout: 0,
+///             pre_confirm: |_: Option<String>| -> PreConfirmFuture {
+///                 Box::pin(async move {
+///                     // e.g. `reqwest::Client::new().post(url).send().await`
+///                     Err("The server is unavailable".to_string())
+///                 })
+///             },
+///         }
+///     }
+/// }
+/// ```
+///
+/// ## Native Alert
+/// ```rust
+/// use dioxus::prelude::*;
+/// use alert_rs::dioxus::Alert;
+///
+/// fn App() -> Element {
+///     let mut show_alert = use_signal(|| false);
+///
+///     rsx! {
+///         button {
+///             onclick: move |_| show_alert.set(true),
+///             "Native Alert"
+///         }
+///         Alert {
+///             show_alert: show_alert.clone(),
+///             native: true,
+///             body: "This uses the browser's native alert.",
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn Alert(props: AlertProps) -> Element {
+    let defaults = try_use_context::<AlertDefaults>().unwrap_or_default();
+    let resolve = |value: &Option<String>, default: &Option<String>, builtin: &str| {
+        AlertDefaults::resolve(value.clone(), default.clone(), builtin.to_string())
+    };
+    let icon_type = AlertDefaults::resolve(
+        props.icon_type.clone(),
+        defaults.icon_type.clone(),
+        IconType::Info,
+    );
+    let show_confirm_button = AlertDefaults::resolve(
+        props.show_confirm_button,
+        defaults.show_confirm_button,
+        true,
+    );
+    let show_cancel_button =
+        AlertDefaults::resolve(props.show_cancel_button, defaults.show_cancel_button, true);
+    let show_deny_button =
+        AlertDefaults::resolve(props.show_deny_button, defaults.show_deny_button, false);
+    let show_close_button =
+        AlertDefaults::resolve(props.show_close_button, defaults.show_close_button, false);
+    let close_on_backdrop_click = AlertDefaults::resolve(
+        props.close_on_backdrop_click,
+        defaults.close_on_backdrop_click,
+        false,
+    );
+    let close_on_escape =
+        AlertDefaults::resolve(props.close_on_escape, defaults.close_on_escape, true);
+    let initial_focus = AlertDefaults::resolve(
+        props.initial_focus,
+        defaults.initial_focus,
+        InitialFocus::Confirm,
+    );
+    let animation =
+        AlertDefaults::resolve(props.animation, defaults.animation, AlertAnimation::None);
+    let animation_duration =
+        AlertDefaults::resolve(props.animation_duration, defaults.animation_duration, 300);
+    let classes = defaults.classes.clone().merge(props.classes.clone());
+    let styles = defaults.styles.clone().merge(props.styles.clone());
+    let timeout = AlertDefaults::resolve(props.timeout, defaults.timeout, 2500);
+    let position = AlertDefaults::resolve(
+        props.position.clone(),
+        defaults.position.clone(),
+        Position::TopRight,
+    );
+    let confirm_button_text = resolve(
+        &props.confirm_button_text,
+        &defaults.confirm_button_text,
+        "Okay",
+    );
+    let cancel_button_text = resolve(
+        &props.cancel_button_text,
+        &defaults.cancel_button_text,
+        "Cancel",
+    );
+    let deny_button_text = resolve(&props.deny_button_text, &defaults.deny_button_text, "No");
+    let alert_class = resolve(&props.alert_class, &classes.alert, "");
+    let icon_class = resolve(&props.icon_class, &classes.icon, "");
+    let image_class = resolve(&props.image_class, &classes.image, "");
+    let title_class = resolve(&props.title_class, &classes.title, "");
+    let body_class = resolve(&props.body_class, &classes.body, "");
+    let confirm_button_class = resolve(&props.confirm_button_class, &classes.confirm_button, "");
+    let cancel_button_class = resolve(&props.cancel_button_class, &classes.cancel_button, "");
+    let deny_button_class = resolve(&props.deny_button_class, &classes.deny_button, "");
+    let input_class = resolve(&props.input_class, &classes.input, "");
+    let validation_message_class = resolve(
+        &props.validation_message_class,
+        &classes.validation_message,
+        "",
+    );
+    let alert_style = resolve(&props.alert_style, &styles.alert, DEFAULT_ALERT_STYLE);
+    let backdrop_style = resolve(
+        &props.backdrop_style,
+        &styles.backdrop,
+        DEFAULT_BACKDROP_STYLE,
+    );
+    let close_button_style = resolve(
+        &props.close_button_style,
+        &styles.close_button,
+        DEFAULT_CLOSE_BUTTON_STYLE,
+    );
+    let confirm_button_style = resolve(
+        &props.confirm_button_style,
+        &styles.confirm_button,
+        DEFAULT_CONFIRM_BUTTON_STYLE,
+    );
+    let cancel_button_style = resolve(
+        &props.cancel_button_style,
+        &styles.cancel_button,
+        DEFAULT_CANCEL_BUTTON_STYLE,
+    );
+    let deny_button_style = resolve(
+        &props.deny_button_style,
+        &styles.deny_button,
+        DEFAULT_DENY_BUTTON_STYLE,
+    );
+    let icon_style = resolve(&props.icon_style, &styles.icon, DEFAULT_ICON_STYLE);
+    let image_style = resolve(&props.image_style, &styles.image, DEFAULT_IMAGE_STYLE);
+    let title_style = resolve(&props.title_style, &styles.title, DEFAULT_TITLE_STYLE);
+    let separator_style = resolve(
+        &props.separator_style,
+        &styles.separator,
+        DEFAULT_SEPARATOR_STYLE,
+    );
+    let message_style = resolve(&props.message_style, &styles.message, DEFAULT_MESSAGE_STYLE);
+    let input_style = resolve(&props.input_style, &styles.input, DEFAULT_INPUT_STYLE);
+    let input_label_style = resolve(
+        &props.input_label_style,
+        &styles.input_label,
+        DEFAULT_INPUT_LABEL_STYLE,
+    );
+    let validation_message_style = resolve(
+        &props.validation_message_style,
+        &styles.validation_message,
+        DEFAULT_VALIDATION_MESSAGE_STYLE,
+    );
+    let spinner_style = resolve(&props.spinner_style, &styles.spinner, DEFAULT_SPINNER_STYLE);
+    let native = props.native;
+    let show_alert = props.show_alert;
+
+    let title = props.title.to_string();
+    let body = props.body.to_string();
+
+    let icon_color = props.icon_color.clone();
+    let icon_width = props.icon_width.clone();
+
+    let machine = use_hook(|| Rc::new(RefCell::new(AlertMachine::new(timeout))));
+    let timer = use_hook(|| Rc::new(RefCell::new(None::<Timeout>)));
+    let escape_listener = use_hook(|| Rc::new(RefCell::new(None::<EventListener>)));
+    let focus_trap = use_hook(|| Rc::new(RefCell::new(None::<FocusTrap>)));
+    let validation_message = use_signal(|| None::<String>);
+    let loading = use_signal(|| false);
+    let leaving = use_signal(|| false);
+    let mut failed_images = use_signal(Vec::<String>::new);
+    let generated_ids = use_hook(AlertIds::generate);
+    let ids = if props.id.is_empty() {
+        generated_ids
+    } else {
+        AlertIds::new(&props.id)
+    };
+    machine.borrow_mut().set_timeout(timeout);
+    machine
+        .borrow_mut()
+        .set_pre_confirm(props.pre_confirm.is_some());
+    let reduced_motion = AlertDefaults::resolve(
+        props.reduced_motion,
+        defaults.reduced_motion,
+        ReducedMotion::System,
+    )
+    .is_reduced();
+    let animation = if reduced_motion {
+        AlertAnimation::None
+    } else {
+        animation
+    };
+    machine.borrow_mut().set_animation_duration(if native {
+        0
+    } else {
+        animation.exit_duration(animation_duration, &props.exit_class, reduced_motion)
+    });
+
+    let driver = Driver {
+        machine,
+        timer: timer.clone(),
+        show_alert,
+        will_open: props.will_open,
+        did_open: props.did_open,
+        did_close: props.did_close,
+        on_confirm: props.on_confirm,
+        on_close: props.on_close,
+        on_cancel: props.on_cancel,
+        on_deny: props.on_deny,
+        on_action: props.on_action,
+        input_validator: props.input_validator,
+        validation_message,
+        pre_confirm: props.pre_confirm,
+        loading,
+        leaving,
+        native,
+    };
+
+    use_drop({
+        let escape_listener = escape_listener.clone();
+        let focus_trap = focus_trap.clone();
+        move || {
+            timer.borrow_mut().take();
+            escape_listener.borrow_mut().take();
+            focus_trap.borrow_mut().take();
+        }
+    });
+
+    let input = props.input;
+    let input_value = input.initial_value(&props.input_value, &props.input_options);
+    let dialog = NativeDialog::for_input(
+        input,
+        show_confirm_button,
+        show_deny_button,
+        show_cancel_button,
+        show_close_button,
+    );
+
+    let close_on_escape = close_on_escape && !native;
+
+    use_effect({
+        let driver = driver.clone();
+        let ids = ids.clone();
+        move || {
+            if !show_alert() {
+                escape_listener.borrow_mut().take();
+                focus_trap.borrow_mut().take();
+                driver.send(AlertEvent::Hidden);
+                return;
+            }
+            // Signals notify even when set to the same value, so an open alert is left alone.
+            if !driver.machine.borrow().can_show() {
+                return;
+            }
+
+            if close_on_escape {
+                if let Some(win) = window() {
+                    let driver = driver.clone();
+                    let listener = EventListener::new(&win, "keydown", move |event| {
+                        let escape = event
+                            .dyn_ref::<web_sys::KeyboardEvent>()
+                            .is_some_and(|event| event.key() == "Escape");
+                        if escape {
+                            driver.send(AlertEvent::Escape);
+                        }
+                    });
+                    escape_listener.borrow_mut().replace(listener);
+                }
+            }
+            if !native {
+                focus_trap.borrow_mut().take();
+                let trap = FocusTrap::activate(&ids, initial_focus);
+                focus_trap.borrow_mut().replace(trap);
+            }
+
+            driver.send(AlertEvent::Show);
+            if !input.is_none() {
+                driver.send(AlertEvent::Input(input_value.clone()));
+            }
+            driver.send(AlertEvent::Shown);
+
+            if native {
+                if let Some(win) = window() {
+                    let full_message = if !title.is_empty() {
+                        format!("{}\n\n{}", title, body)
+                    } else {
+                        body.clone()
+                    };
+
+                    let accepted = match dialog {
+                        NativeDialog::Alert => win.alert_with_message(&full_message).is_ok(),
+                        NativeDialog::Confirm { .. } => {
+                            win.confirm_with_message(&full_message).unwrap_or(false)
+                        }
+                        NativeDialog::Prompt { .. } => {
+                            let mut message = full_message.clone();
+                            loop {
+                                match win.prompt_with_message_and_default(&message, &input_value) {
+                                    Ok(Some(value)) => match driver.validate(&value) {
+                                        Ok(()) => {
+                                            driver.send(AlertEvent::Input(value));
+                                            break true;
+                                        }
+                                        Err(error) => {
+                                            message = format!("{}\n\n{}", error, full_message)
+                                        }
+                                    },
+                                    _ => break false,
+                                }
+                            }
+                        }
+                        NativeDialog::None => false,
+                    };
+
+                    driver.send(dialog.event(accepted));
+                }
+            }
+        }
+    });
+
+    let position_style = position.style();
+    let leaving = leaving() && !show_alert();
+    let animation_class = match (reduced_motion, leaving) {
+        (true, _) => "",
+        (false, true) => props.exit_class.as_str(),
+        (false, false) => props.enter_class.as_str(),
+    };
+
+    let icon_color = if icon_color.is_empty() {
+        icon_type.default_color().to_string()
+    } else {
+        icon_color
+    };
+
+    let icon_tag = match icon_type.svg() {
+        Some(svg) => rsx! {
+            svg {
+                xmlns: "http://www.w3.org/2000/svg",
+                width: "{icon_width}",
+                style: "{icon_style}",
+                class: "p-2 m-2",
+                fill: "{icon_color}",
+                view_box: svg.view_box,
+                path { d: svg.path }
+            }
+        },
+        None => match &icon_type {
+            IconType::Custom(CustomIcon::Image(url)) => rsx! {
+                img {
+                    src: "{url}",
+                    width: "{icon_width}",
+                    style: "{icon_style}",
+                    class: "p-2 m-2",
+                    alt: "",
+                }
+            },
+            IconType::Custom(CustomIcon::Node) => props.icon,
+            _ => rsx! {},
+        },
+    };
+    let image_src = image_source(
+        &props.image_url,
+        &props.image_fallback_url,
+        &failed_images.read(),
+    )
+    .map(str::to_string);
+    let image_tag = match image_src {
+        Some(src) => {
+            let failed_src = src.clone();
+            rsx! {
+                img {
+                    src,
+                    alt: "{props.image_alt}",
+                    width: (!props.image_width.is_empty()).then(|| props.image_width.clone()),
+                    height: (!props.image_height.is_empty()).then(|| props.image_height.clone()),
+                    class: "{image_class}",
+                    style: "{image_style}",
+                    loading: "lazy",
+                    onerror: move |_| failed_images.write().push(failed_src.clone()),
+                }
+            }
+        }
+        None => rsx! {},
+    };
+    let animated_svg = (props.animated_icon && !reduced_motion)
+        .then(|| icon_type.animated_svg(&icon_width, &icon_color))
+        .flatten();
+    let icon_tag = match animated_svg {
+        Some(svg) => rsx! {
+            style { {ANIMATED_ICON_STYLES} }
+            span { dangerous_inner_html: svg }
+        },
+        None => icon_tag,
+    };
+
+    let set_input_attributes = {
+        let attributes = props.input_attributes.clone();
+        move |event: MountedEvent| {
+            if let Some(element) = event.downcast::<web_sys::Element>() {
+                set_input_attributes(element, &attributes);
+            }
+        }
+    };
+
+    let on_input = {
+        let driver = driver.clone();
+        move |event: FormEvent| driver.send(AlertEvent::Input(event.value()))
+    };
+
+    let on_check = {
+        let driver = driver.clone();
+        move |event: FormEvent| driver.send(AlertEvent::Input(event.checked().to_string()))
+    };
+
+    let initial_value = props
+        .input
+        .initial_value(&props.input_value, &props.input_options);
+
+    let invalid = validation_message().is_some();
+    let error_described_by = invalid.then(|| ids.validation_message.clone());
+
+    let input_tag = match props.input {
+        InputType::None => rsx! {},
+        InputType::Textarea => rsx! {
+            textarea {
+                class: "{input_class}",
+                aria_invalid: invalid,
+                aria_describedby: error_described_by.clone(),
+                style: "{input_style}",
+                placeholder: props.input_placeholder.clone(),
+                value: initial_value,
+                oninput: on_input,
+                onmounted: set_input_attributes,
+            }
+        },
+        InputType::Select => rsx! {
+            select {
+                class: "{input_class}",
+                aria_invalid: invalid,
+                aria_describedby: error_described_by.clone(),
+                style: "{input_style}",
+                onchange: on_input,
+                onmounted: set_input_attributes,
+                for (value, label) in props.input_options.iter() {
+                    option {
+                        value: "{value}",
+                        selected: *value == initial_value,
+                        "{label}"
+                    }
+                }
+            }
+        },
+        InputType::Radio => rsx! {
+            div {
+                class: "{input_class}",
+                role: "radiogroup",
+                aria_invalid: invalid,
+                aria_describedby: error_described_by.clone(),
+                onmounted: set_input_attributes,
+                for (value, label) in props.input_options.iter() {
+                    label {
+                        style: "{input_label_style}",
+                        input {
+                            r#type: "radio",
+                            name: "{ids.radio_group}",
+                            value: "{value}",
+                            checked: *value == initial_value,
+                            onchange: on_input.clone(),
+                        }
+                        "{label}"
+                    }
+                }
+            }
+        },
+        InputType::Checkbox => rsx! {
+            label {
+                class: "{input_class}",
+                style: "{input_label_style}",
+                input {
+                    r#type: "checkbox",
+                    aria_invalid: invalid,
+                    aria_describedby: error_described_by.clone(),
+                    checked: initial_value == "true",
+                    onchange: on_check,
+                    onmounted: set_input_attributes,
+                }
+                "{props.input_label}"
+            }
+        },
+        InputType::Range { min, max, step } => rsx! {
+            input {
+                r#type: "range",
+                class: "{input_class}",
+                aria_invalid: invalid,
+                aria_describedby: error_described_by.clone(),
+                style: "{input_style}",
+                min: "{min}",
+                max: "{max}",
+                step: "{step}",
+                value: initial_value,
+                oninput: on_input,
+                onmounted: set_input_attributes,
+            }
+        },
+        input => rsx! {
+            input {
+                r#type: input.html_type(),
+                class: "{input_class}",
+                aria_invalid: invalid,
+                aria_describedby: error_described_by.clone(),
+                style: "{input_style}",
+                placeholder: props.input_placeholder.clone(),
+                value: initial_value,
+                oninput: on_input,
+                onmounted: set_input_attributes,
+            }
+        },
+    };
+
+    let on_backdrop = {
+        let driver = driver.clone();
+        move |_| {
+            if close_on_backdrop_click {
+                driver.send(AlertEvent::Backdrop);
+            }
+        }
+    };
+
+    let on_cancel = {
+        let driver = driver.clone();
+        move |_| driver.send(AlertEvent::Cancel)
+    };
+
+    let on_deny = {
+        let driver = driver.clone();
+        move |_| driver.send(AlertEvent::Deny)
+    };
+
+    let on_close = {
+        let driver = driver.clone();
+        move |_| driver.send(AlertEvent::Close)
+    };
+
+    let on_confirm = {
+        let driver = driver.clone();
+        move |_| driver.confirm()
+    };
+
+    #[cfg(not(feature = "markdown"))]
+    let body_tag = rsx! {
+        p {
+            class: "{body_class}",
+            style: "{message_style}",
+            "{props.body}"
+        }
+    };
+    #[cfg(feature = "markdown")]
+    let body_tag = rsx! {
+        div {
+            class: "{body_class}",
+            style: "{message_style}",
+            {markdown::render_markdown(&parse_markdown(&props.body))}
+        }
+    };
+
+    #[cfg(feature = "sanitize")]
+    let html_body_tag = if props.html_body.is_empty() {
+        rsx! {}
+    } else {
+        rsx! {
+            div {
+                class: "{body_class}",
+                style: "{message_style}",
+                dangerous_inner_html: sanitize_html(&props.html_body),
+            }
+        }
+    };
+    #[cfg(not(feature = "sanitize"))]
+    let html_body_tag = if props.html_body.is_empty() {
+        rsx! {}
+    } else {
+        rsx! {
+            div {
+                class: "{body_class}",
+                style: "{message_style}",
+                "{props.html_body}"
+            }
+        }
+    };
+
+    let has_body = !props.body.is_empty()
+        || !props.html_body.is_empty()
+        || props
+            .children
+            .as_ref()
+            .is_ok_and(|children| *children != VNode::placeholder());
+
+    if !native {
+        rsx! {
+            if show_alert() || leaving {
+                div {
+                    style: format!(
+                        "{} {}",
+                        alert_style,
+                        animation.overlay_style(animation_duration, leaving),
+                    ),
+                    if animation != AlertAnimation::None {
+                        style { {ANIMATION_KEYFRAMES} }
+                    }
+                    div {
+                        style: "{backdrop_style}",
+                        onclick: on_backdrop,
+                    }
+                    div {
+                        id: "{ids.dialog}",
+                        tabindex: "-1",
+                        role: icon_type.dialog_role(),
+                        aria_modal: "true",
+                        aria_labelledby: "{ids.title}",
+                        aria_describedby: has_body.then(|| ids.body.clone()),
+                        class: "{ALERT_CLASS} {alert_class} {animation_class}",
+                        style: format!(
+                            "position: absolute; {} {}",
+                            position_style,
+                            animation.box_style(&position, animation_duration, leaving),
+                        ),
+                        if show_close_button {
+                            button {
+                                style: "{close_button_style}",
+                                onclick: on_close,
+                                disabled: loading(),
+                                aria_label: "Close",
                                 "X"
                             }
                         }
-                        div {
-                            class: props.icon_class,
-                            style: props.icon_style,
-                            {icon_tag}
+                        {props.header}
+                        if icon_type != IconType::None {
+                            div {
+                                class: "{icon_class}",
+                                style: "{icon_style}",
+                                {icon_tag}
+                            }
                         }
+                        {image_tag}
                         strong {
-                            class: props.title_class,
-                            style: props.title_style,
+                            id: "{ids.title}",
+                            class: "{title_class}",
+                            style: "{title_style}",
                             "{props.title}"
                         }
-                        hr { style: props.separator_style }
-                        p {
-                            class: props.body_class,
-                            style: props.message_style,
-                            "{props.body}"
+                        hr { style: "{separator_style}" }
+                        if has_body {
+                            div {
+                                id: "{ids.body}",
+                                if !props.body.is_empty() {
+                                    {body_tag}
+                                }
+                                {html_body_tag}
+                                {props.children}
+                            }
+                        }
+                        {input_tag}
+                        if let Some(message) = validation_message() {
+                            div {
+                                id: "{ids.validation_message}",
+                                class: "{validation_message_class}",
+                                style: "{validation_message_style}",
+                                role: "alert",
+                                "{message}"
+                            }
+                        }
+                        if loading() && !reduced_motion {
+                            style { {SPINNER_KEYFRAMES} }
                         }
-                        if props.show_confirm_button {
+                        if show_confirm_button {
                             button {
-                                class: props.confirm_button_class,
-                                style: props.confirm_button_style,
+                                id: "{ids.confirm_button}",
+                                class: "{confirm_button_class}",
+                                style: "{confirm_button_style}",
                                 onclick: on_confirm,
-                                "{props.confirm_button_text}"
+                                disabled: loading(),
+                                if loading() {
+                                    span {
+                                        style: "{spinner_style}",
+                                        aria_hidden: "true",
+                                    }
+                                }
+                                "{confirm_button_text}"
+                            }
+                        }
+                        if show_deny_button {
+                            button {
+                                id: "{ids.deny_button}",
+                                class: "{deny_button_class}",
+                                style: "{deny_button_style}",
+                                onclick: on_deny,
+                                disabled: loading(),
+                                "{deny_button_text}"
                             }
                         }
-                        if props.show_cancel_button {
+                        if show_cancel_button {
                             button {
-                                class: props.cancel_button_class,
-                                style: props.cancel_button_style,
+                                id: "{ids.cancel_button}",
+                                class: "{cancel_button_class}",
+                                style: "{cancel_button_style}",
                                 onclick:on_cancel,
-                                "{props.cancel_button_text}"
+                                disabled: loading(),
+                                "{cancel_button_text}"
+                            }
+                        }
+                        for action in props.actions.iter().cloned() {
+                            button {
+                                key: "{action.id}",
+                                class: "{action.class}",
+                                style: "{action.button_style()}",
+                                onclick: {
+                                    let driver = driver.clone();
+                                    let event = AlertEvent::Action {
+                                        id: action.id.clone(),
+                                        closes: action.closes,
+                                    };
+                                    move |_| driver.send(event.clone())
+                                },
+                                disabled: loading(),
+                                "{action.label}"
                             }
                         }
+                        {props.footer}
                     }
                 }
             }
@@ -0,0 +1,34 @@
+use crate::common::MarkdownNode;
+use dioxus::prelude::*;
+
+/// Renders a Markdown `body` parsed by [`parse_markdown`](crate::common::parse_markdown).
+///
+/// Text is inserted as text nodes, so Dioxus escapes any markup it contains.
+pub(crate) fn render_markdown(nodes: &[MarkdownNode]) -> Element {
+    rsx! {
+        for node in nodes {
+            {render_node(node)}
+        }
+    }
+}
+
+fn render_node(node: &MarkdownNode) -> Element {
+    match node {
+        MarkdownNode::Text(text) => rsx! { "{text}" },
+        MarkdownNode::Code(code) => rsx! { code { "{code}" } },
+        MarkdownNode::LineBreak => rsx! { br {} },
+        MarkdownNode::Paragraph(children) => rsx! { p { {render_markdown(children)} } },
+        MarkdownNode::Strong(children) => rsx! { strong { {render_markdown(children)} } },
+        MarkdownNode::Emphasis(children) => rsx! { em { {render_markdown(children)} } },
+        MarkdownNode::List {
+            start: Some(start),
+            items,
+        } => rsx! { ol { start: "{start}", {render_markdown(items)} } },
+        MarkdownNode::List { start: None, items } => rsx! { ul { {render_markdown(items)} } },
+        MarkdownNode::ListItem(children) => rsx! { li { {render_markdown(children)} } },
+        MarkdownNode::Link { href, children } => rsx! {
+            a { href: href.clone(), {render_markdown(children)} }
+        },
+        MarkdownNode::CodeBlock(code) => rsx! { pre { code { "{code}" } } },
+    }
+}
@@ -0,0 +1,142 @@
+use super::Alert;
+use crate::common::*;
+use dioxus::prelude::*;
+
+/// Handle to the alerts of the nearest `AlertProvider`.
+///
+/// Obtained with [`use_alert`]. The handle is `Copy` and every copy feeds the same queue.
+#[derive(Clone, Copy, PartialEq)]
+pub struct AlertHandle {
+    queue: Signal<AlertQueue>,
+    show_alert: Signal<bool>,
+}
+
+impl AlertHandle {
+    /// Shows an alert, or queues it behind the current one, and resolves to the user's answer.
+    pub fn fire(&self, config: AlertConfig) -> AlertFuture {
+        let mut queue = self.queue;
+        let mut show_alert = self.show_alert;
+        let idle = queue.peek().is_empty();
+        let future = queue.write().push(config);
+        if idle {
+            show_alert.set(true);
+        }
+        future
+    }
+}
+
+/// Returns the [`AlertHandle`] of the nearest `AlertProvider`.
+///
+/// # Panics
+/// Panics when called outside of an `AlertProvider`.
+pub fn use_alert() -> AlertHandle {
+    use_context::<AlertHandle>()
+}
+
+/// Properties for configuring the `AlertProvider` component.
+#[derive(Props, Clone, PartialEq)]
+pub struct AlertProviderProps {
+    /// The application content that can fire alerts through [`use_alert`].
+    pub children: Element,
+}
+
+/// Alert Provider Component
+///
+/// Renders the alerts fired through [`use_alert`] one at a time, in the order they were fired,
+/// and resolves each [`AlertFuture`] once its alert closes.
+///
+/// # Examples
+/// ```rust
+/// use alert_rs::common::AlertConfig;
+/// use alert_rs::dioxus::{use_alert, AlertProvider};
+/// use alert_rs::IconType;
+/// use dioxus::prelude::*;
+///
+/// fn DeleteButton() -> Element {
+///     let alert = use_alert();
+///     rsx! {
+///         button {
+///             onclick: move |_| async move {
+///                 let config = AlertConfig::new("This cannot be undone.")
+///                     .title("Delete file?")
+///                     .icon_type(IconType::Warning)
+///                     .timeout(0);
+///                 if alert.fire(config).await.is_confirmed() {
+///                     println!("Deleted!");
+///                 }
+///             },
+///             "Delete"
+///         }
+///     }
+/// }
+///
+/// fn App() -> Element {
+///     rsx! {
+///         AlertProvider { DeleteButton {} }
+///     }
+/// }
+/// ```
+#[component]
+pub fn AlertProvider(props: AlertProviderProps) -> Element {
+    let mut queue = use_signal(AlertQueue::new);
+    let mut show_alert = use_signal(|| false);
+    use_context_provider(|| AlertHandle { queue, show_alert });
+
+    let current = {
+        let queue = queue.read();
+        queue.current().cloned().zip(queue.current_id())
+    };
+
+    rsx! {
+        {props.children}
+        // Keyed by the queue entry, so no state carries over from the previous alert.
+        {current.map(|(config, id)| rsx! {
+            Alert {
+                key: "{id}",
+                show_alert,
+                title: config.title,
+                body: config.body,
+                icon_type: config.icon_type,
+                animated_icon: config.animated_icon,
+                image_url: config.image_url,
+                image_alt: config.image_alt,
+                image_width: config.image_width,
+                image_height: config.image_height,
+                image_fallback_url: config.image_fallback_url,
+                position: config.position,
+                timeout: config.timeout,
+                confirm_button_text: config.confirm_button_text,
+                cancel_button_text: config.cancel_button_text,
+                deny_button_text: config.deny_button_text,
+                show_confirm_button: config.show_confirm_button,
+                show_cancel_button: config.show_cancel_button,
+                show_deny_button: config.show_deny_button,
+                show_close_button: config.show_close_button,
+                actions: config.actions,
+                input: config.input,
+                input_placeholder: config.input_placeholder,
+                input_value: config.input_value,
+                input_attributes: config.input_attributes,
+                input_options: config.input_options,
+                input_label: config.input_label,
+                input_validator: config.input_validator.map(|validator| {
+                    Callback::new(move |value: String| validator.validate(&value))
+                }),
+                pre_confirm: config.pre_confirm.map(|pre_confirm| {
+                    Callback::new(move |value| pre_confirm.run(value))
+                }),
+                classes: config.classes,
+                styles: config.styles,
+                animation: config.animation,
+                animation_duration: config.animation_duration,
+                reduced_motion: config.reduced_motion,
+                initial_focus: config.initial_focus,
+                did_close: move |result| {
+                    if queue.write().resolve(result).is_some() {
+                        show_alert.set(true);
+                    }
+                },
+            }
+        })}
+    }
+}
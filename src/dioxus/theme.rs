@@ -0,0 +1,63 @@
+use crate::common::*;
+use dioxus::prelude::*;
+
+/// Properties for configuring the `AlertThemeProvider` component.
+#[derive(Props, Clone, PartialEq)]
+pub struct AlertThemeProviderProps {
+    /// The application content whose alerts and toasts follow the theme.
+    pub children: Element,
+
+    /// Which theme to apply.
+    ///
+    /// Defaults to `ThemeMode::Auto`, following the system color scheme.
+    #[props(default)]
+    pub mode: ThemeMode,
+
+    /// The theme used in light mode.
+    ///
+    /// Defaults to `AlertTheme::light()`.
+    #[props(default = AlertTheme::light())]
+    pub light: AlertTheme,
+
+    /// The theme used in dark mode.
+    ///
+    /// Defaults to `AlertTheme::dark()`.
+    #[props(default = AlertTheme::dark())]
+    pub dark: AlertTheme,
+}
+
+/// Alert Theme Provider Component
+///
+/// Sets the `--alert-rs-*` CSS variables of an [`AlertTheme`] for the whole page, so every
+/// alert and toast switches theme at once when `mode` changes.
+///
+/// # Examples
+/// ```rust
+/// use alert_rs::common::{AlertTheme, ThemeMode};
+/// use alert_rs::dioxus::{Alert, AlertThemeProvider};
+/// use dioxus::prelude::*;
+///
+/// fn App() -> Element {
+///     let show_alert = use_signal(|| true);
+///     let mut dark = use_signal(|| false);
+///     let mode = if dark() { ThemeMode::Dark } else { ThemeMode::Light };
+///
+///     rsx! {
+///         AlertThemeProvider {
+///             mode,
+///             light: AlertTheme::light().confirm_color("#7c3aed"),
+///             button { onclick: move |_| dark.toggle(), "Toggle theme" }
+///             Alert { show_alert, title: "Themed", timeout: 0 }
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn AlertThemeProvider(props: AlertThemeProviderProps) -> Element {
+    let stylesheet = props.mode.stylesheet(&props.light, &props.dark);
+
+    rsx! {
+        style { {stylesheet} }
+        {props.children}
+    }
+}
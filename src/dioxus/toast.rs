@@ -0,0 +1,205 @@
+use crate::common::*;
+use dioxus::prelude::*;
+use gloo::timers::callback::Timeout;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// Handle to the toasts of the nearest `ToastProvider`.
+///
+/// Obtained with [`use_toast`]. The handle is `Copy` and every copy controls the same stacks.
+#[derive(Clone, Copy, PartialEq)]
+pub struct ToastHandle {
+    manager: Signal<ToastManager>,
+}
+
+impl ToastHandle {
+    /// Shows a toast, or queues it if its stack is full, and returns its id.
+    pub fn push(&self, toast: Toast) -> ToastId {
+        let mut manager = self.manager;
+        let id = manager.write().push(toast);
+        id
+    }
+
+    /// Removes a toast before its timeout expires.
+    pub fn dismiss(&self, id: ToastId) {
+        let mut manager = self.manager;
+        if manager.peek().contains(id) {
+            manager.write().dismiss(id);
+        }
+    }
+
+    /// Removes every toast.
+    pub fn clear(&self) {
+        let mut manager = self.manager;
+        manager.write().clear();
+    }
+}
+
+/// Returns the [`ToastHandle`] of the nearest `ToastProvider`.
+///
+/// # Panics
+/// Panics when called outside of a `ToastProvider`.
+pub fn use_toast() -> ToastHandle {
+    use_context::<ToastHandle>()
+}
+
+/// Properties for configuring the `ToastProvider` component.
+#[derive(Props, Clone, PartialEq)]
+pub struct ToastProviderProps {
+    /// The application content that can push toasts through [`use_toast`].
+    pub children: Element,
+
+    /// The gap in pixels between stacked toasts.
+    ///
+    /// Defaults to `10`.
+    #[props(default = 10)]
+    pub gap: u32,
+
+    /// The number of toasts shown at once per position; the rest are queued.
+    ///
+    /// Defaults to `5`.
+    #[props(default = 5)]
+    pub max_visible: usize,
+
+    /// Custom CSS class for styling each toast.
+    ///
+    /// Defaults to an empty string.
+    #[props(into, default = "".to_string())]
+    pub toast_class: String,
+
+    /// Default style for each toast.
+    ///
+    /// Defaults to `DEFAULT_TOAST_STYLE`.
+    #[props(into, default = DEFAULT_TOAST_STYLE.to_string())]
+    pub toast_style: String,
+
+    /// Default style for the toast title.
+    ///
+    /// Defaults to `DEFAULT_TOAST_TITLE_STYLE`.
+    #[props(into, default = DEFAULT_TOAST_TITLE_STYLE.to_string())]
+    pub title_style: String,
+
+    /// Default style for the toast body.
+    ///
+    /// Defaults to `DEFAULT_MESSAGE_STYLE`.
+    #[props(into, default = DEFAULT_MESSAGE_STYLE.to_string())]
+    pub message_style: String,
+
+    /// Default style for the close button of each toast.
+    ///
+    /// Defaults to `DEFAULT_CLOSE_BUTTON_STYLE`.
+    #[props(into, default = DEFAULT_CLOSE_BUTTON_STYLE.to_string())]
+    pub close_button_style: String,
+}
+
+/// Toast Provider Component
+///
+/// Hosts a [`ToastManager`] for its children and renders one stack of toasts per `Position`.
+/// Descendants call [`use_toast`] to push toasts; each toast is removed when its own `timeout`
+/// expires or when its close button is clicked.
+///
+/// # Examples
+/// ```rust
+/// use alert_rs::common::Toast;
+/// use alert_rs::dioxus::{use_toast, ToastProvider};
+/// use alert_rs::IconType;
+/// use dioxus::prelude::*;
+///
+/// fn SaveButton() -> Element {
+///     let toast = use_toast();
+///     rsx! {
+///         button {
+///             onclick: move |_| {
+///                 toast.push(Toast::new("Your changes were saved.").icon_type(IconType::Success));
+///             },
+///             "Save"
+///         }
+///     }
+/// }
+///
+/// fn App() -> Element {
+///     rsx! {
+///         ToastProvider {
+///             gap: 8,
+///             max_visible: 3,
+///             SaveButton {}
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn ToastProvider(props: ToastProviderProps) -> Element {
+    let mut manager = use_signal(ToastManager::new);
+    let handle = use_context_provider(|| ToastHandle { manager });
+    let timers = use_hook(|| Rc::new(RefCell::new(HashMap::<ToastId, Timeout>::new())));
+
+    let gap = props.gap;
+    let max_visible = props.max_visible;
+    use_effect(use_reactive!(|(gap, max_visible)| {
+        manager.write().configure(gap, max_visible);
+    }));
+
+    use_effect(move || {
+        let timeouts = manager.read().visible_timeouts();
+        let mut timers = timers.borrow_mut();
+        timers.retain(|id, _| timeouts.iter().any(|(visible, _)| visible == id));
+        for (id, millis) in timeouts {
+            timers
+                .entry(id)
+                .or_insert_with(|| Timeout::new(millis, move || handle.dismiss(id)));
+        }
+    });
+
+    let stacks = {
+        let manager = manager.read();
+        manager
+            .stacks()
+            .into_iter()
+            .map(|(position, toasts)| {
+                let toasts = toasts
+                    .into_iter()
+                    .map(|(id, toast)| (id, toast.clone()))
+                    .collect::<Vec<_>>();
+                (
+                    manager.stack_style(&position),
+                    manager.stack_aria_live(&position),
+                    toasts,
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+
+    rsx! {
+        {props.children}
+        for (stack_style, aria_live, toasts) in stacks {
+            div {
+                style: stack_style,
+                aria_live,
+                aria_relevant: "additions text",
+                for (id, toast) in toasts {
+                    div {
+                        key: "{id.get()}",
+                        class: "{props.toast_class}",
+                        style: format!(
+                            "{} border-left: 4px solid {};",
+                            props.toast_style,
+                            toast.icon_type.default_color()
+                        ),
+                        aria_atomic: "true",
+                        button {
+                            style: "{props.close_button_style}",
+                            onclick: move |_| handle.dismiss(id),
+                            aria_label: "Close",
+                            "X"
+                        }
+                        if !toast.title.is_empty() {
+                            strong { style: "{props.title_style}", "{toast.title}" }
+                        }
+                        p { style: "{props.message_style}", "{toast.body}" }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -3,6 +3,151 @@ use leptos::{ev::MouseEvent, prelude::*, *};
 use std::time::Duration;
 use web_sys::window;
 
+#[cfg(feature = "markdown")]
+mod markdown;
+mod queue;
+mod theme;
+mod toast;
+
+pub use queue::*;
+pub use theme::*;
+pub use toast::*;
+
+/// Resolves a text, class or style prop: the value set on the alert, else `default`, else `builtin`.
+fn with_default(
+    value: MaybeProp<String>,
+    builtin: &'static str,
+    default: Option<String>,
+) -> Signal<String> {
+    Signal::derive(move || AlertDefaults::resolve(value.get(), default.clone(), builtin.into()))
+}
+
+/// Resolves any other prop: the value set on the alert, else `default`, else `builtin`.
+fn resolve_prop<T>(value: MaybeProp<T>, default: Option<T>, builtin: T) -> Signal<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    Signal::derive(move || AlertDefaults::resolve(value.get(), default.clone(), builtin.clone()))
+}
+
+/// Performs the effects emitted by the shared [`AlertMachine`].
+#[derive(Clone, Copy)]
+struct Driver {
+    machine: StoredValue<AlertMachine>,
+    timer: StoredValue<Option<TimeoutHandle>>,
+    show_alert: WriteSignal<bool>,
+    will_open: Callback<()>,
+    did_open: Callback<()>,
+    did_close: Callback<AlertResult>,
+    on_confirm: Callback<()>,
+    on_close: Callback<()>,
+    on_cancel: Callback<()>,
+    on_deny: Callback<()>,
+    on_action: Callback<String>,
+    input_validator: Option<Callback<String, Result<(), String>>>,
+    validation_message: RwSignal<Option<String>>,
+    pre_confirm: Option<Callback<Option<String>, PreConfirmFuture>>,
+    loading: RwSignal<bool>,
+    leaving: RwSignal<bool>,
+    native: bool,
+}
+
+impl Driver {
+    fn validate(self, value: &str) -> Result<(), String> {
+        match self.input_validator {
+            Some(validator) => validator.run(value.to_string()),
+            None => Ok(()),
+        }
+    }
+
+    fn confirm(self) {
+        let value = self
+            .machine
+            .try_with_value(|machine| machine.value().map(str::to_string))
+            .flatten();
+        if let Some(Err(message)) = value.map(|value| self.validate(&value)) {
+            self.send(AlertEvent::Invalid(message));
+        } else {
+            self.send(AlertEvent::Confirm);
+        }
+    }
+
+    fn send(self, event: AlertEvent) {
+        let effects = self
+            .machine
+            .try_update_value(|machine| machine.send(event))
+            .unwrap_or_default();
+        for effect in effects {
+            match effect {
+                AlertEffect::WillOpen => self.will_open.run(()),
+                AlertEffect::DidOpen => self.did_open.run(()),
+                AlertEffect::StartTimer(millis) => {
+                    let handle = set_timeout_with_handle(
+                        move || self.send(AlertEvent::Timeout),
+                        Duration::from_millis(millis.into()),
+                    )
+                    .ok();
+                    let previous = self
+                        .timer
+                        .try_update_value(|timer| std::mem::replace(timer, handle))
+                        .flatten();
+                    if let Some(previous) = previous {
+                        previous.clear();
+                    }
+                }
+                AlertEffect::ClearTimer => {
+                    if let Some(handle) = self.timer.try_update_value(Option::take).flatten() {
+                        handle.clear();
+                    }
+                }
+                AlertEffect::RunPreConfirm(value) => {
+                    let future = self.pre_confirm.map(|hook| hook.run(value));
+                    task::spawn_local(async move {
+                        let result = match future {
+                            Some(future) => future.await,
+                            None => Ok(()),
+                        };
+                        // A native dialog is already gone, so report the error and close.
+                        let failed = result.as_ref().err().cloned();
+                        self.send(AlertEvent::PreConfirmed(result));
+                        if let (true, Some(message)) = (self.native, failed) {
+                            if let Some(win) = window() {
+                                let _ = win.alert_with_message(&message);
+                            }
+                            self.send(AlertEvent::Close);
+                        }
+                    });
+                }
+                AlertEffect::Loading(loading) => self.loading.set(loading),
+                AlertEffect::Confirm => self.on_confirm.run(()),
+                AlertEffect::Deny => self.on_deny.run(()),
+                AlertEffect::Cancel => self.on_cancel.run(()),
+                AlertEffect::Close => self.on_close.run(()),
+                AlertEffect::Action(id) => self.on_action.run(id),
+                AlertEffect::ValidationMessage(message) => self.validation_message.set(message),
+                AlertEffect::Hide => self.show_alert.set(false),
+                AlertEffect::StartExit(millis) => {
+                    self.leaving.set(true);
+                    let handle = set_timeout_with_handle(
+                        move || self.send(AlertEvent::Exited),
+                        Duration::from_millis(millis.into()),
+                    )
+                    .ok();
+                    let previous = self
+                        .timer
+                        .try_update_value(|timer| std::mem::replace(timer, handle))
+                        .flatten();
+                    if let Some(previous) = previous {
+                        previous.clear();
+                    }
+                }
+                AlertEffect::Unmount => self.leaving.set(false),
+                AlertEffect::DidClose(result) => self.did_close.run(result),
+            }
+        }
+    }
+}
+
 /// Alert Component
 ///
 /// A Leptos component for displaying customizable alerts with various behaviors and styling options.
@@ -14,45 +159,96 @@ use web_sys::window;
 /// The component uses the `AlertProps` struct for its properties. Key properties include:
 ///
 /// - **show_alert**: State handle controlling the visibility of the alert (`Signal<bool>`). This is a required prop to manage alert visibility.
-/// - **body**: The content of the alert message (`&'static str`). Default: `""`.
-/// - **title**: The title text of the alert (`&'static str`). Default: `"Info"`.
-/// - **timeout**: Timeout duration in milliseconds before the alert auto-closes (`u32`). Default: `2500`.
-/// - **confirm_button_text**: Text for the confirm button (`&'static str`). Default: `"Okay"`.
-/// - **cancel_button_text**: Text for the cancel button (`&'static str`). Default: `"Cancel"`.
-/// - **show_confirm_button**: Whether to display the confirm button (`bool`). Default: `true`.
-/// - **show_cancel_button**: Whether to display the cancel button (`bool`). Default: `true`.
-/// - **show_close_button**: Whether to display the close button (`bool`). Default: `false`.
-/// - **position**: The position of the alert on the screen (`Position`). Default: `Position::TopRight`.
-/// - **icon_type**: The icon type displayed with the alert (`IconType`). Default: `IconType::Info`.
-/// - **icon_color**: The color of the icon (`&'static str`). Default: `""`.
-/// - **icon_width**: The width of the icon (`&'static str`). Default: `"50"`.
-/// - **alert_class**: CSS class for styling the alert container (`&'static str`). Default: `""`.
-/// - **icon_class**: CSS class for styling the icon (`&'static str`). Default: `""`.
-/// - **confirm_button_class**: CSS class for styling the confirm button (`&'static str`). Default: `""`.
-/// - **cancel_button_class**: CSS class for styling the cancel button (`&'static str`). Default: `""`.
-/// - **title_class**: CSS class for styling the alert title (`&'static str`). Default: `""`.
-/// - **body_class**: CSS class for styling the message text in the alert (`&'static str`). Default: `""`.
-/// - **alert_style**: Default inline styles for the alert (`&'static str`). Default: `DEFAULT_ALERT_STYLE`.
-/// - **close_button_style**: Default inline styles for the close button (`&'static str`). Default: `DEFAULT_CLOSE_BUTTON_STYLE`.
-/// - **confirm_button_style**: Default inline styles for the confirm button (`&'static str`). Default: `DEFAULT_CONFIRM_BUTTON_STYLE`.
-/// - **cancel_button_style**: Default inline styles for the cancel button (`&'static str`). Default: `DEFAULT_CANCEL_BUTTON_STYLE`.
-/// - **icon_style**: Default inline styles for the icon (`&'static str`). Default: `DEFAULT_ICON_STYLE`.
-/// - **title_style**: Default inline styles for the title text (`&'static str`). Default: `DEFAULT_TITLE_STYLE`.
-/// - **separator_style**: Default inline styles for the separator (`&'static str`). Default: `DEFAULT_SEPARATOR_STYLE`.
-/// - **message_style**: Default inline styles for the message text (`&'static str`). Default: `DEFAULT_MESSAGE_STYLE`.
+/// - **body**: The content of the alert message (`Signal<String>`). Default: `""`.
+/// - **html_body**: HTML snippet rendered below the body text, sanitized with the `sanitize` feature and shown as text without it (`Signal<String>`). Default: `""`.
+/// - **children**: Rich content rendered below the body text (`ChildrenFn`). Default: nothing.
+/// - **header**: Content rendered above the icon (`ViewFn`). Default: nothing.
+/// - **footer**: Content rendered below the buttons (`ViewFn`). Default: nothing.
+/// - **title**: The title text of the alert (`Signal<String>`). Default: `"Info"`.
+/// - **id**: The `id` of the alert box and prefix of its title and body `id`s (`String`). Default: a generated `alert-rs-N`.
+/// - **timeout**: Timeout duration in milliseconds before the alert auto-closes (`MaybeProp<u64>`). Default: `2500`.
+/// - **confirm_button_text**: Text for the confirm button (`MaybeProp<String>`). Default: `"Okay"`.
+/// - **cancel_button_text**: Text for the cancel button (`MaybeProp<String>`). Default: `"Cancel"`.
+/// - **deny_button_text**: Text for the deny button (`MaybeProp<String>`). Default: `"No"`.
+/// - **show_confirm_button**: Whether to display the confirm button (`MaybeProp<bool>`). Default: `true`.
+/// - **show_cancel_button**: Whether to display the cancel button (`MaybeProp<bool>`). Default: `true`.
+/// - **show_deny_button**: Whether to display the deny button (`MaybeProp<bool>`). Default: `false`.
+/// - **actions**: Extra buttons after the built-in ones (`Vec<AlertAction>`). Default: `[]`.
+/// - **show_close_button**: Whether to display the close button (`MaybeProp<bool>`). Default: `false`.
+/// - **position**: The position of the alert on the screen (`MaybeProp<Position>`). Default: `Position::TopRight`.
+/// - **icon_type**: The icon type displayed with the alert (`MaybeProp<IconType>`). Default: `IconType::Info`. Built-in icons render only with their `icon-*` feature enabled (all of them by default).
+/// - **icon_color**: The color of the icon (`Signal<String>`). Default: `""`.
+/// - **icon_width**: The width of the icon (`Signal<String>`). Default: `"50"`.
+/// - **animated_icon**: Whether to render the animated SweetAlert-style icon (`bool`). Default: `false`.
+/// - **icon**: Node rendered as a `IconType::Custom(CustomIcon::Node)` icon (`ViewFn`). Default: nothing.
+/// - **image_url**: URL of a lazy-loaded image shown above the title (`Signal<String>`). Default: `""`.
+/// - **image_alt**: Alternative text of the image (`Signal<String>`). Default: `""`.
+/// - **image_width**: Width of the image (`Signal<String>`). Default: `""`.
+/// - **image_height**: Height of the image (`Signal<String>`). Default: `""`.
+/// - **image_fallback_url**: Image shown when `image_url` fails to load (`Signal<String>`). Default: `""`.
+/// - **classes**: Every `*_class` prop in one value; flat props win (`AlertClasses`). Default: empty.
+/// - **styles**: Every `*_style` prop in one value; flat props win (`AlertStyles`). Default: empty.
+/// - **alert_class**: CSS class for styling the alert container (`MaybeProp<String>`). Default: `""`.
+/// - **icon_class**: CSS class for styling the icon (`MaybeProp<String>`). Default: `""`.
+/// - **image_class**: CSS class for styling the image (`MaybeProp<String>`). Default: `""`.
+/// - **confirm_button_class**: CSS class for styling the confirm button (`MaybeProp<String>`). Default: `""`.
+/// - **cancel_button_class**: CSS class for styling the cancel button (`MaybeProp<String>`). Default: `""`.
+/// - **deny_button_class**: CSS class for styling the deny button (`MaybeProp<String>`). Default: `""`.
+/// - **title_class**: CSS class for styling the alert title (`MaybeProp<String>`). Default: `""`.
+/// - **body_class**: CSS class for styling the message text in the alert (`MaybeProp<String>`). Default: `""`.
+/// - **alert_style**: Default inline styles for the alert (`MaybeProp<String>`). Default: `DEFAULT_ALERT_STYLE`.
+/// - **close_button_style**: Default inline styles for the close button (`MaybeProp<String>`). Default: `DEFAULT_CLOSE_BUTTON_STYLE`.
+/// - **confirm_button_style**: Default inline styles for the confirm button (`MaybeProp<String>`). Default: `DEFAULT_CONFIRM_BUTTON_STYLE`.
+/// - **cancel_button_style**: Default inline styles for the cancel button (`MaybeProp<String>`). Default: `DEFAULT_CANCEL_BUTTON_STYLE`.
+/// - **deny_button_style**: Default inline styles for the deny button (`MaybeProp<String>`). Default: `DEFAULT_DENY_BUTTON_STYLE`.
+/// - **icon_style**: Default inline styles for the icon (`MaybeProp<String>`). Default: `DEFAULT_ICON_STYLE`.
+/// - **image_style**: Default inline styles for the image (`MaybeProp<String>`). Default: `DEFAULT_IMAGE_STYLE`.
+/// - **title_style**: Default inline styles for the title text (`MaybeProp<String>`). Default: `DEFAULT_TITLE_STYLE`.
+/// - **separator_style**: Default inline styles for the separator (`MaybeProp<String>`). Default: `DEFAULT_SEPARATOR_STYLE`.
+/// - **message_style**: Default inline styles for the message text (`MaybeProp<String>`). Default: `DEFAULT_MESSAGE_STYLE`.
 /// - **native**: Whether to use the native alert implementation (`bool`). Default: `false`.
 /// - **will_open**: Callback triggered before the alert opens (`Callback<()>`). Default: no-op.
 /// - **did_open**: Callback triggered after the alert opens (`Callback<()>`). Default: no-op.
-/// - **did_close**: Callback triggered after the alert closes (`Callback<()>`). Default: no-op.
+/// - **input**: The prompt input shown below the body (`InputType`). Default: `InputType::None`.
+/// - **input_placeholder**: Placeholder of the prompt input (`Signal<String>`). Default: `""`.
+/// - **input_value**: Initial value of the prompt input (`Signal<String>`). Default: `""`.
+/// - **input_attributes**: Extra HTML attributes of the prompt input (`Vec<(String, String)>`). Default: `[]`.
+/// - **input_options**: `(value, label)` options of a select or radio input (`Vec<(String, String)>`). Default: `[]`.
+/// - **input_label**: Label next to a checkbox input (`Signal<String>`). Default: `""`.
+/// - **input_label_style**: Default inline styles for radio and checkbox labels (`MaybeProp<String>`). Default: `DEFAULT_INPUT_LABEL_STYLE`.
+/// - **input_validator**: Check run on the input value on confirm; an `Err` keeps the alert open (`Option<Callback<String, Result<(), String>>>`). Default: `None`.
+/// - **pre_confirm**: Async check run on confirm with a loading state; an `Err` keeps the alert open (`Option<Callback<Option<String>, PreConfirmFuture>>`). Default: `None`.
+/// - **spinner_style**: Default inline styles for the loading spinner (`MaybeProp<String>`). Default: `DEFAULT_SPINNER_STYLE`.
+/// - **validation_message_class**: CSS class for styling the validation message (`MaybeProp<String>`). Default: `""`.
+/// - **validation_message_style**: Default inline styles for the validation message (`MaybeProp<String>`). Default: `DEFAULT_VALIDATION_MESSAGE_STYLE`.
+/// - **input_class**: CSS class for styling the prompt input (`MaybeProp<String>`). Default: `""`.
+/// - **input_style**: Default inline styles for the prompt input (`MaybeProp<String>`). Default: `DEFAULT_INPUT_STYLE`.
+/// - **close_on_backdrop_click**: Whether clicking the backdrop closes the alert (`MaybeProp<bool>`). Default: `false`.
+/// - **close_on_escape**: Whether pressing Escape closes the alert (`MaybeProp<bool>`). Default: `true`.
+/// - **initial_focus**: The element focused when the alert opens; focus is trapped inside and restored on close (`MaybeProp<InitialFocus>`). Default: `InitialFocus::Confirm`.
+/// - **backdrop_style**: Default inline styles for the backdrop (`MaybeProp<String>`). Default: `DEFAULT_BACKDROP_STYLE`.
+/// - **animation**: The enter and exit animation (`MaybeProp<AlertAnimation>`). Default: `AlertAnimation::None`.
+/// - **animation_duration**: Duration of the enter and exit animations in milliseconds (`MaybeProp<u32>`). Default: `300`.
+/// - **enter_class**: CSS class added to the alert box while it is open (`Signal<String>`). Default: `""`.
+/// - **exit_class**: CSS class added to the alert box while it leaves (`Signal<String>`). Default: `""`.
+/// - **reduced_motion**: Whether the alert honors the system's reduced motion setting (`MaybeProp<ReducedMotion>`). Default: `ReducedMotion::System`.
+/// - **did_close**: Callback triggered after the alert closes, with the reason (`Callback<AlertResult>`). Default: no-op.
 /// - **on_confirm**: Callback triggered when the confirm button is clicked (`Callback<()>`). Default: no-op.
 /// - **on_close**: Callback triggered when the close button is clicked (`Callback<()>`). Default: no-op.
 /// - **on_cancel**: Callback triggered when the cancel button is clicked (`Callback<()>`). Default: no-op.
+/// - **on_deny**: Callback triggered when the deny button is clicked (`Callback<()>`). Default: no-op.
+/// - **on_action**: Callback triggered when an action is clicked, with its id (`Callback<String>`). Default: no-op.
 ///
 /// # Features
 /// - Customizable alert message and title.
+/// - Rich content through `children`, `header` and `footer`.
 /// - Configurable buttons (confirm, cancel, and close).
 /// - Adjustable timeout duration for auto-closing.
 /// - Dynamic positioning and icon customization.
+/// - Animated SweetAlert-style icons with `animated_icon`, shared by every framework.
+/// - Lazy-loaded images with `image_url`, falling back to `image_fallback_url` when they fail to load.
+/// - Announced as a `dialog` or `alertdialog` labelled by its title and described by its body.
+/// - Traps keyboard focus while open, starting at `initial_focus`, and restores it on close.
 /// - Supports native browser alert functionality with `native`.
 /// - Callbacks for various actions, such as opening, closing, and button clicks.
 ///
@@ -100,6 +296,415 @@ use web_sys::window;
 /// }
 /// ```
 ///
+/// ## Reactive Text
+/// ```rust
+/// use leptos::prelude::*;
+/// use alert_rs::leptos::Alert;
+///
+/// #[component]
+/// pub fn DeletedFiles() -> impl IntoView {
+///     let show_alert = signal(false);
+///     let count = RwSignal::new(3);
+///     view! {
+///         <>
+///             <button on:click={move |_| show_alert.1.set(true)}>{ "Delete" }</button>
+///             <Alert
+///                 show_alert={show_alert}
+///                 title="Done"
+///                 body=Signal::derive(move || format!("Deleted {} files", count.get()))
+///             />
+///         </>
+///     }
+/// }
+/// ```
+///
+/// ## Save / Don't Save / Cancel
+/// ```rust
+/// use leptos::prelude::*;
+/// use alert_rs::leptos::Alert;
+///
+/// #[component]
+/// pub fn UnsavedChanges() -> impl IntoView {
+///     let show_alert = signal(false);
+///     view! {
+///         <>
+///             <button on:click={move |_| show_alert.1.set(true)}>{ "Close Document" }</button>
+///             <Alert
+///                 show_alert={show_alert}
+///                 title="Save changes?"
+///                 timeout={0}
+///                 confirm_button_text="Save"
+///                 deny_button_text="Don't save"
+///                 show_deny_button={true}
+///                 on_confirm={Callback::from(move || log::info!("Saved!"))}
+///                 on_deny={Callback::from(move || log::info!("Discarded!"))}
+///             />
+///         </>
+///     }
+/// }
+/// ```
+///
+/// ## Actions
+/// ```rust
+/// use leptos::prelude::*;
+/// use alert_rs::common::{ActionVariant, AlertAction};
+/// use alert_rs::leptos::Alert;
+///
+/// #[component]
+/// pub fn UploadFailed() -> impl IntoView {
+///     let show_alert = signal(false);
+///     let actions = vec![
+///         AlertAction::new("retry", "Retry"),
+///         AlertAction::new("logs", "Open logs")
+///             .variant(ActionVariant::Secondary)
+///             .closes(false),
+///         AlertAction::new("dismiss", "Dismiss").variant(ActionVariant::Danger),
+///     ];
+///     view! {
+///         <>
+///             <button on:click={move |_| show_alert.1.set(true)}>{ "Upload" }</button>
+///             <Alert
+///                 show_alert={show_alert}
+///                 title="Upload failed"
+///                 timeout={0}
+///                 show_confirm_button={false}
+///                 show_cancel_button={false}
+///                 actions=actions
+///                 on_action=Callback::new(|id: String| log::info!("Clicked {id}"))
+///             />
+///         </>
+///     }
+/// }
+/// ```
+///
+/// ## Rich Content
+/// ```rust
+/// use leptos::prelude::*;
+/// use alert_rs::leptos::Alert;
+///
+/// #[component]
+/// pub fn TermsAlert() -> impl IntoView {
+///     let show_alert = signal(false);
+///     view! {
+///         <>
+///             <button on:click={move |_| show_alert.1.set(true)}>{ "Sign Up" }</button>
+///             <Alert
+///                 show_alert={show_alert}
+///                 title="Terms of Service"
+///                 timeout={0}
+///                 header=|| view! { <small>{"Step 2 of 3"}</small> }
+///                 footer=|| view! { <a href="/privacy">{"Privacy policy"}</a> }
+///             >
+///                 <ul>
+///                     <li>{"Be kind."}</li>
+///                     <li>{"No spam."}</li>
+///                 </ul>
+///             </Alert>
+///         </>
+///     }
+/// }
+/// ```
+///
+/// ## HTML Body
+/// ```rust
+/// use leptos::prelude::*;
+/// use alert_rs::leptos::Alert;
+///
+/// #[component]
+/// pub fn LegacyAlert() -> impl IntoView {
+///     let show_alert = signal(false);
+///     // Messages coming from the backend as HTML, like SweetAlert2's `html:` option.
+///     let html_body = "Your <b>trial</b> ends in <i>3 days</i>.<script>alert(1)</script>";
+///     view! {
+///         <>
+///             <button on:click={move |_| show_alert.1.set(true)}>{ "Show" }</button>
+///             <Alert show_alert={show_alert} title="Heads up" html_body=html_body />
+///         </>
+///     }
+/// }
+/// ```
+///
+/// ## Grouped Classes and Styles
+/// ```rust
+/// use leptos::prelude::*;
+/// use alert_rs::common::{AlertClasses, AlertStyles};
+/// use alert_rs::leptos::Alert;
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     let show_alert = signal(true);
+///     let classes = AlertClasses::default()
+///         .alert("rounded-lg shadow-xl p-6 bg-white")
+///         .confirm_button("btn btn-primary");
+///     let styles = AlertStyles::default().title("font-size: 20px;");
+///     view! {
+///         <Alert
+///             show_alert=show_alert
+///             title="Grouped"
+///             classes=classes.clone().merge(AlertClasses::default().confirm_button("btn btn-danger"))
+///             styles=styles
+///             // Flat props still win over the grouped ones.
+///             title_class="text-xl"
+///         />
+///     }
+/// }
+/// ```
+///
+/// ## Animations
+/// ```rust
+/// use leptos::prelude::*;
+/// use alert_rs::common::AlertAnimation;
+/// use alert_rs::leptos::Alert;
+/// use alert_rs::{IconType, Position};
+///
+/// #[component]
+/// pub fn SlideAlert() -> impl IntoView {
+///     let show_alert = signal(false);
+///     view! {
+///         <button on:click={move |_| show_alert.1.set(true)}>{ "Show" }</button>
+///         <Alert
+///             show_alert=show_alert
+///             title="Sliding in"
+///             icon_type=IconType::Success
+///             animated_icon=true
+///             position=Position::BottomRight
+///             animation=AlertAnimation::Slide
+///             animation_duration=500
+///             // Fired once the exit animation is over.
+///             did_close=Callback::new(|_| log::info!("Gone"))
+///         />
+///         // Or bring your own keyframes, e.g. from Animate.css.
+///         <Alert
+///             show_alert=show_alert
+///             animation=AlertAnimation::None
+///             enter_class="animate__animated animate__backInDown"
+///             exit_class="animate__animated animate__backOutDown"
+///         />
+///     }
+/// }
+/// ```
+///
+/// ## Custom Icons
+/// ```rust
+/// use leptos::prelude::*;
+/// use alert_rs::common::CustomIcon;
+/// use alert_rs::leptos::Alert;
+/// use alert_rs::IconType;
+///
+/// #[component]
+/// pub fn BrandedAlerts() -> impl IntoView {
+///     let show_alert = signal(true);
+///     view! {
+///         <Alert
+///             show_alert=show_alert
+///             title="New message"
+///             icon_type=IconType::Custom(CustomIcon::Svg {
+///                 path: "M64 112c-8.8 0-16 7.2-16 16v22.1L220.5 291.7c20.7 17 50.4 17 71.1 0L464 150.1V128c0-8.8-7.2-16-16-16H64z".into(),
+///                 view_box: "0 0 512 512".into(),
+///             })
+///             icon_color="purple"
+///         />
+///         <Alert
+///             show_alert=show_alert
+///             title="Welcome back"
+///             icon_type=IconType::Custom(CustomIcon::Image("/assets/avatar.png".into()))
+///         />
+///         <Alert
+///             show_alert=show_alert
+///             title="Level up"
+///             icon_type=IconType::Custom(CustomIcon::Node)
+///             icon=|| view! { <span style="font-size: 48px;">"🏆"</span> }
+///         />
+///         <Alert show_alert=show_alert title="No icon" icon_type=IconType::None />
+///     }
+/// }
+/// ```
+///
+/// ## Image
+/// ```rust
+/// use leptos::prelude::*;
+/// use alert_rs::leptos::Alert;
+/// use alert_rs::IconType;
+///
+/// #[component]
+/// pub fn WhatsNew() -> impl IntoView {
+///     let show_alert = signal(true);
+///     view! {
+///         <Alert
+///             show_alert=show_alert
+///             title="What's new"
+///             body="Dark mode is here."
+///             icon_type=IconType::None
+///             image_url="/assets/dark-mode.png"
+///             image_alt="The dashboard in dark mode"
+///             image_width="400"
+///             image_height="200"
+///             image_fallback_url="/assets/placeholder.png"
+///             image_class="rounded-lg"
+///             timeout=0
+///         />
+///     }
+/// }
+/// ```
+///
+/// ## Accessibility
+/// ```rust
+/// use leptos::prelude::*;
+/// use alert_rs::leptos::Alert;
+/// use alert_rs::IconType;
+///
+/// #[component]
+/// pub fn DeleteFiles() -> impl IntoView {
+///     let show_alert = signal(true);
+///     // Rendered as `role="alertdialog"` with `aria-labelledby="delete-files-title"`
+///     // and `aria-describedby="delete-files-body"`.
+///     view! {
+///         <Alert
+///             show_alert=show_alert
+///             id="delete-files"
+///             title="Delete files?"
+///             body="This cannot be undone."
+///             icon_type=IconType::Warning
+///         />
+///     }
+/// }
+/// ```
+///
+/// ## Initial Focus
+/// ```rust
+/// use leptos::prelude::*;
+/// use alert_rs::common::InitialFocus;
+/// use alert_rs::leptos::Alert;
+/// use alert_rs::IconType;
+///
+/// #[component]
+/// pub fn DeleteAccount() -> impl IntoView {
+///     let show_alert = signal(true);
+///     // Focus starts on Cancel, so pressing Enter right away does not delete anything.
+///     view! {
+///         <Alert
+///             show_alert=show_alert
+///             title="Delete your account?"
+///             icon_type=IconType::Warning
+///             initial_focus=InitialFocus::Cancel
+///             timeout=0
+///         />
+///     }
+/// }
+/// ```
+///
+/// ## Reduced Motion
+/// ```rust
+/// use leptos::prelude::*;
+/// use alert_rs::common::ReducedMotion;
+/// use alert_rs::leptos::Alert;
+///
+/// #[component]
+/// pub fn SavedAlert(reduce_motion: bool) -> impl IntoView {
+///     let show_alert = signal(true);
+///     // Follows `prefers-reduced-motion` by default; an in-app setting can force it.
+///     let reduced_motion = if reduce_motion {
+///         ReducedMotion::Always
+///     } else {
+///         ReducedMotion::System
+///     };
+///     view! {
+///         <Alert show_alert=show_alert title="Saved" reduced_motion=reduced_motion />
+///     }
+/// }
+/// ```
+///
+/// ## App-wide Defaults
+/// ```rust
+/// use leptos::prelude::*;
+/// use alert_rs::common::{AlertClasses, AlertDefaults};
+/// use alert_rs::leptos::Alert;
+/// use alert_rs::Position;
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     let show_alert = signal(true);
+///     provide_context(
+///         AlertDefaults::default()
+///             .position(Position::TopCenter)
+///             .confirm_button_text("Got it")
+///             .classes(AlertClasses::default().alert("rounded-lg shadow-xl p-6 bg-white")),
+///     );
+///     view! {
+///         // Uses the app-wide position, button text and class.
+///         <Alert show_alert=show_alert title="Saved" />
+///     }
+/// }
+/// ```
+///
+/// ## Prompt
+/// ```rust
+/// use leptos::prelude::*;
+/// use alert_rs::common::AlertResult;
+/// use alert_rs::leptos::Alert;
+/// use alert_rs::InputType;
+///
+/// #[component]
+/// pub fn NamePrompt() -> impl IntoView {
+///     let show_alert = create_signal(false);
+///     let did_close = Callback::new(|result: AlertResult| {
+///         if let Some(name) = result.value {
+///             log::info!("Hello, {name}!");
+///         }
+///     });
+///     let input_validator = Callback::new(|name: String| {
+///         if name.trim().is_empty() {
+///             Err("Please enter a name".to_string())
+///         } else {
+///             Ok(())
+///         }
+///     });
+///     view! {
+///         <>
+///             <button on:click={move |_| show_alert.1.set(true)}>{ "Ask Name" }</button>
+///             <Alert
+///                 show_alert={show_alert}
+///                 title="What is your name?"
+///                 input={InputType::Text}
+///                 input_placeholder="Ferris"
+///                 input_validator=input_validator
+///                 timeout={0}
+///                 did_close={did_close}
+///             />
+///         </>
+///     }
+/// }
+/// ```
+///
+/// ## Async Confirmation
+/// ```rust
+/// use leptos::prelude::*;
+/// use alert_rs::common::PreConfirmFuture;
+/// use alert_rs::leptos::Alert;
+///
+/// #[component]
+/// pub fn SaveAlert() -> impl IntoView {
+///     let show_alert = signal(false);
+///     let pre_confirm = Callback::new(|_: Option<String>| -> PreConfirmFuture {
+///         Box::pin(async move {
+///             // e.g. `gloo::net::http::Request::post("/api/save").send().await`
+///             Err("The server is unavailable".to_string())
+///         })
+///     });
+///     view! {
+///         <>
+///             <button on:click={move |_| show_alert.1.set(true)}>{ "Save" }</button>
+///             <Alert
+///                 show_alert={show_alert}
+///                 title={"Save changes?"}
+///                 timeout={0}
+///                 pre_confirm=pre_confirm
+///             />
+///         </>
+///     }
+/// }
+/// ```
+///
 /// ## Native Alert
 /// ```rust
 /// use leptos::prelude::*;
@@ -121,17 +726,55 @@ use web_sys::window;
 /// - The component uses `create_signal` to manage the visibility of the alert.
 /// - It also uses `create_effect` to handle side-effects such as displaying the alert and auto-closing after the specified timeout.
 /// - Buttons trigger specific callbacks (e.g., confirm, cancel, close) when clicked.
+/// - Open, close and timer transitions are driven by the shared `AlertMachine`, so every framework behaves the same.
+/// - Clicking confirm, cancel or close hides the alert. A `timeout` of `0` disables auto-closing.
+/// - A hidden alert stays mounted while its exit animation plays, and `did_close` fires once it
+///   is over.
+/// - When `prefers-reduced-motion` is set, or `reduced_motion` forces it, alerts open and close
+///   instantly and the spinner stands still.
+/// - `did_close` receives an `AlertResult` whose `CloseReason` tells confirm, cancel, close,
+///   backdrop, Escape, timer and programmatic hiding apart.
 ///
 /// # Notes
 /// - The `native` prop can be set to `true` to use the browser's default alert behavior instead of the custom component.
+/// - Props left unset take the value of the nearest `AlertDefaults` context, if any.
+/// - Native dialogs only show `title` and `body` text; `children`, `header` and `footer` are not rendered.
+/// - With the `markdown` feature, `body` is rendered as Markdown inside a `<div>`; raw HTML in it is
+///   shown as text.
 #[component]
 pub fn Alert(
     /// The body text of the alert.
     ///
     /// This defines the message content that will be displayed within the alert.
     /// Defaults to an empty string if not specified.
-    #[prop(default = "")]
-    body: &'static str,
+    #[prop(into, default = "".into())]
+    body: Signal<String>,
+
+    /// An HTML snippet rendered below the body text, such as a legacy SweetAlert2 `html:` message.
+    ///
+    /// With the `sanitize` feature it is cleaned with an allow-list and injected as HTML. Without
+    /// it, the snippet is shown as plain text and never injected. Defaults to an empty string.
+    #[prop(into, default = "".into())]
+    html_body: Signal<String>,
+
+    /// Rich content rendered below the body text, such as links, lists or components.
+    ///
+    /// Combine it with `body` or leave `body` empty to replace it. The alert re-renders its
+    /// content every time it opens, so this is a `ChildrenFn`. Defaults to nothing.
+    #[prop(optional)]
+    children: Option<ChildrenFn>,
+
+    /// Content rendered at the top of the alert box, above the icon.
+    ///
+    /// Defaults to nothing.
+    #[prop(optional, into)]
+    header: Option<ViewFn>,
+
+    /// Content rendered at the bottom of the alert box, below the buttons.
+    ///
+    /// Defaults to nothing.
+    #[prop(optional, into)]
+    footer: Option<ViewFn>,
 
     /// The state handle controlling the visibility of the alert.
     ///
@@ -144,172 +787,286 @@ pub fn Alert(
     /// The timeout duration in milliseconds before the alert auto-closes.
     ///
     /// Defines how long the alert stays visible before automatically closing.
-    /// Defaults to `2500` milliseconds (2.5 seconds).
-    #[prop(default = 2500)]
-    timeout: u64,
+    /// Defaults to the app-wide `AlertDefaults` value, or `2500` milliseconds (2.5 seconds).
+    /// A changed value applies the next time the alert opens.
+    #[prop(into, optional)]
+    timeout: MaybeProp<u64>,
 
     /// The title text for the alert.
     ///
     /// This defines the heading or title of the alert. Defaults to `"Info"`.
-    #[prop(default = "Info")]
-    title: &'static str,
+    #[prop(into, default = "Info".into())]
+    title: Signal<String>,
+
+    /// The `id` of the alert box, also the prefix of its title and body `id`s.
+    ///
+    /// They are referenced by `aria-labelledby` and `aria-describedby`. Defaults to an empty
+    /// string, which generates a unique `alert-rs-N` once per mounted alert.
+    #[prop(into, optional)]
+    id: String,
 
     /// Text for the confirm button.
     ///
     /// This defines the label for the confirm button within the alert.
-    /// Defaults to `"Okay"`.
-    #[prop(default = "Okay")]
-    confirm_button_text: &'static str,
+    /// Defaults to the app-wide `AlertDefaults` value, or `"Okay"`.
+    #[prop(into, optional)]
+    confirm_button_text: MaybeProp<String>,
 
     /// Text for the cancel button.
     ///
     /// This defines the label for the cancel button within the alert.
-    /// Defaults to `"Cancel"`.
-    #[prop(default = "Cancel")]
-    cancel_button_text: &'static str,
+    /// Defaults to the app-wide `AlertDefaults` value, or `"Cancel"`.
+    #[prop(into, optional)]
+    cancel_button_text: MaybeProp<String>,
+
+    /// Text for the deny button.
+    ///
+    /// This defines the label for the deny button within the alert.
+    /// Defaults to the app-wide `AlertDefaults` value, or `"No"`.
+    #[prop(into, optional)]
+    deny_button_text: MaybeProp<String>,
 
     /// Whether to show the confirm button.
     ///
-    /// If `true`, the confirm button is displayed. Defaults to `true`.
-    #[prop(default = true)]
-    show_confirm_button: bool,
+    /// If `true`, the confirm button is displayed. Defaults to the app-wide `AlertDefaults` value, or `true`.
+    #[prop(into, optional)]
+    show_confirm_button: MaybeProp<bool>,
 
     /// Whether to show the cancel button.
     ///
-    /// If `true`, the cancel button is displayed. Defaults to `true`.
-    #[prop(default = true)]
-    show_cancel_button: bool,
+    /// If `true`, the cancel button is displayed. Defaults to the app-wide `AlertDefaults` value, or `true`.
+    #[prop(into, optional)]
+    show_cancel_button: MaybeProp<bool>,
+
+    /// Whether to show the deny button.
+    ///
+    /// If `true`, a deny button is displayed between the confirm and cancel buttons, for
+    /// "Save / Don't save / Cancel" dialogs. Defaults to the app-wide `AlertDefaults` value, or `false`.
+    #[prop(into, optional)]
+    show_deny_button: MaybeProp<bool>,
+
+    /// Extra buttons rendered after the confirm, deny and cancel buttons.
+    ///
+    /// Each click calls `on_action` with the id of the action, and closes the alert with
+    /// `CloseReason::Action` unless the action was built with `closes(false)`. Hide the confirm
+    /// and cancel buttons to use the actions alone. Ignored in `native` mode.
+    /// Defaults to no actions.
+    #[prop(default = Vec::new())]
+    actions: Vec<AlertAction>,
 
     /// Whether to show the close button.
     ///
-    /// If `true`, a close button is included. Defaults to `false`.
-    #[prop(default = false)]
-    show_close_button: bool,
+    /// If `true`, a close button is included. Defaults to the app-wide `AlertDefaults` value, or `false`.
+    #[prop(into, optional)]
+    show_close_button: MaybeProp<bool>,
 
     /// The position of the alert on the screen.
     ///
     /// Defines where the alert will appear on the screen (e.g., top-left, top-right, bottom-left, bottom-right).
-    /// Defaults to `Position::TopRight`.
-    #[prop(default = Position::TopRight)]
-    position: Position,
+    /// Defaults to the app-wide `AlertDefaults` value, or `Position::TopRight`.
+    #[prop(into, optional)]
+    position: MaybeProp<Position>,
 
     /// The type of icon to display in the alert.
     ///
     /// Defines the icon associated with the alert type (e.g., success, error, warning, info).
-    /// Defaults to `IconType::Info`.
-    #[prop(default = IconType::Info)]
-    icon_type: IconType,
+    /// Defaults to the app-wide `AlertDefaults` value, or `IconType::Info`.
+    #[prop(into, optional)]
+    icon_type: MaybeProp<IconType>,
 
     /// The color of the icon.
     ///
     /// Defines the color of the icon displayed in the alert. This value is applied as a CSS class.
     /// Defaults to an empty string.
-    #[prop(default = "")]
-    icon_color: &'static str,
+    #[prop(into, default = "".into())]
+    icon_color: Signal<String>,
 
     /// The width of the icon.
     ///
     /// Defines the size of the icon in percentage or pixel values. Defaults to `"50"`.
-    #[prop(default = "50")]
-    icon_width: &'static str,
+    #[prop(into, default = "50".into())]
+    icon_width: Signal<String>,
+
+    /// Whether to render the animated SweetAlert-style icon instead of the static one.
+    ///
+    /// The animation is pure CSS and falls back to the static icon with reduced motion.
+    /// Defaults to `false`.
+    #[prop(default = false)]
+    animated_icon: bool,
+
+    /// The node rendered as the icon when `icon_type` is `IconType::Custom(CustomIcon::Node)`.
+    ///
+    /// Defaults to nothing.
+    #[prop(optional, into)]
+    icon: Option<ViewFn>,
+
+    /// URL of an image shown above the title, alongside or instead of the icon.
+    ///
+    /// The image is lazy-loaded. Defaults to an empty string, which shows no image.
+    #[prop(into, default = "".into())]
+    image_url: Signal<String>,
+
+    /// Alternative text of the image.
+    ///
+    /// Defaults to an empty string.
+    #[prop(into, default = "".into())]
+    image_alt: Signal<String>,
+
+    /// Width of the image, such as `"400"`.
+    ///
+    /// Defaults to an empty string, which keeps the image's own width.
+    #[prop(into, default = "".into())]
+    image_width: Signal<String>,
+
+    /// Height of the image, such as `"200"`.
+    ///
+    /// Defaults to an empty string, which keeps the image's own height.
+    #[prop(into, default = "".into())]
+    image_height: Signal<String>,
+
+    /// URL of the image shown when `image_url` fails to load.
+    ///
+    /// When both fail, or no fallback is set, the image is removed. Defaults to an empty string.
+    #[prop(into, default = "".into())]
+    image_fallback_url: Signal<String>,
+
+    /// All CSS classes of the alert in one value.
+    ///
+    /// A flat `*_class` prop set on the alert wins over the matching field, which wins over the
+    /// app-wide `AlertDefaults` value. Defaults to no classes.
+    #[prop(optional)]
+    classes: AlertClasses,
+
+    /// All inline styles of the alert in one value.
+    ///
+    /// A flat `*_style` prop set on the alert wins over the matching field, which wins over the
+    /// app-wide `AlertDefaults` value. Defaults to no styles.
+    #[prop(optional)]
+    styles: AlertStyles,
 
     /// Custom CSS class for styling the alert container.
     ///
     /// This allows for the styling of the entire alert container, including background color, padding, etc.
-    /// Defaults to an empty string.
-    #[prop(default = "")]
-    alert_class: &'static str,
+    /// Defaults to the matching `classes` field, the app-wide `AlertDefaults` value, or an empty string.
+    #[prop(into, optional)]
+    alert_class: MaybeProp<String>,
 
     /// Custom CSS class for styling the icon in the alert.
     ///
     /// This applies additional styling to the icon inside the alert.
-    /// Defaults to an empty string.
-    #[prop(default = "")]
-    icon_class: &'static str,
+    /// Defaults to the matching `classes` field, the app-wide `AlertDefaults` value, or an empty string.
+    #[prop(into, optional)]
+    icon_class: MaybeProp<String>,
+
+    /// Custom CSS class for styling the image in the alert.
+    ///
+    /// Defaults to the matching `classes` field, the app-wide `AlertDefaults` value, or an empty string.
+    #[prop(into, optional)]
+    image_class: MaybeProp<String>,
 
     /// Custom CSS class for styling the confirm button.
     ///
     /// This applies additional styling to the confirm button within the alert.
-    /// Defaults to an empty string.
-    #[prop(default = "")]
-    confirm_button_class: &'static str,
+    /// Defaults to the matching `classes` field, the app-wide `AlertDefaults` value, or an empty string.
+    #[prop(into, optional)]
+    confirm_button_class: MaybeProp<String>,
 
     /// Custom CSS class for styling the cancel button.
     ///
     /// This applies additional styling to the cancel button within the alert.
-    /// Defaults to an empty string.
-    #[prop(default = "")]
-    cancel_button_class: &'static str,
+    /// Defaults to the matching `classes` field, the app-wide `AlertDefaults` value, or an empty string.
+    #[prop(into, optional)]
+    cancel_button_class: MaybeProp<String>,
+
+    /// Custom CSS class for styling the deny button.
+    ///
+    /// This applies additional styling to the deny button within the alert.
+    /// Defaults to the matching `classes` field, the app-wide `AlertDefaults` value, or an empty string.
+    #[prop(into, optional)]
+    deny_button_class: MaybeProp<String>,
 
     /// Custom CSS class for styling the title of the alert.
     ///
     /// This applies additional styling to the title text within the alert.
-    /// Defaults to an empty string.
-    #[prop(default = "")]
-    title_class: &'static str,
+    /// Defaults to the matching `classes` field, the app-wide `AlertDefaults` value, or an empty string.
+    #[prop(into, optional)]
+    title_class: MaybeProp<String>,
 
     /// Custom CSS class for styling the message in the alert.
     ///
     /// This applies additional styling to the body message inside the alert.
-    /// Defaults to an empty string.
-    #[prop(default = "")]
-    body_class: &'static str,
+    /// Defaults to the matching `classes` field, the app-wide `AlertDefaults` value, or an empty string.
+    #[prop(into, optional)]
+    body_class: MaybeProp<String>,
 
     /// Default style for the alert component.
     ///
     /// This defines default inline styles for the alert.
-    /// Defaults to `DEFAULT_ALERT_STYLE`.
-    #[prop(default = DEFAULT_ALERT_STYLE)]
-    alert_style: &'static str,
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_ALERT_STYLE`.
+    #[prop(into, optional)]
+    alert_style: MaybeProp<String>,
 
     /// Default style for the close button.
     ///
     /// This defines the default inline styles for the close button within the alert.
-    /// Defaults to `DEFAULT_CLOSE_BUTTON_STYLE`.
-    #[prop(default = DEFAULT_CLOSE_BUTTON_STYLE)]
-    close_button_style: &'static str,
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_CLOSE_BUTTON_STYLE`.
+    #[prop(into, optional)]
+    close_button_style: MaybeProp<String>,
 
     /// Default style for the confirm button.
     ///
     /// This defines the default inline styles for the confirm button within the alert.
-    /// Defaults to `DEFAULT_CONFIRM_BUTTON_STYLE`.
-    #[prop(default = DEFAULT_CONFIRM_BUTTON_STYLE)]
-    confirm_button_style: &'static str,
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_CONFIRM_BUTTON_STYLE`.
+    #[prop(into, optional)]
+    confirm_button_style: MaybeProp<String>,
 
     /// Default style for the cancel button.
     ///
     /// This defines the default inline styles for the cancel button within the alert.
-    /// Defaults to `DEFAULT_CANCEL_BUTTON_STYLE`.
-    #[prop(default = DEFAULT_CANCEL_BUTTON_STYLE)]
-    cancel_button_style: &'static str,
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_CANCEL_BUTTON_STYLE`.
+    #[prop(into, optional)]
+    cancel_button_style: MaybeProp<String>,
+
+    /// Default style for the deny button.
+    ///
+    /// This defines the default inline styles for the deny button within the alert.
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_DENY_BUTTON_STYLE`.
+    #[prop(into, optional)]
+    deny_button_style: MaybeProp<String>,
 
     /// Default style for the icon in the alert.
     ///
     /// This defines the default inline styles for the icon.
-    /// Defaults to `DEFAULT_ICON_STYLE`.
-    #[prop(default = DEFAULT_ICON_STYLE)]
-    icon_style: &'static str,
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_ICON_STYLE`.
+    #[prop(into, optional)]
+    icon_style: MaybeProp<String>,
+
+    /// Default style for the image in the alert.
+    ///
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_IMAGE_STYLE`.
+    #[prop(into, optional)]
+    image_style: MaybeProp<String>,
 
     /// Default style for the title text in the alert.
     ///
     /// This defines the default inline styles for the title.
-    /// Defaults to `DEFAULT_TITLE_STYLE`.
-    #[prop(default = DEFAULT_TITLE_STYLE)]
-    title_style: &'static str,
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_TITLE_STYLE`.
+    #[prop(into, optional)]
+    title_style: MaybeProp<String>,
 
     /// Default style for the separator between the title and message.
     ///
     /// This defines the default inline styles for the separator line.
-    /// Defaults to `DEFAULT_SEPARATOR_STYLE`.
-    #[prop(default = DEFAULT_SEPARATOR_STYLE)]
-    separator_style: &'static str,
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_SEPARATOR_STYLE`.
+    #[prop(into, optional)]
+    separator_style: MaybeProp<String>,
 
     /// Default style for the message text in the alert.
     ///
     /// This defines the default inline styles for the message text.
-    /// Defaults to `DEFAULT_MESSAGE_STYLE`.
-    #[prop(default = DEFAULT_MESSAGE_STYLE)]
-    message_style: &'static str,
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_MESSAGE_STYLE`.
+    #[prop(into, optional)]
+    message_style: MaybeProp<String>,
 
     /// Whether to use the native browser alert implementation.
     ///
@@ -318,6 +1075,157 @@ pub fn Alert(
     #[prop(default = false)]
     native: bool,
 
+    /// The prompt input rendered between the body and the buttons.
+    ///
+    /// Its value is returned in the `AlertResult` passed to `did_close` when the alert is
+    /// confirmed. In `native` mode, `window.prompt()` is used instead.
+    /// Defaults to `InputType::None`.
+    #[prop(default = InputType::None)]
+    input: InputType,
+
+    /// Placeholder text of the prompt input.
+    ///
+    /// Defaults to an empty string.
+    #[prop(into, default = "".into())]
+    input_placeholder: Signal<String>,
+
+    /// Initial value of the prompt input.
+    ///
+    /// A new value replaces what was typed into an open alert. Defaults to an empty string.
+    #[prop(into, default = "".into())]
+    input_value: Signal<String>,
+
+    /// Extra HTML attributes of the prompt input, such as `("maxlength", "20")`.
+    ///
+    /// Defaults to no attributes.
+    #[prop(default = Vec::new())]
+    input_attributes: Vec<(String, String)>,
+
+    /// The `(value, label)` options of a select or radio input.
+    ///
+    /// Defaults to no options.
+    #[prop(default = Vec::new())]
+    input_options: Vec<(String, String)>,
+
+    /// The label shown next to a checkbox input.
+    ///
+    /// Defaults to an empty string.
+    #[prop(into, default = "".into())]
+    input_label: Signal<String>,
+
+    /// Default style for the labels of radio and checkbox inputs.
+    ///
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_INPUT_LABEL_STYLE`.
+    #[prop(into, optional)]
+    input_label_style: MaybeProp<String>,
+
+    /// Check run on the input value when the confirm button is clicked.
+    ///
+    /// Returning `Err(message)` keeps the alert open, shows `message` under the input and marks
+    /// the input with `aria-invalid`. Defaults to no validation.
+    #[prop(default = None, into)]
+    input_validator: Option<Callback<String, Result<(), String>>>,
+
+    /// Async check run when the confirm button is clicked, before the alert closes.
+    ///
+    /// It receives the input value and puts the alert into a loading state: a spinner on the
+    /// confirm button, all buttons disabled and the auto-close timer stopped. `Ok` closes the
+    /// alert, `Err(message)` keeps it open and shows `message` in the validation area.
+    /// Defaults to closing immediately.
+    #[prop(default = None, into)]
+    pre_confirm: Option<Callback<Option<String>, PreConfirmFuture>>,
+
+    /// Default style for the spinner shown on the confirm button while `pre_confirm` runs.
+    ///
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_SPINNER_STYLE`.
+    #[prop(into, optional)]
+    spinner_style: MaybeProp<String>,
+
+    /// Custom CSS class for styling the validation message.
+    ///
+    /// Defaults to the matching `classes` field, the app-wide `AlertDefaults` value, or an empty string.
+    #[prop(into, optional)]
+    validation_message_class: MaybeProp<String>,
+
+    /// Default style for the validation message.
+    ///
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_VALIDATION_MESSAGE_STYLE`.
+    #[prop(into, optional)]
+    validation_message_style: MaybeProp<String>,
+
+    /// Custom CSS class for styling the prompt input.
+    ///
+    /// Defaults to the matching `classes` field, the app-wide `AlertDefaults` value, or an empty string.
+    #[prop(into, optional)]
+    input_class: MaybeProp<String>,
+
+    /// Default style for the prompt input.
+    ///
+    /// This defines the default inline styles for the input or textarea.
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_INPUT_STYLE`.
+    #[prop(into, optional)]
+    input_style: MaybeProp<String>,
+
+    /// Whether clicking the backdrop around the alert closes it.
+    ///
+    /// If `true`, a click outside the alert box dismisses it with `CloseReason::Backdrop`.
+    /// Defaults to the app-wide `AlertDefaults` value, or `false`.
+    #[prop(into, optional)]
+    close_on_backdrop_click: MaybeProp<bool>,
+
+    /// Whether pressing the Escape key closes the alert.
+    ///
+    /// If `true`, Escape dismisses the alert with `CloseReason::Escape`. Defaults to the app-wide `AlertDefaults` value, or `true`.
+    #[prop(into, optional)]
+    close_on_escape: MaybeProp<bool>,
+
+    /// The element focused when the alert opens.
+    ///
+    /// While the alert is open, Tab and Shift+Tab cycle through its controls, and focus moves
+    /// back to the previously focused element when it closes. Defaults to the app-wide `AlertDefaults` value, or `InitialFocus::Confirm`.
+    #[prop(into, optional)]
+    initial_focus: MaybeProp<InitialFocus>,
+
+    /// Default style for the backdrop behind the alert box.
+    ///
+    /// This defines the default inline styles for the clickable backdrop.
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_BACKDROP_STYLE`.
+    #[prop(into, optional)]
+    backdrop_style: MaybeProp<String>,
+
+    /// The animation played when the alert opens and closes.
+    ///
+    /// On close, the alert stays mounted until the exit animation finishes.
+    /// Defaults to the app-wide `AlertDefaults` value, or `AlertAnimation::None`, so `did_close` fires as soon as the alert is hidden.
+    #[prop(into, optional)]
+    animation: MaybeProp<AlertAnimation>,
+
+    /// Duration of the enter and exit animations in milliseconds.
+    ///
+    /// Defaults to the app-wide `AlertDefaults` value, or `300` milliseconds.
+    #[prop(into, optional)]
+    animation_duration: MaybeProp<u32>,
+
+    /// Custom CSS class added to the alert box while it opens and stays open.
+    ///
+    /// Use it to plug in animation libraries such as Animate.css. Defaults to an empty string.
+    #[prop(into, default = "".into())]
+    enter_class: Signal<String>,
+
+    /// Custom CSS class added to the alert box while its exit animation plays.
+    ///
+    /// The alert is kept mounted for `animation_duration`, even with `AlertAnimation::None`.
+    /// Defaults to an empty string.
+    #[prop(into, default = "".into())]
+    exit_class: Signal<String>,
+
+    /// Whether the alert honors the system's reduced motion setting.
+    ///
+    /// With reduced motion, animations, the animation class hooks and the spinner animation are
+    /// turned off. Defaults to the app-wide `AlertDefaults` value, or `ReducedMotion::System`, which follows `prefers-reduced-motion`.
+    #[prop(into, optional)]
+    reduced_motion: MaybeProp<ReducedMotion>,
+
     /// Callback triggered when the alert opens.
     ///
     /// This is triggered before the alert is shown to the user. Defaults to no-op.
@@ -332,9 +1240,10 @@ pub fn Alert(
 
     /// Callback triggered when the alert closes.
     ///
-    /// This is triggered after the alert is closed. Defaults to no-op.
-    #[prop(default = Callback::from(move || {}))]
-    did_close: Callback<()>,
+    /// This is triggered after the alert is closed and receives an `AlertResult` describing
+    /// why it closed. Defaults to no-op.
+    #[prop(default = Callback::new(|_| {}))]
+    did_close: Callback<AlertResult>,
 
     /// Callback triggered when the confirm button is clicked.
     ///
@@ -353,235 +1262,698 @@ pub fn Alert(
     /// This is triggered when the user clicks the cancel button. Defaults to no-op.
     #[prop(default = Callback::from(move || {}))]
     on_cancel: Callback<()>,
+
+    /// Callback triggered when the deny button is clicked.
+    ///
+    /// This is triggered when the user clicks the deny button. Defaults to no-op.
+    #[prop(default = Callback::from(move || {}))]
+    on_deny: Callback<()>,
+
+    /// Callback triggered when one of the `actions` is clicked, with its id.
+    ///
+    /// Defaults to no-op.
+    #[prop(default = Callback::new(|_| {}))]
+    on_action: Callback<String>,
 ) -> impl IntoView {
-    let delay = Duration::from_millis(timeout);
+    let defaults = use_context::<AlertDefaults>().unwrap_or_default();
+    let default_timeout = defaults.timeout.map(u64::from);
+    let timeout = Signal::derive(move || {
+        let timeout = AlertDefaults::resolve(timeout.get(), default_timeout, 2500);
+        u32::try_from(timeout).unwrap_or(u32::MAX)
+    });
+    let reduced_motion = resolve_prop(
+        reduced_motion,
+        defaults.reduced_motion,
+        ReducedMotion::System,
+    );
+    let position = resolve_prop(position, defaults.position.clone(), Position::TopRight);
+    let icon_type = resolve_prop(icon_type, defaults.icon_type.clone(), IconType::Info);
+    let show_confirm_button = resolve_prop(show_confirm_button, defaults.show_confirm_button, true);
+    let show_cancel_button = resolve_prop(show_cancel_button, defaults.show_cancel_button, true);
+    let show_deny_button = resolve_prop(show_deny_button, defaults.show_deny_button, false);
+    let show_close_button = resolve_prop(show_close_button, defaults.show_close_button, false);
+    let close_on_backdrop_click = resolve_prop(
+        close_on_backdrop_click,
+        defaults.close_on_backdrop_click,
+        false,
+    );
+    let close_on_escape = resolve_prop(close_on_escape, defaults.close_on_escape, true);
+    let initial_focus = resolve_prop(initial_focus, defaults.initial_focus, InitialFocus::Confirm);
+    let animation = resolve_prop(animation, defaults.animation, AlertAnimation::None);
+    let animation_duration = resolve_prop(animation_duration, defaults.animation_duration, 300);
+    let classes = defaults.classes.clone().merge(classes);
+    let styles = defaults.styles.clone().merge(styles);
+    let confirm_button_text =
+        with_default(confirm_button_text, "Okay", defaults.confirm_button_text);
+    let cancel_button_text =
+        with_default(cancel_button_text, "Cancel", defaults.cancel_button_text);
+    let deny_button_text = with_default(deny_button_text, "No", defaults.deny_button_text);
+    let alert_class = with_default(alert_class, "", classes.alert);
+    let icon_class = with_default(icon_class, "", classes.icon);
+    let image_class = with_default(image_class, "", classes.image);
+    let title_class = with_default(title_class, "", classes.title);
+    let body_class = with_default(body_class, "", classes.body);
+    let confirm_button_class = with_default(confirm_button_class, "", classes.confirm_button);
+    let cancel_button_class = with_default(cancel_button_class, "", classes.cancel_button);
+    let deny_button_class = with_default(deny_button_class, "", classes.deny_button);
+    let input_class = with_default(input_class, "", classes.input);
+    let validation_message_class =
+        with_default(validation_message_class, "", classes.validation_message);
+    let alert_style = with_default(alert_style, DEFAULT_ALERT_STYLE, styles.alert);
+    let backdrop_style = with_default(backdrop_style, DEFAULT_BACKDROP_STYLE, styles.backdrop);
+    let close_button_style = with_default(
+        close_button_style,
+        DEFAULT_CLOSE_BUTTON_STYLE,
+        styles.close_button,
+    );
+    let confirm_button_style = with_default(
+        confirm_button_style,
+        DEFAULT_CONFIRM_BUTTON_STYLE,
+        styles.confirm_button,
+    );
+    let cancel_button_style = with_default(
+        cancel_button_style,
+        DEFAULT_CANCEL_BUTTON_STYLE,
+        styles.cancel_button,
+    );
+    let deny_button_style = with_default(
+        deny_button_style,
+        DEFAULT_DENY_BUTTON_STYLE,
+        styles.deny_button,
+    );
+    let icon_style = with_default(icon_style, DEFAULT_ICON_STYLE, styles.icon);
+    let image_style = with_default(image_style, DEFAULT_IMAGE_STYLE, styles.image);
+    let title_style = with_default(title_style, DEFAULT_TITLE_STYLE, styles.title);
+    let separator_style = with_default(separator_style, DEFAULT_SEPARATOR_STYLE, styles.separator);
+    let message_style = with_default(message_style, DEFAULT_MESSAGE_STYLE, styles.message);
+    let input_style = with_default(input_style, DEFAULT_INPUT_STYLE, styles.input);
+    let input_label_style = with_default(
+        input_label_style,
+        DEFAULT_INPUT_LABEL_STYLE,
+        styles.input_label,
+    );
+    let validation_message_style = with_default(
+        validation_message_style,
+        DEFAULT_VALIDATION_MESSAGE_STYLE,
+        styles.validation_message,
+    );
+    let spinner_style = with_default(spinner_style, DEFAULT_SPINNER_STYLE, styles.spinner);
+
+    let machine = StoredValue::new(AlertMachine::new(timeout.get_untracked()));
+    machine.update_value(|machine| machine.set_pre_confirm(pre_confirm.is_some()));
+    let timer = StoredValue::new(None::<TimeoutHandle>);
+    let escape_listener = StoredValue::new(None::<WindowListenerHandle>);
+    let focus_trap = StoredValue::new_local(None::<FocusTrap>);
+
+    let validation_message = RwSignal::new(None::<String>);
+    let loading = RwSignal::new(false);
+    let leaving = RwSignal::new(false);
+    let failed_images = RwSignal::new(Vec::<String>::new());
+    let ids = StoredValue::new(if id.is_empty() {
+        AlertIds::generate()
+    } else {
+        AlertIds::new(&id)
+    });
+
+    let driver = Driver {
+        machine,
+        timer,
+        show_alert: show_alert.1,
+        will_open,
+        did_open,
+        did_close,
+        on_confirm,
+        on_close,
+        on_cancel,
+        on_deny,
+        on_action,
+        input_validator,
+        validation_message,
+        pre_confirm,
+        loading,
+        leaving,
+        native,
+    };
+
+    on_cleanup(move || {
+        if let Some(handle) = timer.try_update_value(Option::take).flatten() {
+            handle.clear();
+        }
+        if let Some(handle) = escape_listener.try_update_value(Option::take).flatten() {
+            handle.remove();
+        }
+        focus_trap.try_update_value(Option::take);
+    });
+
+    let input_options = StoredValue::new(input_options);
+    let initial_value = move || {
+        input_options.with_value(|options| input.initial_value(&input_value.get(), options))
+    };
+
+    // A new `input_value` replaces what was typed into an open alert, on screen and in the machine.
+    Effect::new(move |previous: Option<()>| {
+        let value = initial_value();
+        if previous.is_some() && show_alert.0.get_untracked() {
+            driver.send(AlertEvent::Input(value));
+        }
+    });
+
     Effect::new(move || {
-        if show_alert.0.get() && !native {
-            will_open.run(());
-            set_timeout(
-                move || {
-                    show_alert.1.set(false);
-                    did_close.run(());
-                },
-                delay,
-            );
-
-            did_open.run(());
-        } else if show_alert.0.get() && native {
-            if let Some(win) = window() {
-                will_open.run(());
-
-                let full_message = if !title.is_empty() {
-                    format!("{}\n\n{}", title, body)
+        let timeout = timeout.get();
+        machine.update_value(|machine| machine.set_timeout(timeout));
+    });
+
+    Effect::new(move || {
+        let show = show_alert.0.get();
+        untrack(move || {
+            if !show {
+                if let Some(handle) = escape_listener.try_update_value(Option::take).flatten() {
+                    handle.remove();
+                }
+                focus_trap.update_value(|trap| *trap = None);
+                let duration = if native {
+                    0
                 } else {
-                    body.to_string()
+                    animation.get().exit_duration(
+                        animation_duration.get(),
+                        &exit_class.get(),
+                        reduced_motion.get().is_reduced(),
+                    )
                 };
+                machine.update_value(|machine| machine.set_animation_duration(duration));
+                driver.send(AlertEvent::Hidden);
+                return;
+            }
+            // Signals notify even when set to the same value, so an open alert is left alone.
+            if !machine.with_value(AlertMachine::can_show) {
+                return;
+            }
 
-                match (show_confirm_button, show_cancel_button, show_close_button) {
-                    (true, true, true) => {
-                        if win.confirm_with_message(&full_message).unwrap_or(false) {
-                            on_confirm.run(());
-                        } else {
-                            on_close.run(());
-                            on_cancel.run(());
-                        }
-                    }
-                    (true, true, false) => {
-                        if win.confirm_with_message(&full_message).unwrap_or(false) {
-                            on_confirm.run(());
-                        } else {
-                            on_cancel.run(());
-                        }
-                    }
-                    (true, false, false) => {
-                        win.alert_with_message(&full_message).ok();
-                        on_confirm.run(());
+            if close_on_escape.get() && !native {
+                let handle = window_event_listener(ev::keydown, move |event| {
+                    if event.key() == "Escape" {
+                        driver.send(AlertEvent::Escape);
                     }
-                    _ => {}
+                });
+                let previous = escape_listener
+                    .try_update_value(|listener| listener.replace(handle))
+                    .flatten();
+                if let Some(previous) = previous {
+                    previous.remove();
                 }
+            }
+            if !native {
+                focus_trap.update_value(|trap| *trap = None);
+                let trap = FocusTrap::activate(&ids.get_value(), initial_focus.get());
+                focus_trap.set_value(Some(trap));
+            }
 
-                if timeout > 0 {
-                    set_timeout(
-                        move || {
-                            show_alert.1.set(false);
-                            did_close.run(());
-                        },
-                        delay,
-                    );
-                } else {
-                    show_alert.1.set(false);
-                }
+            driver.send(AlertEvent::Show);
+            if !input.is_none() {
+                driver.send(AlertEvent::Input(initial_value()));
+            }
+            driver.send(AlertEvent::Shown);
+
+            if native {
+                let dialog = NativeDialog::for_input(
+                    input,
+                    show_confirm_button.get(),
+                    show_deny_button.get(),
+                    show_cancel_button.get(),
+                    show_close_button.get(),
+                );
+                if let Some(win) = window() {
+                    let (title, body) = (title.get_untracked(), body.get_untracked());
+                    let full_message = if !title.is_empty() {
+                        format!("{}\n\n{}", title, body)
+                    } else {
+                        body
+                    };
 
-                did_open.run(());
+                    let accepted = match dialog {
+                        NativeDialog::Alert => win.alert_with_message(&full_message).is_ok(),
+                        NativeDialog::Confirm { .. } => {
+                            win.confirm_with_message(&full_message).unwrap_or(false)
+                        }
+                        NativeDialog::Prompt { .. } => {
+                            let default = initial_value();
+                            let mut message = full_message.clone();
+                            loop {
+                                match win.prompt_with_message_and_default(&message, &default) {
+                                    Ok(Some(value)) => match driver.validate(&value) {
+                                        Ok(()) => {
+                                            driver.send(AlertEvent::Input(value));
+                                            break true;
+                                        }
+                                        Err(error) => {
+                                            message = format!("{}\n\n{}", error, full_message)
+                                        }
+                                    },
+                                    _ => break false,
+                                }
+                            }
+                        }
+                        NativeDialog::None => false,
+                    };
+
+                    driver.send(dialog.event(accepted));
+                }
             }
-        }
+        });
     });
 
-    let on_cancel = {
-        move |_: MouseEvent| {
-            on_cancel.run(());
-            show_alert.1.set(false);
+    let input_ref = NodeRef::<html::Input>::new();
+    let textarea_ref = NodeRef::<html::Textarea>::new();
+    let select_ref = NodeRef::<html::Select>::new();
+    let group_ref = NodeRef::<html::Div>::new();
+
+    Effect::new(move || {
+        let element: Option<web_sys::Element> = input_ref
+            .get()
+            .map(Into::into)
+            .or_else(|| textarea_ref.get().map(Into::into))
+            .or_else(|| select_ref.get().map(Into::into))
+            .or_else(|| group_ref.get().map(Into::into));
+        if let Some(element) = element {
+            set_input_attributes(&element, &input_attributes);
         }
-    };
+    });
 
-    let on_confirm = {
-        move |_: MouseEvent| {
-            on_confirm.run(());
+    let on_input =
+        move |event: ev::Event| driver.send(AlertEvent::Input(event_target_value(&event)));
+    let on_check = move |event: ev::Event| {
+        driver.send(AlertEvent::Input(event_target_checked(&event).to_string()))
+    };
+    let on_backdrop = move |_: MouseEvent| {
+        if close_on_backdrop_click.get_untracked() {
+            driver.send(AlertEvent::Backdrop);
         }
     };
-
-    let position_style = match position {
-        Position::TopLeft => "top: 0; left: 0;".to_string(),
-        Position::TopCenter => "top: 0; left: 50%; transform: translateX(-50%);".to_string(),
-        Position::TopRight => "top: 0; right: 0;".to_string(),
-        Position::LeftCenter => "top: 50%; left: 0; transform: translateY(-50%);".to_string(),
-        Position::Center => "top: 50%; left: 50%; transform: translate(-50%, -50%);".to_string(),
-        Position::BottomCenter => "bottom: 0; left: 50%; transform: translateX(-50%);".to_string(),
-        Position::RightCenter => "top: 50%; right: 0; transform: translateY(-50%);".to_string(),
-        Position::BottomRight => "bottom: 0; right: 0;".to_string(),
-        Position::BottomLeft => "bottom: 0; left: 0;".to_string(),
-        Position::Custom(x, y) => format!("top: {}; left: {};", y, x),
+    let on_cancel = move |_: MouseEvent| driver.send(AlertEvent::Cancel);
+    let on_deny = move |_: MouseEvent| driver.send(AlertEvent::Deny);
+    let actions = StoredValue::new(actions);
+    let on_close = move |_: MouseEvent| driver.send(AlertEvent::Close);
+    let on_confirm = move |_: MouseEvent| driver.confirm();
+    let invalid = move || validation_message.with(Option::is_some).to_string();
+    let closing = move || leaving.get() && !show_alert.0.get();
+    let visible = Memo::new(move |_| show_alert.0.get() || closing());
+    let reduced = Memo::new(move |_| reduced_motion.get().is_reduced());
+    let active_animation = move || {
+        if reduced.get() {
+            AlertAnimation::None
+        } else {
+            animation.get()
+        }
     };
 
-    let icon_color = if icon_color.is_empty() {
-        match icon_type {
-            IconType::Warning => "orange",
-            IconType::Error => "red",
-            IconType::Success => "green",
-            IconType::Info => "blue",
-            IconType::Question => "gray",
+    let icon_color = Signal::derive(move || {
+        let color = icon_color.get();
+        if color.is_empty() {
+            icon_type.with(IconType::default_color).to_string()
+        } else {
+            color
         }
-    } else {
-        icon_color
-    };
+    });
 
     view! {
         {move || {
-            // SVGs taken from: https://fontawesome.com/icons
-            let icon_tag = match icon_type {
-                IconType::Warning => view! {
-                    <svg
-                        xmlns="http://www.w3.org/2000/svg"
-                        width={icon_width}
-                        style={icon_style}
-                        class="p-2 m-2"
-                        fill={icon_color}
-                        viewBox="0 0 512 512"
-                    >
-                        <path
-                            d="M248.4 84.3c1.6-2.7 4.5-4.3 7.6-4.3s6 1.6 7.6 4.3L461.9 410c1.4 2.3 2.1 4.9 2.1 7.5c0 8-6.5 14.5-14.5 14.5H62.5c-8 0-14.5-6.5-14.5-14.5c0-2.7 .7-5.3 2.1-7.5L248.4 84.3zm-41-25L9.1 385c-6 9.8-9.1 21-9.1 32.5C0 452 28 480 62.5 480h387c34.5 0 62.5-28 62.5-62.5c0-11.5-3.2-22.7-9.1-32.5L304.6 59.3C294.3 42.4 275.9 32 256 32s-38.3 10.4-48.6 27.3zM288 368a32 32 0 1 0 -64 0 32 32 0 1 0 64 0zm-8-184c0-13.3-10.7-24-24-24s-24 10.7-24 24v96c0 13.3 10.7 24 24 24s24-10.7 24-24V184z"
-                        />
-                    </svg>
-                }.into_any(),
-                IconType::Error => view! {
-                    <svg
-                        xmlns="http://www.w3.org/2000/svg"
-                        width={icon_width}
-                        style={icon_style}
-                        class="p-2 m-2"
-                        fill={icon_color}
-                        viewBox="0 0 20 20"
-                    >
-                        <path
-                            d="M12.71,7.291c-0.15-0.15-0.393-0.15-0.542,0L10,9.458L7.833,7.291c-0.15-0.15-0.392-0.15-0.542,0c-0.149,0.149-0.149,0.392,0,0.541L9.458,10l-2.168,2.167c-0.149,0.15-0.149,0.393,0,0.542c0.15,0.149,0.392,0.149,0.542,0L10,10.542l2.168,2.167c0.149,0.149,0.392,0.149,0.542,0c0.148-0.149,0.148-0.392,0-0.542L10.542,10l2.168-2.168C12.858,7.683,12.858,7.44,12.71,7.291z M10,1.188c-4.867,0-8.812,3.946-8.812,8.812c0,4.867,3.945,8.812,8.812,8.812s8.812-3.945,8.812-8.812C18.812,5.133,14.867,1.188,10,1.188z M10,18.046c-4.444,0-8.046-3.603-8.046-8.046c0-4.444,3.603-8.046,8.046-8.046c4.443,0,8.046,3.602,8.046,8.046C18.046,14.443,14.443,18.046,10,18.046z"
-                        />
-                    </svg>
-                }.into_any(),
-                IconType::Success => view! {
-                    <svg
-                        xmlns="http://www.w3.org/2000/svg"
-                        width={icon_width}
-                        style={icon_style}
-                        class="p-2 m-2"
-                        fill={icon_color}
-                        viewBox="0 0 512 512"
-                    >
-                        <path
-                            d="M256 48a208 208 0 1 1 0 416 208 208 0 1 1 0-416zm0 464A256 256 0 1 0 256 0a256 256 0 1 0 0 512zM369 209c9.4-9.4 9.4-24.6 0-33.9s-24.6-9.4-33.9 0l-111 111-47-47c-9.4-9.4-24.6-9.4-33.9 0s-9.4 24.6 0 33.9l64 64c9.4 9.4 24.6 9.4 33.9 0L369 209z"
-                        />
-                    </svg>
-                }.into_any(),
-                IconType::Info => view! {
-                    <svg
-                        xmlns="http://www.w3.org/2000/svg"
-                        width={icon_width}
-                        style={icon_style}
-                        class="p-2 m-2"
-                        fill={icon_color}
-                        viewBox="0 0 16 16"
+            let static_icon = {
+                let icon = icon.clone();
+                move || icon_type.with(|icon_type| match icon_type.svg() {
+                    Some(svg) => view! {
+                        <svg
+                            xmlns="http://www.w3.org/2000/svg"
+                            width=move || icon_width.get()
+                            style={icon_style}
+                            class="p-2 m-2"
+                            fill=move || icon_color.get()
+                            viewBox=svg.view_box.to_string()
+                        >
+                            <path d=svg.path.to_string() />
+                        </svg>
+                    }.into_any(),
+                    None => match icon_type {
+                        IconType::Custom(CustomIcon::Image(url)) => view! {
+                            <img
+                                src=url.clone()
+                                width=move || icon_width.get()
+                                style={icon_style}
+                                class="p-2 m-2"
+                                alt=""
+                            />
+                        }.into_any(),
+                        IconType::Custom(CustomIcon::Node) => icon.as_ref().map(ViewFn::run).into_any(),
+                        _ => ().into_any(),
+                    },
+                })
+            };
+            // Sized and colored reactively, so a new color doesn't rebuild the dialog.
+            let icon_tag = move || {
+                let animated = animated_icon
+                    && !reduced.get()
+                    && icon_type.with(|icon_type| icon_type.animated_svg("", "").is_some());
+                if !animated {
+                    return static_icon();
+                }
+                view! {
+                    <style>{ANIMATED_ICON_STYLES}</style>
+                    <span inner_html=move || {
+                        icon_type.with(|icon_type| {
+                            icon_type.animated_svg(&icon_width.get(), &icon_color.get())
+                        })
+                    }></span>
+                }
+                .into_any()
+            };
+            let AlertIds {
+                dialog: dialog_id,
+                title: title_id,
+                body: body_id,
+                confirm_button: confirm_id,
+                cancel_button: cancel_id,
+                deny_button: deny_id,
+                radio_group,
+                validation_message: validation_id,
+            } = ids.get_value();
+            let labelled_by = title_id.clone();
+            let described_by = body_id.clone();
+            let has_children = children.is_some();
+            let has_body = move || {
+                !body.get().is_empty() || !html_body.get().is_empty() || has_children
+            };
+            let error_described_by = {
+                let validation_id = validation_id.clone();
+                move || {
+                    validation_message
+                        .with(Option::is_some)
+                        .then(|| validation_id.clone())
+                }
+            };
+            let input_tag = match input {
+                InputType::None => None,
+                InputType::Textarea => Some(view! {
+                    <textarea
+                        node_ref=textarea_ref
+                        aria-invalid=invalid
+                        aria-describedby=error_described_by.clone()
+                        class=move || input_class.get()
+                        style={input_style}
+                        placeholder=move || input_placeholder.get()
+                        prop:value=initial_value
+                        on:input=on_input
+                    />
+                }.into_any()),
+                InputType::Select => Some(view! {
+                    <select
+                        node_ref=select_ref
+                        aria-invalid=invalid
+                        aria-describedby=error_described_by.clone()
+                        class=move || input_class.get()
+                        style={input_style}
+                        on:change=on_input
                     >
-                        <path d="M8 15A7 7 0 1 1 8 1a7 7 0 0 1 0 14zm0 1A8 8 0 1 0 8 0a8 8 0 0 0 0 16z" />
-                        <path
-                            d="m8.93 6.588-2.29.287-.082.38.45.083c.294.07.352.176.288.469l-.738 3.468c-.194.897.105 1.319.808 1.319.545 0 1.178-.252 1.465-.598l.088-.416c-.2.176-.492.246-.686.246-.275 0-.375-.193-.304-.533L8.93 6.588zM9 4.5a1 1 0 1 1-2 0 1 1 0 0 1 2 0z"
-                        />
-                    </svg>
-                }.into_any(),
-                IconType::Question => view! {
-                    <svg
-                        xmlns="http://www.w3.org/2000/svg"
-                        width={icon_width}
-                        style={icon_style}
-                        class="p-2 m-2"
-                        fill={icon_color}
-                        viewBox="0 0 16 16"
+                        {input_options.get_value().into_iter().map(|(value, label)| {
+                            let selected = {
+                                let value = value.clone();
+                                move || value == initial_value()
+                            };
+                            view! { <option prop:selected=selected value={value}>{label}</option> }
+                        }).collect_view()}
+                    </select>
+                }.into_any()),
+                InputType::Radio => Some(view! {
+                    <div
+                        node_ref=group_ref
+                        class=move || input_class.get()
+                        role="radiogroup"
+                        aria-invalid=invalid
+                        aria-describedby=error_described_by.clone()
                     >
-                        <path d="M8 15A7 7 0 1 1 8 1a7 7 0 0 1 0 14zm0 1A8 8 0 1 0 8 0a8 8 0 0 0 0 16z" />
-                        <path
-                            d="M5.255 5.786a.237.237 0 0 0 .241.247h.825c.138 0 .248-.113.266-.25.09-.656.54-1.134 1.342-1.134.686 0 1.314.343 1.314 1.168 0 .635-.374.927-.965 1.371-.673.489-1.206 1.06-1.168 1.987l.003.217a.25.25 0 0 0 .25.246h.811a.25.25 0 0 0 .25-.25v-.105c0-.718.273-.927 1.01-1.486.609-.463 1.244-.977 1.244-2.056 0-1.511-1.276-2.241-2.673-2.241-1.267 0-2.655.59-2.75 2.286zm1.557 5.763c0 .533.425.927 1.01.927.609 0 1.028-.394 1.028-.927 0-.552-.42-.94-1.029-.94-.584 0-1.009.388-1.009.94z"
+                        {input_options.get_value().into_iter().map(|(value, label)| {
+                            let checked = {
+                                let value = value.clone();
+                                move || value == initial_value()
+                            };
+                            view! {
+                                <label style={input_label_style}>
+                                    <input
+                                        type="radio"
+                                        name=radio_group.clone()
+                                        prop:checked=checked
+                                        value={value}
+                                        on:change=on_input
+                                    />
+                                    {label}
+                                </label>
+                            }
+                        }).collect_view()}
+                    </div>
+                }.into_any()),
+                InputType::Checkbox => Some(view! {
+                    <label class=move || input_class.get() style={input_label_style}>
+                        <input
+                            node_ref=input_ref
+                            aria-invalid=invalid
+                            aria-describedby=error_described_by.clone()
+                            type="checkbox"
+                            prop:checked=move || initial_value() == "true"
+                            on:change=on_check
                         />
-                    </svg>
-                }.into_any(),
+                        {move || input_label.get()}
+                    </label>
+                }.into_any()),
+                InputType::Range { min, max, step } => Some(view! {
+                    <input
+                        node_ref=input_ref
+                        aria-invalid=invalid
+                        aria-describedby=error_described_by.clone()
+                        type="range"
+                        class=move || input_class.get()
+                        style={input_style}
+                        min={min.to_string()}
+                        max={max.to_string()}
+                        step={step.to_string()}
+                        prop:value=initial_value
+                        on:input=on_input
+                    />
+                }.into_any()),
+                input => Some(view! {
+                    <input
+                        node_ref=input_ref
+                        aria-invalid=invalid
+                        aria-describedby=error_described_by.clone()
+                        type={input.html_type()}
+                        class=move || input_class.get()
+                        style={input_style}
+                        placeholder=move || input_placeholder.get()
+                        prop:value=initial_value
+                        on:input=on_input
+                    />
+                }.into_any()),
             };
-            if show_alert.0.get() {
+            #[cfg(feature = "sanitize")]
+            let html_body_tag = move || {
+                let html_body = html_body.get();
+                (!html_body.is_empty()).then(|| view! {
+                    <div
+                        class=move || body_class.get()
+                        style={message_style}
+                        inner_html=sanitize_html(&html_body)
+                    ></div>
+                })
+            };
+            #[cfg(not(feature = "sanitize"))]
+            let html_body_tag = move || {
+                let html_body = html_body.get();
+                (!html_body.is_empty()).then(|| view! {
+                    <div class=move || body_class.get() style={message_style}>{html_body}</div>
+                })
+            };
+            if visible.get() {
                 Some(view! {
-                    <div style={alert_style}>
+                    <div style=move || {
+                        format!(
+                            "{} {}",
+                            alert_style.get(),
+                            active_animation().overlay_style(animation_duration.get(), closing()),
+                        )
+                    }>
+                        {move || (active_animation() != AlertAnimation::None).then(|| view! {
+                            <style>{ANIMATION_KEYFRAMES}</style>
+                        })}
+                        <div style={backdrop_style} on:click=on_backdrop></div>
                         <div
-                            class={alert_class}
-                            style=format!("position: absolute; {}", position_style.to_string())
+                            id=dialog_id
+                            tabindex="-1"
+                            role=move || icon_type.with(IconType::dialog_role)
+                            aria-modal="true"
+                            aria-labelledby=labelled_by
+                            aria-describedby=move || {
+                                has_body().then(|| described_by.clone())
+                            }
+                            class=move || {
+                                let animation_class = match (reduced.get(), closing()) {
+                                    (true, _) => String::new(),
+                                    (false, true) => exit_class.get(),
+                                    (false, false) => enter_class.get(),
+                                };
+                                format!("{ALERT_CLASS} {} {animation_class}", alert_class.get())
+                            }
+                            style=move || {
+                                let position = position.get();
+                                format!(
+                                    "position: absolute; {} {}",
+                                    position.style(),
+                                    active_animation().box_style(
+                                        &position,
+                                        animation_duration.get(),
+                                        closing(),
+                                    ),
+                                )
+                            }
                         >
-                            {if show_close_button {
+                            {move || show_close_button.get().then(|| view! {
+                                <button
+                                    style={close_button_style}
+                                    on:click=on_close
+                                    disabled=move || loading.get()
+                                    aria-label="Close"
+                                >
+                                    {"X"}
+                                </button>
+                            })}
+                            {header.as_ref().map(ViewFn::run)}
+                            {move || {
+                                let icon_tag = icon_tag.clone();
+                                icon_type.with(|icon_type| *icon_type != IconType::None).then(|| view! {
+                                    <div class=move || icon_class.get() style={icon_style}>
+                                        {icon_tag}
+                                    </div>
+                                })
+                            }}
+                            {move || {
+                                let (url, fallback) = (image_url.get(), image_fallback_url.get());
+                                let src = failed_images.with(|failed| {
+                                    image_source(&url, &fallback, failed).map(str::to_string)
+                                })?;
+                                let failed_src = src.clone();
                                 Some(view! {
-                                    <button style={close_button_style} on:click=on_cancel>{"X"}</button>
+                                    <img
+                                        src=src
+                                        alt=move || image_alt.get()
+                                        width=move || Some(image_width.get()).filter(|width| !width.is_empty())
+                                        height=move || Some(image_height.get()).filter(|height| !height.is_empty())
+                                        class=move || image_class.get()
+                                        style=image_style
+                                        loading="lazy"
+                                        on:error=move |_| failed_images.update(|failed| failed.push(failed_src.clone()))
+                                    />
                                 })
-                            } else {
-                                None
                             }}
-                            <div class={icon_class} style={icon_style}>
-                                {icon_tag}
-                            </div>
-                            <strong style={title_style} class={title_class}>
-                                {title}
+                            <strong id=title_id style={title_style} class=move || title_class.get()>
+                                {move || title.get()}
                             </strong>
                             <hr style={separator_style} />
-                            <p class={body_class} style={message_style}>
-                                {body}
-                            </p>
-                            {if show_confirm_button {
-                                Some(view! {
+                            <div id=body_id hidden=move || !has_body()>
+                                {move || {
+                                    let body = body.get();
+                                    if body.is_empty() {
+                                        return None;
+                                    }
+                                    #[cfg(not(feature = "markdown"))]
+                                    let body_tag = view! {
+                                        <p class=move || body_class.get() style={message_style}>
+                                            {body}
+                                        </p>
+                                    };
+                                    #[cfg(feature = "markdown")]
+                                    let body_tag = view! {
+                                        <div class=move || body_class.get() style={message_style}>
+                                            {markdown::render_markdown(&parse_markdown(&body))}
+                                        </div>
+                                    };
+                                    Some(body_tag)
+                                }}
+                                {html_body_tag}
+                                {children.as_ref().map(|children| children())}
+                            </div>
+                            {input_tag}
+                            {move || validation_message.get().map(|message| view! {
+                                <div
+                                    id=validation_id.clone()
+                                    class=move || validation_message_class.get()
+                                    style={validation_message_style}
+                                    role="alert"
+                                >
+                                    {message}
+                                </div>
+                            })}
+                            {move || (loading.get() && !reduced.get()).then(|| view! {
+                                <style>{SPINNER_KEYFRAMES}</style>
+                            })}
+                            {move || {
+                                let confirm_id = confirm_id.clone();
+                                show_confirm_button.get().then(|| view! {
                                     <button
-                                        class={confirm_button_class}
+                                        id=confirm_id
+                                        class=move || confirm_button_class.get()
                                         style={confirm_button_style}
                                         on:click=on_confirm
+                                        disabled=move || loading.get()
                                     >
-                                        {confirm_button_text}
+                                        {move || loading.get().then(|| view! {
+                                            <span style={spinner_style} aria-hidden="true"></span>
+                                        })}
+                                        {move || confirm_button_text.get()}
                                     </button>
                                 })
-                            } else {
-                                None
                             }}
-                            {if show_cancel_button {
-                                Some(view! {
+                            {move || {
+                                let deny_id = deny_id.clone();
+                                show_deny_button.get().then(|| view! {
+                                    <button
+                                        id=deny_id
+                                        class=move || deny_button_class.get()
+                                        style={deny_button_style}
+                                        on:click=on_deny
+                                        disabled=move || loading.get()
+                                    >
+                                        {move || deny_button_text.get()}
+                                    </button>
+                                })
+                            }}
+                            {move || {
+                                let cancel_id = cancel_id.clone();
+                                show_cancel_button.get().then(|| view! {
                                     <button
-                                        class={cancel_button_class}
+                                        id=cancel_id
+                                        class=move || cancel_button_class.get()
                                         style={cancel_button_style}
                                         on:click=on_cancel
+                                        disabled=move || loading.get()
                                     >
-                                        {cancel_button_text}
+                                        {move || cancel_button_text.get()}
                                     </button>
                                 })
-                            } else {
-                                None
                             }}
+                            {actions.get_value().into_iter().map(|action| {
+                                let event = AlertEvent::Action {
+                                    id: action.id.clone(),
+                                    closes: action.closes,
+                                };
+                                let style = action.button_style().to_string();
+                                view! {
+                                    <button
+                                        class={action.class}
+                                        style={style}
+                                        on:click=move |_| driver.send(event.clone())
+                                        disabled=move || loading.get()
+                                    >
+                                        {action.label}
+                                    </button>
+                                }
+                            }).collect_view()}
+                            {footer.as_ref().map(ViewFn::run)}
                         </div>
                     </div>
                 })
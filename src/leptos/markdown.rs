@@ -0,0 +1,42 @@
+use crate::common::MarkdownNode;
+use leptos::prelude::*;
+
+/// Renders a Markdown `body` parsed by [`parse_markdown`](crate::common::parse_markdown).
+///
+/// Text is inserted as text nodes, so Leptos escapes any markup it contains.
+pub(crate) fn render_markdown(nodes: &[MarkdownNode]) -> AnyView {
+    nodes.iter().map(render_node).collect_view().into_any()
+}
+
+fn render_node(node: &MarkdownNode) -> AnyView {
+    match node {
+        MarkdownNode::Text(text) => text.clone().into_any(),
+        MarkdownNode::Code(code) => view! { <code>{code.clone()}</code> }.into_any(),
+        MarkdownNode::LineBreak => view! { <br /> }.into_any(),
+        MarkdownNode::Paragraph(children) => {
+            view! { <p>{render_markdown(children)}</p> }.into_any()
+        }
+        MarkdownNode::Strong(children) => {
+            view! { <strong>{render_markdown(children)}</strong> }.into_any()
+        }
+        MarkdownNode::Emphasis(children) => {
+            view! { <em>{render_markdown(children)}</em> }.into_any()
+        }
+        MarkdownNode::List {
+            start: Some(start),
+            items,
+        } => view! { <ol start=start.to_string()>{render_markdown(items)}</ol> }.into_any(),
+        MarkdownNode::List { start: None, items } => {
+            view! { <ul>{render_markdown(items)}</ul> }.into_any()
+        }
+        MarkdownNode::ListItem(children) => {
+            view! { <li>{render_markdown(children)}</li> }.into_any()
+        }
+        MarkdownNode::Link { href, children } => {
+            view! { <a href=href.clone()>{render_markdown(children)}</a> }.into_any()
+        }
+        MarkdownNode::CodeBlock(code) => {
+            view! { <pre><code>{code.clone()}</code></pre> }.into_any()
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use super::Alert;
+use crate::common::*;
+use leptos::prelude::*;
+
+/// Handle to the alerts of the nearest `AlertProvider`.
+///
+/// Obtained with [`use_alert`]. The handle is `Copy` and every copy feeds the same queue.
+#[derive(Clone, Copy)]
+pub struct AlertHandle {
+    queue: RwSignal<AlertQueue>,
+}
+
+impl AlertHandle {
+    /// Shows an alert, or queues it behind the current one, and resolves to the user's answer.
+    pub fn fire(&self, config: AlertConfig) -> AlertFuture {
+        self.queue
+            .try_update(|queue| queue.push(config))
+            .expect("the `AlertProvider` was disposed")
+    }
+}
+
+/// Returns the [`AlertHandle`] of the nearest `AlertProvider`.
+///
+/// # Panics
+/// Panics when called outside of an `AlertProvider`.
+pub fn use_alert() -> AlertHandle {
+    expect_context::<AlertHandle>()
+}
+
+/// Alert Provider Component
+///
+/// Renders the alerts fired through [`use_alert`] one at a time, in the order they were fired,
+/// and resolves each [`AlertFuture`] once its alert closes.
+///
+/// # Examples
+/// ```rust
+/// use alert_rs::common::AlertConfig;
+/// use alert_rs::leptos::{use_alert, AlertProvider};
+/// use alert_rs::IconType;
+/// use leptos::prelude::*;
+/// use leptos::task::spawn_local;
+///
+/// #[component]
+/// pub fn DeleteButton() -> impl IntoView {
+///     let alert = use_alert();
+///     let on_click = move |_| {
+///         spawn_local(async move {
+///             let config = AlertConfig::new("This cannot be undone.")
+///                 .title("Delete file?")
+///                 .icon_type(IconType::Warning)
+///                 .timeout(0);
+///             if alert.fire(config).await.is_confirmed() {
+///                 log::info!("Deleted!");
+///             }
+///         });
+///     };
+///     view! { <button on:click=on_click>{"Delete"}</button> }
+/// }
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     view! {
+///         <AlertProvider>
+///             <DeleteButton />
+///         </AlertProvider>
+///     }
+/// }
+/// ```
+#[component]
+pub fn AlertProvider(
+    /// The application content that can fire alerts through [`use_alert`].
+    children: Children,
+) -> impl IntoView {
+    let queue = RwSignal::new(AlertQueue::new());
+    provide_context(AlertHandle { queue });
+
+    let current = Memo::new(move |_| queue.with(AlertQueue::current_id));
+
+    let did_close = Callback::new(move |result| {
+        queue.update(|queue| {
+            queue.resolve(result);
+        });
+    });
+
+    view! {
+        {children()}
+        {move || {
+            current.get()?;
+            let config = queue.with_untracked(|queue| queue.current().cloned())?;
+            // Rebuilt whenever `current_id` changes, so no state carries over from the previous
+            // alert and every alert gets its own visibility signal, starting open.
+            let show_alert = signal(true);
+            Some(view! {
+                <Alert
+                    show_alert=show_alert
+                    title=config.title
+                    body=config.body
+                    icon_type=config.icon_type
+                    animated_icon=config.animated_icon
+                    image_url=config.image_url
+                    image_alt=config.image_alt
+                    image_width=config.image_width
+                    image_height=config.image_height
+                    image_fallback_url=config.image_fallback_url
+                    position=config.position
+                    timeout=config.timeout.map(u64::from)
+                    confirm_button_text=config.confirm_button_text
+                    cancel_button_text=config.cancel_button_text
+                    deny_button_text=config.deny_button_text
+                    show_confirm_button=config.show_confirm_button
+                    show_cancel_button=config.show_cancel_button
+                    show_deny_button=config.show_deny_button
+                    show_close_button=config.show_close_button
+                    actions=config.actions
+                    input=config.input
+                    input_placeholder=config.input_placeholder
+                    input_value=config.input_value
+                    input_attributes=config.input_attributes
+                    input_options=config.input_options
+                    input_label=config.input_label
+                    input_validator=config.input_validator.map(|validator| {
+                        Callback::new(move |value: String| validator.validate(&value))
+                    })
+                    pre_confirm=config.pre_confirm.map(|pre_confirm| {
+                        Callback::new(move |value| pre_confirm.run(value))
+                    })
+                    classes=config.classes
+                    styles=config.styles
+                    animation=config.animation
+                    animation_duration=config.animation_duration
+                    reduced_motion=config.reduced_motion
+                    initial_focus=config.initial_focus
+                    did_close=did_close
+                />
+            })
+        }}
+    }
+}
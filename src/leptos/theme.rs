@@ -0,0 +1,58 @@
+use crate::common::*;
+use leptos::prelude::*;
+
+/// Alert Theme Provider Component
+///
+/// Sets the `--alert-rs-*` CSS variables of an [`AlertTheme`] for the whole page, so every
+/// alert and toast switches theme at once when `mode` changes.
+///
+/// # Examples
+/// ```rust
+/// use alert_rs::common::{AlertTheme, ThemeMode};
+/// use alert_rs::leptos::{Alert, AlertThemeProvider};
+/// use leptos::prelude::*;
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     let show_alert = signal(true);
+///     let dark = RwSignal::new(false);
+///     let mode = Signal::derive(move || if dark.get() { ThemeMode::Dark } else { ThemeMode::Light });
+///     view! {
+///         <AlertThemeProvider mode=mode light=AlertTheme::light().confirm_color("#7c3aed")>
+///             <button on:click=move |_| dark.update(|dark| *dark = !*dark)>{"Toggle theme"}</button>
+///             <Alert show_alert=show_alert title="Themed" timeout=0 />
+///         </AlertThemeProvider>
+///     }
+/// }
+/// ```
+#[component]
+pub fn AlertThemeProvider(
+    /// The application content whose alerts and toasts follow the theme.
+    children: Children,
+
+    /// Which theme to apply.
+    ///
+    /// Defaults to `ThemeMode::Auto`, following the system color scheme.
+    #[prop(into, default = ThemeMode::Auto.into())]
+    mode: Signal<ThemeMode>,
+
+    /// The theme used in light mode.
+    ///
+    /// Defaults to `AlertTheme::light()`.
+    #[prop(into, default = AlertTheme::light().into())]
+    light: Signal<AlertTheme>,
+
+    /// The theme used in dark mode.
+    ///
+    /// Defaults to `AlertTheme::dark()`.
+    #[prop(into, default = AlertTheme::dark().into())]
+    dark: Signal<AlertTheme>,
+) -> impl IntoView {
+    let stylesheet =
+        move || light.with(|light| dark.with(|dark| mode.get().stylesheet(light, dark)));
+
+    view! {
+        <style>{stylesheet}</style>
+        {children()}
+    }
+}
@@ -0,0 +1,229 @@
+use crate::common::*;
+use leptos::prelude::*;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Handle to the toasts of the nearest `ToastProvider`.
+///
+/// Obtained with [`use_toast`]. The handle is `Copy` and every copy controls the same stacks.
+#[derive(Clone, Copy)]
+pub struct ToastHandle {
+    manager: RwSignal<ToastManager>,
+}
+
+impl ToastHandle {
+    /// Shows a toast, or queues it if its stack is full, and returns its id.
+    pub fn push(&self, toast: Toast) -> ToastId {
+        self.manager
+            .try_update(|manager| manager.push(toast))
+            .expect("the `ToastProvider` was disposed")
+    }
+
+    /// Removes a toast before its timeout expires.
+    pub fn dismiss(&self, id: ToastId) {
+        if self
+            .manager
+            .try_with_untracked(|manager| manager.contains(id))
+            .unwrap_or(false)
+        {
+            self.manager.update(|manager| {
+                manager.dismiss(id);
+            });
+        }
+    }
+
+    /// Removes every toast.
+    pub fn clear(&self) {
+        self.manager.update(ToastManager::clear);
+    }
+}
+
+/// Returns the [`ToastHandle`] of the nearest `ToastProvider`.
+///
+/// # Panics
+/// Panics when called outside of a `ToastProvider`.
+pub fn use_toast() -> ToastHandle {
+    expect_context::<ToastHandle>()
+}
+
+/// Toast Provider Component
+///
+/// Hosts a [`ToastManager`] for its children and renders one stack of toasts per `Position`.
+/// Descendants call [`use_toast`] to push toasts; each toast is removed when its own `timeout`
+/// expires or when its close button is clicked.
+///
+/// # Examples
+/// ```rust
+/// use alert_rs::common::Toast;
+/// use alert_rs::leptos::{use_toast, ToastProvider};
+/// use alert_rs::IconType;
+/// use leptos::prelude::*;
+///
+/// #[component]
+/// pub fn SaveButton() -> impl IntoView {
+///     let toast = use_toast();
+///     view! {
+///         <button on:click=move |_| {
+///             toast.push(Toast::new("Your changes were saved.").icon_type(IconType::Success));
+///         }>{"Save"}</button>
+///     }
+/// }
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     view! {
+///         <ToastProvider gap=8 max_visible=3>
+///             <SaveButton />
+///         </ToastProvider>
+///     }
+/// }
+/// ```
+#[component]
+pub fn ToastProvider(
+    /// The application content that can push toasts through [`use_toast`].
+    children: Children,
+
+    /// The gap in pixels between stacked toasts.
+    ///
+    /// Defaults to `10`.
+    #[prop(default = 10)]
+    gap: u32,
+
+    /// The number of toasts shown at once per position; the rest are queued.
+    ///
+    /// Defaults to `5`.
+    #[prop(default = 5)]
+    max_visible: usize,
+
+    /// Custom CSS class for styling each toast.
+    ///
+    /// Defaults to an empty string.
+    #[prop(into, default = "".into())]
+    toast_class: Signal<String>,
+
+    /// Default style for each toast.
+    ///
+    /// Defaults to `DEFAULT_TOAST_STYLE`.
+    #[prop(into, default = DEFAULT_TOAST_STYLE.into())]
+    toast_style: Signal<String>,
+
+    /// Default style for the toast title.
+    ///
+    /// Defaults to `DEFAULT_TOAST_TITLE_STYLE`.
+    #[prop(into, default = DEFAULT_TOAST_TITLE_STYLE.into())]
+    title_style: Signal<String>,
+
+    /// Default style for the toast body.
+    ///
+    /// Defaults to `DEFAULT_MESSAGE_STYLE`.
+    #[prop(into, default = DEFAULT_MESSAGE_STYLE.into())]
+    message_style: Signal<String>,
+
+    /// Default style for the close button of each toast.
+    ///
+    /// Defaults to `DEFAULT_CLOSE_BUTTON_STYLE`.
+    #[prop(into, default = DEFAULT_CLOSE_BUTTON_STYLE.into())]
+    close_button_style: Signal<String>,
+) -> impl IntoView {
+    let manager = RwSignal::new(
+        ToastManager::new()
+            .with_gap(gap)
+            .with_max_visible(max_visible),
+    );
+    let handle = ToastHandle { manager };
+    provide_context(handle);
+
+    let timers = StoredValue::new(HashMap::<ToastId, TimeoutHandle>::new());
+
+    Effect::new(move || {
+        let timeouts = manager.with(ToastManager::visible_timeouts);
+        timers.update_value(|timers| {
+            timers.retain(|id, timer| {
+                let visible = timeouts.iter().any(|(visible, _)| visible == id);
+                if !visible {
+                    timer.clear();
+                }
+                visible
+            });
+            for (id, millis) in timeouts {
+                if let Entry::Vacant(entry) = timers.entry(id) {
+                    if let Ok(timer) = set_timeout_with_handle(
+                        move || handle.dismiss(id),
+                        Duration::from_millis(millis.into()),
+                    ) {
+                        entry.insert(timer);
+                    }
+                }
+            }
+        });
+    });
+
+    on_cleanup(move || {
+        timers.update_value(|timers| {
+            for (_, timer) in timers.drain() {
+                timer.clear();
+            }
+        });
+    });
+
+    let positions = move || {
+        manager.with(|manager| {
+            manager
+                .stacks()
+                .into_iter()
+                .map(|(position, _)| position)
+                .collect::<Vec<_>>()
+        })
+    };
+
+    view! {
+        {children()}
+        <For each=positions key=|position| position.clone() let:position>
+            {
+                let style = manager.with_untracked(|manager| manager.stack_style(&position));
+                let live_position = position.clone();
+                let aria_live =
+                    move || manager.with(|manager| manager.stack_aria_live(&live_position));
+                let toasts = move || {
+                    manager.with(|manager| {
+                        manager
+                            .visible(&position)
+                            .into_iter()
+                            .map(|(id, toast)| (id, toast.clone()))
+                            .collect::<Vec<_>>()
+                    })
+                };
+                view! {
+                    <div style=style aria-live=aria_live aria-relevant="additions text">
+                        <For
+                            each=toasts
+                            key=|(id, _)| *id
+                            children=move |(id, toast)| {
+                                let color = toast.icon_type.default_color();
+                                let style = move || {
+                                    format!("{} border-left: 4px solid {color};", toast_style.get())
+                                };
+                                view! {
+                                    <div class=move || toast_class.get() style=style aria-atomic="true">
+                                        <button
+                                            style=move || close_button_style.get()
+                                            on:click=move |_| handle.dismiss(id)
+                                            aria-label="Close"
+                                        >
+                                            {"X"}
+                                        </button>
+                                        {(!toast.title.is_empty()).then(|| {
+                                            view! { <strong style=move || title_style.get()>{toast.title}</strong> }
+                                        })}
+                                        <p style=move || message_style.get()>{toast.body}</p>
+                                    </div>
+                                }
+                            }
+                        />
+                    </div>
+                }
+            }
+        </For>
+    }
+}
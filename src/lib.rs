@@ -16,4 +16,4 @@ pub mod dioxus;
 #[cfg(feature = "lep")]
 pub mod leptos;
 
-pub use common::{IconType, Position};
+pub use common::{IconType, InputType, Position};
@@ -1,8 +1,22 @@
 use crate::common::*;
+use gloo::events::EventListener;
 use gloo::timers::callback::Timeout;
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
 use web_sys::window;
 use yew::prelude::*;
 
+#[cfg(feature = "markdown")]
+mod markdown;
+mod queue;
+mod theme;
+mod toast;
+
+pub use queue::*;
+pub use theme::*;
+pub use toast::*;
+
 /// Properties for configuring the `Alert` component.
 ///
 /// This component allows the display of customizable alerts with various behaviors and styles.
@@ -15,7 +29,32 @@ pub struct AlertProps {
     /// This defines the message content that will be displayed within the alert.
     /// Defaults to an empty string if not specified.
     #[prop_or_default]
-    pub body: &'static str,
+    pub body: AttrValue,
+
+    /// An HTML snippet rendered below the body text, such as a legacy SweetAlert2 `html:` message.
+    ///
+    /// With the `sanitize` feature it is cleaned with an allow-list and injected as HTML. Without
+    /// it, the snippet is shown as plain text and never injected. Defaults to an empty string.
+    #[prop_or_default]
+    pub html_body: AttrValue,
+
+    /// Rich content rendered below the body text, such as links, lists or components.
+    ///
+    /// Combine it with `body` or leave `body` empty to replace it. Defaults to nothing.
+    #[prop_or_default]
+    pub children: Html,
+
+    /// Content rendered at the top of the alert box, above the icon.
+    ///
+    /// Defaults to nothing.
+    #[prop_or_default]
+    pub header: Html,
+
+    /// Content rendered at the bottom of the alert box, below the buttons.
+    ///
+    /// Defaults to nothing.
+    #[prop_or_default]
+    pub footer: Html,
 
     /// The state handle controlling the visibility of the alert.
     ///
@@ -25,172 +64,285 @@ pub struct AlertProps {
     /// The timeout duration in milliseconds before the alert auto-closes.
     ///
     /// Defines how long the alert stays visible before automatically closing.
-    /// Defaults to `2500` milliseconds (2.5 seconds).
-    #[prop_or(2500)]
-    pub timeout: u32,
+    /// Defaults to the app-wide `AlertDefaults` value, or `2500` milliseconds (2.5 seconds).
+    #[prop_or_default]
+    pub timeout: Option<u32>,
 
     /// The title text for the alert.
     ///
     /// This defines the heading or title of the alert. Defaults to `"Info"`.
-    #[prop_or("Info")]
-    pub title: &'static str,
+    #[prop_or(AttrValue::Static("Info"))]
+    pub title: AttrValue,
+
+    /// The `id` of the alert box, also the prefix of its title and body `id`s.
+    ///
+    /// They are referenced by `aria-labelledby` and `aria-describedby`. Defaults to an empty
+    /// string, which generates a unique `alert-rs-N` once per mounted alert.
+    #[prop_or_default]
+    pub id: AttrValue,
 
     /// Text for the confirm button.
     ///
     /// This defines the label for the confirm button within the alert.
-    /// Defaults to `"Okay"`.
-    #[prop_or("Okay")]
-    pub confirm_button_text: &'static str,
+    /// Defaults to the app-wide `AlertDefaults` value, or `"Okay"`.
+    #[prop_or_default]
+    pub confirm_button_text: Option<AttrValue>,
 
     /// Text for the cancel button.
     ///
     /// This defines the label for the cancel button within the alert.
-    /// Defaults to `"Cancel"`.
-    #[prop_or("Cancel")]
-    pub cancel_button_text: &'static str,
+    /// Defaults to the app-wide `AlertDefaults` value, or `"Cancel"`.
+    #[prop_or_default]
+    pub cancel_button_text: Option<AttrValue>,
+
+    /// Text for the deny button.
+    ///
+    /// This defines the label for the deny button within the alert.
+    /// Defaults to the app-wide `AlertDefaults` value, or `"No"`.
+    #[prop_or_default]
+    pub deny_button_text: Option<AttrValue>,
 
     /// Whether to show the confirm button.
     ///
-    /// If `true`, the confirm button is displayed. Defaults to `true`.
-    #[prop_or(true)]
-    pub show_confirm_button: bool,
+    /// If `true`, the confirm button is displayed. Defaults to the app-wide `AlertDefaults` value, or `true`.
+    #[prop_or_default]
+    pub show_confirm_button: Option<bool>,
 
     /// Whether to show the cancel button.
     ///
-    /// If `true`, the cancel button is displayed. Defaults to `true`.
-    #[prop_or(true)]
-    pub show_cancel_button: bool,
+    /// If `true`, the cancel button is displayed. Defaults to the app-wide `AlertDefaults` value, or `true`.
+    #[prop_or_default]
+    pub show_cancel_button: Option<bool>,
+
+    /// Whether to show the deny button.
+    ///
+    /// If `true`, a deny button is displayed between the confirm and cancel buttons, for
+    /// "Save / Don't save / Cancel" dialogs. Defaults to the app-wide `AlertDefaults` value, or `false`.
+    #[prop_or_default]
+    pub show_deny_button: Option<bool>,
+
+    /// Extra buttons rendered after the confirm, deny and cancel buttons.
+    ///
+    /// Each click calls `on_action` with the id of the action, and closes the alert with
+    /// `CloseReason::Action` unless the action was built with `closes(false)`. Hide the confirm
+    /// and cancel buttons to use the actions alone. Ignored in `native` mode.
+    /// Defaults to no actions.
+    #[prop_or_default]
+    pub actions: Vec<AlertAction>,
 
     /// Whether to show the close button.
     ///
-    /// If `true`, a close button is included. Defaults to `false`.
-    #[prop_or(false)]
-    pub show_close_button: bool,
+    /// If `true`, a close button is included. Defaults to the app-wide `AlertDefaults` value, or `false`.
+    #[prop_or_default]
+    pub show_close_button: Option<bool>,
 
     /// The position of the alert on the screen.
     ///
     /// Defines where the alert will appear on the screen (e.g., top-left, top-right, bottom-left, bottom-right).
-    /// Defaults to `Position::TopRight`.
-    #[prop_or(Position::TopRight)]
-    pub position: Position,
+    /// Defaults to the app-wide `AlertDefaults` value, or `Position::TopRight`.
+    #[prop_or_default]
+    pub position: Option<Position>,
 
     /// The type of icon to display in the alert.
     ///
     /// Defines the icon associated with the alert type (e.g., success, error, warning, info).
-    /// Defaults to `IconType::Info`.
-    #[prop_or(IconType::Info)]
-    pub icon_type: IconType,
+    /// Defaults to the app-wide `AlertDefaults` value, or `IconType::Info`.
+    #[prop_or_default]
+    pub icon_type: Option<IconType>,
 
     /// The color of the icon.
     ///
     /// Defines the color of the icon displayed in the alert. This value is applied as a CSS class.
     /// Defaults to an empty string.
-    #[prop_or("")]
-    pub icon_color: &'static str,
+    #[prop_or_default]
+    pub icon_color: AttrValue,
 
     /// The width of the icon.
     ///
     /// Defines the size of the icon in percentage or pixel values. Defaults to `"50"`.
-    #[prop_or("50")]
-    pub icon_width: &'static str,
+    #[prop_or(AttrValue::Static("50"))]
+    pub icon_width: AttrValue,
+
+    /// Whether to render the animated SweetAlert-style icon instead of the static one.
+    ///
+    /// The animation is pure CSS and falls back to the static icon with reduced motion.
+    /// Defaults to `false`.
+    #[prop_or(false)]
+    pub animated_icon: bool,
+
+    /// The node rendered as the icon when `icon_type` is `IconType::Custom(CustomIcon::Node)`.
+    ///
+    /// Defaults to nothing.
+    #[prop_or_default]
+    pub icon: Html,
+
+    /// URL of an image shown above the title, alongside or instead of the icon.
+    ///
+    /// The image is lazy-loaded. Defaults to an empty string, which shows no image.
+    #[prop_or_default]
+    pub image_url: AttrValue,
+
+    /// Alternative text of the image.
+    ///
+    /// Defaults to an empty string.
+    #[prop_or_default]
+    pub image_alt: AttrValue,
+
+    /// Width of the image, such as `"400"`.
+    ///
+    /// Defaults to an empty string, which keeps the image's own width.
+    #[prop_or_default]
+    pub image_width: AttrValue,
+
+    /// Height of the image, such as `"200"`.
+    ///
+    /// Defaults to an empty string, which keeps the image's own height.
+    #[prop_or_default]
+    pub image_height: AttrValue,
+
+    /// URL of the image shown when `image_url` fails to load.
+    ///
+    /// When both fail, or no fallback is set, the image is removed. Defaults to an empty string.
+    #[prop_or_default]
+    pub image_fallback_url: AttrValue,
+
+    /// All CSS classes of the alert in one value.
+    ///
+    /// A flat `*_class` prop set on the alert wins over the matching field, which wins over the
+    /// app-wide `AlertDefaults` value. Defaults to no classes.
+    #[prop_or_default]
+    pub classes: AlertClasses,
+
+    /// All inline styles of the alert in one value.
+    ///
+    /// A flat `*_style` prop set on the alert wins over the matching field, which wins over the
+    /// app-wide `AlertDefaults` value. Defaults to no styles.
+    #[prop_or_default]
+    pub styles: AlertStyles,
 
     /// Custom CSS class for styling the alert container.
     ///
     /// This allows for the styling of the entire alert container, including background color, padding, etc.
-    /// Defaults to an empty string.
+    /// Defaults to the matching `classes` field, the app-wide `AlertDefaults` value, or an empty string.
     #[prop_or_default]
-    pub alert_class: &'static str,
+    pub alert_class: Option<AttrValue>,
 
     /// Custom CSS class for styling the icon in the alert.
     ///
     /// This applies additional styling to the icon inside the alert.
-    /// Defaults to an empty string.
+    /// Defaults to the matching `classes` field, the app-wide `AlertDefaults` value, or an empty string.
+    #[prop_or_default]
+    pub icon_class: Option<AttrValue>,
+
+    /// Custom CSS class for styling the image in the alert.
+    ///
+    /// Defaults to the matching `classes` field, the app-wide `AlertDefaults` value, or an empty string.
     #[prop_or_default]
-    pub icon_class: &'static str,
+    pub image_class: Option<AttrValue>,
 
     /// Custom CSS class for styling the confirm button.
     ///
     /// This applies additional styling to the confirm button within the alert.
-    /// Defaults to an empty string.
+    /// Defaults to the matching `classes` field, the app-wide `AlertDefaults` value, or an empty string.
     #[prop_or_default]
-    pub confirm_button_class: &'static str,
+    pub confirm_button_class: Option<AttrValue>,
 
     /// Custom CSS class for styling the cancel button.
     ///
     /// This applies additional styling to the cancel button within the alert.
-    /// Defaults to an empty string.
+    /// Defaults to the matching `classes` field, the app-wide `AlertDefaults` value, or an empty string.
     #[prop_or_default]
-    pub cancel_button_class: &'static str,
+    pub cancel_button_class: Option<AttrValue>,
+
+    /// Custom CSS class for styling the deny button.
+    ///
+    /// This applies additional styling to the deny button within the alert.
+    /// Defaults to the matching `classes` field, the app-wide `AlertDefaults` value, or an empty string.
+    #[prop_or_default]
+    pub deny_button_class: Option<AttrValue>,
 
     /// Custom CSS class for styling the title of the alert.
     ///
     /// This applies additional styling to the title text within the alert.
-    /// Defaults to an empty string.
+    /// Defaults to the matching `classes` field, the app-wide `AlertDefaults` value, or an empty string.
     #[prop_or_default]
-    pub title_class: &'static str,
+    pub title_class: Option<AttrValue>,
 
     /// Custom CSS class for styling the message in the alert.
     ///
     /// This applies additional styling to the body message inside the alert.
-    /// Defaults to an empty string.
+    /// Defaults to the matching `classes` field, the app-wide `AlertDefaults` value, or an empty string.
     #[prop_or_default]
-    pub body_class: &'static str,
+    pub body_class: Option<AttrValue>,
 
     /// Default style for the alert component.
     ///
     /// This defines default inline styles for the alert.
-    /// Defaults to `DEFAULT_ALERT_STYLE`.
-    #[prop_or(DEFAULT_ALERT_STYLE)]
-    pub alert_style: &'static str,
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_ALERT_STYLE`.
+    #[prop_or_default]
+    pub alert_style: Option<AttrValue>,
 
     /// Default style for the close button.
     ///
     /// This defines the default inline styles for the close button within the alert.
-    /// Defaults to `DEFAULT_CLOSE_BUTTON_STYLE`.
-    #[prop_or(DEFAULT_CLOSE_BUTTON_STYLE)]
-    pub close_button_style: &'static str,
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_CLOSE_BUTTON_STYLE`.
+    #[prop_or_default]
+    pub close_button_style: Option<AttrValue>,
 
     /// Default style for the confirm button.
     ///
     /// This defines the default inline styles for the confirm button within the alert.
-    /// Defaults to `DEFAULT_CONFIRM_BUTTON_STYLE`.
-    #[prop_or(DEFAULT_CONFIRM_BUTTON_STYLE)]
-    pub confirm_button_style: &'static str,
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_CONFIRM_BUTTON_STYLE`.
+    #[prop_or_default]
+    pub confirm_button_style: Option<AttrValue>,
 
     /// Default style for the cancel button.
     ///
     /// This defines the default inline styles for the cancel button within the alert.
-    /// Defaults to `DEFAULT_CANCEL_BUTTON_STYLE`.
-    #[prop_or(DEFAULT_CANCEL_BUTTON_STYLE)]
-    pub cancel_button_style: &'static str,
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_CANCEL_BUTTON_STYLE`.
+    #[prop_or_default]
+    pub cancel_button_style: Option<AttrValue>,
+
+    /// Default style for the deny button.
+    ///
+    /// This defines the default inline styles for the deny button within the alert.
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_DENY_BUTTON_STYLE`.
+    #[prop_or_default]
+    pub deny_button_style: Option<AttrValue>,
 
     /// Default style for the icon in the alert.
     ///
     /// This defines the default inline styles for the icon.
-    /// Defaults to `DEFAULT_ICON_STYLE`.
-    #[prop_or(DEFAULT_ICON_STYLE)]
-    pub icon_style: &'static str,
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_ICON_STYLE`.
+    #[prop_or_default]
+    pub icon_style: Option<AttrValue>,
+
+    /// Default style for the image in the alert.
+    ///
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_IMAGE_STYLE`.
+    #[prop_or_default]
+    pub image_style: Option<AttrValue>,
 
     /// Default style for the title text in the alert.
     ///
     /// This defines the default inline styles for the title.
-    /// Defaults to `DEFAULT_TITLE_STYLE`.
-    #[prop_or(DEFAULT_TITLE_STYLE)]
-    pub title_style: &'static str,
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_TITLE_STYLE`.
+    #[prop_or_default]
+    pub title_style: Option<AttrValue>,
 
     /// Default style for the separator between the title and message.
     ///
     /// This defines the default inline styles for the separator line.
-    /// Defaults to `DEFAULT_SEPARATOR_STYLE`.
-    #[prop_or(DEFAULT_SEPARATOR_STYLE)]
-    pub separator_style: &'static str,
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_SEPARATOR_STYLE`.
+    #[prop_or_default]
+    pub separator_style: Option<AttrValue>,
 
     /// Default style for the message text in the alert.
     ///
     /// This defines the default inline styles for the message text.
-    /// Defaults to `DEFAULT_MESSAGE_STYLE`.
-    #[prop_or(DEFAULT_MESSAGE_STYLE)]
-    pub message_style: &'static str,
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_MESSAGE_STYLE`.
+    #[prop_or_default]
+    pub message_style: Option<AttrValue>,
 
     /// Whether to use the native browser alert implementation.
     ///
@@ -199,6 +351,157 @@ pub struct AlertProps {
     #[prop_or(false)]
     pub native: bool,
 
+    /// The prompt input rendered between the body and the buttons.
+    ///
+    /// Its value is returned in the `AlertResult` passed to `did_close` when the alert is
+    /// confirmed. In `native` mode, `window.prompt()` is used instead.
+    /// Defaults to `InputType::None`.
+    #[prop_or_default]
+    pub input: InputType,
+
+    /// Placeholder text of the prompt input.
+    ///
+    /// Defaults to an empty string.
+    #[prop_or_default]
+    pub input_placeholder: AttrValue,
+
+    /// Initial value of the prompt input.
+    ///
+    /// Defaults to an empty string.
+    #[prop_or_default]
+    pub input_value: AttrValue,
+
+    /// Extra HTML attributes of the prompt input, such as `("maxlength", "20")`.
+    ///
+    /// Defaults to no attributes.
+    #[prop_or_default]
+    pub input_attributes: Vec<(AttrValue, AttrValue)>,
+
+    /// The `(value, label)` options of a select or radio input.
+    ///
+    /// Defaults to no options.
+    #[prop_or_default]
+    pub input_options: Vec<(AttrValue, AttrValue)>,
+
+    /// The label shown next to a checkbox input.
+    ///
+    /// Defaults to an empty string.
+    #[prop_or_default]
+    pub input_label: AttrValue,
+
+    /// Default style for the labels of radio and checkbox inputs.
+    ///
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_INPUT_LABEL_STYLE`.
+    #[prop_or_default]
+    pub input_label_style: Option<AttrValue>,
+
+    /// Check run on the input value when the confirm button is clicked.
+    ///
+    /// Returning `Err(message)` keeps the alert open, shows `message` under the input and marks
+    /// the input with `aria-invalid`. Defaults to no validation.
+    #[prop_or_default]
+    pub input_validator: Option<Callback<String, Result<(), String>>>,
+
+    /// Async check run when the confirm button is clicked, before the alert closes.
+    ///
+    /// It receives the input value and puts the alert into a loading state: a spinner on the
+    /// confirm button, all buttons disabled and the auto-close timer stopped. `Ok` closes the
+    /// alert, `Err(message)` keeps it open and shows `message` in the validation area.
+    /// Defaults to closing immediately.
+    #[prop_or_default]
+    pub pre_confirm: Option<Callback<Option<String>, PreConfirmFuture>>,
+
+    /// Default style for the spinner shown on the confirm button while `pre_confirm` runs.
+    ///
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_SPINNER_STYLE`.
+    #[prop_or_default]
+    pub spinner_style: Option<AttrValue>,
+
+    /// Custom CSS class for styling the validation message.
+    ///
+    /// Defaults to the matching `classes` field, the app-wide `AlertDefaults` value, or an empty string.
+    #[prop_or_default]
+    pub validation_message_class: Option<AttrValue>,
+
+    /// Default style for the validation message.
+    ///
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_VALIDATION_MESSAGE_STYLE`.
+    #[prop_or_default]
+    pub validation_message_style: Option<AttrValue>,
+
+    /// Custom CSS class for styling the prompt input.
+    ///
+    /// Defaults to the matching `classes` field, the app-wide `AlertDefaults` value, or an empty string.
+    #[prop_or_default]
+    pub input_class: Option<AttrValue>,
+
+    /// Default style for the prompt input.
+    ///
+    /// This defines the default inline styles for the input or textarea.
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_INPUT_STYLE`.
+    #[prop_or_default]
+    pub input_style: Option<AttrValue>,
+
+    /// Whether clicking the backdrop around the alert closes it.
+    ///
+    /// If `true`, a click outside the alert box dismisses it with `CloseReason::Backdrop`.
+    /// Defaults to the app-wide `AlertDefaults` value, or `false`.
+    #[prop_or_default]
+    pub close_on_backdrop_click: Option<bool>,
+
+    /// Whether pressing the Escape key closes the alert.
+    ///
+    /// If `true`, Escape dismisses the alert with `CloseReason::Escape`. Defaults to the app-wide `AlertDefaults` value, or `true`.
+    #[prop_or_default]
+    pub close_on_escape: Option<bool>,
+
+    /// The element focused when the alert opens.
+    ///
+    /// While the alert is open, Tab and Shift+Tab cycle through its controls, and focus moves
+    /// back to the previously focused element when it closes. Defaults to the app-wide `AlertDefaults` value, or `InitialFocus::Confirm`.
+    #[prop_or_default]
+    pub initial_focus: Option<InitialFocus>,
+
+    /// Default style for the backdrop behind the alert box.
+    ///
+    /// This defines the default inline styles for the clickable backdrop.
+    /// Defaults to the matching `styles` field, the app-wide `AlertDefaults` value, or `DEFAULT_BACKDROP_STYLE`.
+    #[prop_or_default]
+    pub backdrop_style: Option<AttrValue>,
+
+    /// The animation played when the alert opens and closes.
+    ///
+    /// On close, the alert stays mounted until the exit animation finishes.
+    /// Defaults to the app-wide `AlertDefaults` value, or `AlertAnimation::None`, so `did_close` fires as soon as the alert is hidden.
+    #[prop_or_default]
+    pub animation: Option<AlertAnimation>,
+
+    /// Duration of the enter and exit animations in milliseconds.
+    ///
+    /// Defaults to the app-wide `AlertDefaults` value, or `300` milliseconds.
+    #[prop_or_default]
+    pub animation_duration: Option<u32>,
+
+    /// Custom CSS class added to the alert box while it opens and stays open.
+    ///
+    /// Use it to plug in animation libraries such as Animate.css. Defaults to an empty string.
+    #[prop_or_default]
+    pub enter_class: AttrValue,
+
+    /// Custom CSS class added to the alert box while its exit animation plays.
+    ///
+    /// The alert is kept mounted for `animation_duration`, even with `AlertAnimation::None`.
+    /// Defaults to an empty string.
+    #[prop_or_default]
+    pub exit_class: AttrValue,
+
+    /// Whether the alert honors the system's reduced motion setting.
+    ///
+    /// With reduced motion, animations, the animation class hooks and the spinner animation are
+    /// turned off. Defaults to the app-wide `AlertDefaults` value, or `ReducedMotion::System`, which follows `prefers-reduced-motion`.
+    #[prop_or_default]
+    pub reduced_motion: Option<ReducedMotion>,
+
     /// Callback triggered when the alert opens.
     ///
     /// This is triggered before the alert is shown to the user. Defaults to no-op.
@@ -213,9 +516,10 @@ pub struct AlertProps {
 
     /// Callback triggered when the alert closes.
     ///
-    /// This is triggered after the alert is closed. Defaults to no-op.
+    /// This is triggered after the alert is closed and receives an `AlertResult` describing
+    /// why it closed. Defaults to no-op.
     #[prop_or_default]
-    pub did_close: Callback<()>,
+    pub did_close: Callback<AlertResult>,
 
     /// Callback triggered when the confirm button is clicked.
     ///
@@ -234,6 +538,111 @@ pub struct AlertProps {
     /// This is triggered when the user clicks the cancel button. Defaults to no-op.
     #[prop_or_default]
     pub on_cancel: Callback<()>,
+
+    /// Callback triggered when the deny button is clicked.
+    ///
+    /// This is triggered when the user clicks the deny button. Defaults to no-op.
+    #[prop_or_default]
+    pub on_deny: Callback<()>,
+
+    /// Callback triggered when one of the `actions` is clicked, with its id.
+    ///
+    /// Defaults to no-op.
+    #[prop_or_default]
+    pub on_action: Callback<String>,
+}
+
+/// Performs the effects emitted by the shared [`AlertMachine`].
+#[derive(Clone)]
+struct Driver {
+    machine: Rc<RefCell<AlertMachine>>,
+    timer: Rc<RefCell<Option<Timeout>>>,
+    show_alert: UseStateHandle<bool>,
+    will_open: Callback<()>,
+    did_open: Callback<()>,
+    did_close: Callback<AlertResult>,
+    on_confirm: Callback<()>,
+    on_close: Callback<()>,
+    on_cancel: Callback<()>,
+    on_deny: Callback<()>,
+    on_action: Callback<String>,
+    input_validator: Option<Callback<String, Result<(), String>>>,
+    validation_message: UseStateHandle<Option<String>>,
+    pre_confirm: Option<Callback<Option<String>, PreConfirmFuture>>,
+    loading: UseStateHandle<bool>,
+    leaving: UseStateHandle<bool>,
+    native: bool,
+}
+
+impl Driver {
+    fn validate(&self, value: &str) -> Result<(), String> {
+        match &self.input_validator {
+            Some(validator) => validator.emit(value.to_string()),
+            None => Ok(()),
+        }
+    }
+
+    fn confirm(&self) {
+        let value = self.machine.borrow().value().map(str::to_string);
+        if let Some(Err(message)) = value.map(|value| self.validate(&value)) {
+            self.send(AlertEvent::Invalid(message));
+        } else {
+            self.send(AlertEvent::Confirm);
+        }
+    }
+
+    fn send(&self, event: AlertEvent) {
+        let effects = self.machine.borrow_mut().send(event);
+        for effect in effects {
+            match effect {
+                AlertEffect::WillOpen => self.will_open.emit(()),
+                AlertEffect::DidOpen => self.did_open.emit(()),
+                AlertEffect::StartTimer(millis) => {
+                    let driver = self.clone();
+                    let handle = Timeout::new(millis, move || driver.send(AlertEvent::Timeout));
+                    self.timer.borrow_mut().replace(handle);
+                }
+                AlertEffect::ClearTimer => {
+                    self.timer.borrow_mut().take();
+                }
+                AlertEffect::RunPreConfirm(value) => {
+                    let driver = self.clone();
+                    let future = self.pre_confirm.as_ref().map(|hook| hook.emit(value));
+                    yew::platform::spawn_local(async move {
+                        let result = match future {
+                            Some(future) => future.await,
+                            None => Ok(()),
+                        };
+                        // A native dialog is already gone, so report the error and close.
+                        let failed = result.as_ref().err().cloned();
+                        driver.send(AlertEvent::PreConfirmed(result));
+                        if let (true, Some(message)) = (driver.native, failed) {
+                            if let Some(win) = window() {
+                                let _ = win.alert_with_message(&message);
+                            }
+                            driver.send(AlertEvent::Close);
+                        }
+                    });
+                }
+                AlertEffect::Loading(loading) => self.loading.set(loading),
+                AlertEffect::Confirm => self.on_confirm.emit(()),
+                AlertEffect::Deny => self.on_deny.emit(()),
+                AlertEffect::Cancel => self.on_cancel.emit(()),
+                AlertEffect::Close => self.on_close.emit(()),
+                AlertEffect::Action(id) => self.on_action.emit(id),
+                AlertEffect::ValidationMessage(message) => self.validation_message.set(message),
+                AlertEffect::Hide => self.show_alert.set(false),
+                AlertEffect::StartExit(millis) => {
+                    self.leaving.set(true);
+                    let driver = self.clone();
+                    let handle = Timeout::new(millis, move || driver.send(AlertEvent::Exited));
+                    self.timer.borrow_mut().replace(handle);
+                }
+                AlertEffect::Unmount => self.leaving.set(false),
+                AlertEffect::DidClose(result) => self.did_close.emit(result),
+            }
+        }
+    }
 }
 
 /// Alert Component
@@ -246,47 +655,98 @@ pub struct AlertProps {
 /// # Properties
 /// The component uses the `AlertProps` struct for its properties. Key properties include:
 ///
-/// - **body**: The content of the alert message (`&'static str`). Default: `""`.
+/// - **body**: The content of the alert message (`AttrValue`). Default: `""`.
+/// - **html_body**: HTML snippet rendered below the body text, sanitized with the `sanitize` feature and shown as text without it (`AttrValue`). Default: `""`.
+/// - **children**: Rich content rendered below the body text (`Html`). Default: nothing.
+/// - **header**: Content rendered above the icon (`Html`). Default: nothing.
+/// - **footer**: Content rendered below the buttons (`Html`). Default: nothing.
 /// - **show_alert**: State handle controlling the visibility of the alert (`UseStateHandle<bool>`).
 ///   This is a required prop to manage alert visibility.
-/// - **timeout**: Timeout duration in milliseconds before the alert auto-closes (`u32`). Default: `2500`.
-/// - **title**: The title text of the alert (`&'static str`). Default: `"Info"`.
-/// - **confirm_button_text**: Text for the confirm button (`&'static str`). Default: `"Okay"`.
-/// - **cancel_button_text**: Text for the cancel button (`&'static str`). Default: `"Cancel"`.
-/// - **show_confirm_button**: Whether to display the confirm button (`bool`). Default: `true`.
-/// - **show_cancel_button**: Whether to display the cancel button (`bool`). Default: `true`.
-/// - **show_close_button**: Whether to display the close button (`bool`). Default: `false`.
-/// - **position**: The position of the alert on the screen (`Position`). Default: `Position::TopRight`.
-/// - **icon_type**: The icon type displayed with the alert (`IconType`). Default: `IconType::Info`.
-/// - **icon_color**: The color of the icon (`&'static str`). Default: `""`.
-/// - **icon_width**: The width of the icon (`&'static str`). Default: `"50"`.
-/// - **alert_class**: CSS class for styling the alert container (`&'static str`). Default: `""`.
-/// - **icon_class**: CSS class for styling the icon (`&'static str`). Default: `""`.
-/// - **confirm_button_class**: CSS class for styling the confirm button (`&'static str`). Default: `""`.
-/// - **cancel_button_class**: CSS class for styling the cancel button (`&'static str`). Default: `""`.
-/// - **title_class**: CSS class for styling the alert title (`&'static str`). Default: `""`.
-/// - **body_class**: CSS class for styling the message text in the alert (`&'static str`). Default: `""`.
-/// - **alert_style**: Default inline styles for the alert (`&'static str`). Default: `DEFAULT_ALERT_STYLE`.
-/// - **close_button_style**: Default inline styles for the close button (`&'static str`). Default: `DEFAULT_CLOSE_BUTTON_STYLE`.
-/// - **confirm_button_style**: Default inline styles for the confirm button (`&'static str`). Default: `DEFAULT_CONFIRM_BUTTON_STYLE`.
-/// - **cancel_button_style**: Default inline styles for the cancel button (`&'static str`). Default: `DEFAULT_CANCEL_BUTTON_STYLE`.
-/// - **icon_style**: Default inline styles for the icon (`&'static str`). Default: `DEFAULT_ICON_STYLE`.
-/// - **title_style**: Default inline styles for the title text (`&'static str`). Default: `DEFAULT_TITLE_STYLE`.
-/// - **separator_style**: Default inline styles for the separator (`&'static str`). Default: `DEFAULT_SEPARATOR_STYLE`.
-/// - **message_style**: Default inline styles for the message text (`&'static str`). Default: `DEFAULT_MESSAGE_STYLE`.
+/// - **timeout**: Timeout duration in milliseconds before the alert auto-closes (`Option<u32>`). Default: `2500`.
+/// - **title**: The title text of the alert (`AttrValue`). Default: `"Info"`.
+/// - **id**: The `id` of the alert box and prefix of its title and body `id`s (`AttrValue`). Default: a generated `alert-rs-N`.
+/// - **confirm_button_text**: Text for the confirm button (`Option<AttrValue>`). Default: `"Okay"`.
+/// - **cancel_button_text**: Text for the cancel button (`Option<AttrValue>`). Default: `"Cancel"`.
+/// - **deny_button_text**: Text for the deny button (`Option<AttrValue>`). Default: `"No"`.
+/// - **show_confirm_button**: Whether to display the confirm button (`Option<bool>`). Default: `true`.
+/// - **show_cancel_button**: Whether to display the cancel button (`Option<bool>`). Default: `true`.
+/// - **show_deny_button**: Whether to display the deny button (`Option<bool>`). Default: `false`.
+/// - **actions**: Extra buttons after the built-in ones (`Vec<AlertAction>`). Default: `[]`.
+/// - **show_close_button**: Whether to display the close button (`Option<bool>`). Default: `false`.
+/// - **position**: The position of the alert on the screen (`Option<Position>`). Default: `Position::TopRight`.
+/// - **icon_type**: The icon type displayed with the alert (`Option<IconType>`). Default: `IconType::Info`. Built-in icons render only with their `icon-*` feature enabled (all of them by default).
+/// - **icon_color**: The color of the icon (`AttrValue`). Default: `""`.
+/// - **icon_width**: The width of the icon (`AttrValue`). Default: `"50"`.
+/// - **animated_icon**: Whether to render the animated SweetAlert-style icon (`bool`). Default: `false`.
+/// - **icon**: Node rendered as a `IconType::Custom(CustomIcon::Node)` icon (`Html`). Default: nothing.
+/// - **image_url**: URL of a lazy-loaded image shown above the title (`AttrValue`). Default: `""`.
+/// - **image_alt**: Alternative text of the image (`AttrValue`). Default: `""`.
+/// - **image_width**: Width of the image (`AttrValue`). Default: `""`.
+/// - **image_height**: Height of the image (`AttrValue`). Default: `""`.
+/// - **image_fallback_url**: Image shown when `image_url` fails to load (`AttrValue`). Default: `""`.
+/// - **classes**: Every `*_class` prop in one value; flat props win (`AlertClasses`). Default: empty.
+/// - **styles**: Every `*_style` prop in one value; flat props win (`AlertStyles`). Default: empty.
+/// - **alert_class**: CSS class for styling the alert container (`Option<AttrValue>`). Default: `""`.
+/// - **icon_class**: CSS class for styling the icon (`Option<AttrValue>`). Default: `""`.
+/// - **image_class**: CSS class for styling the image (`Option<AttrValue>`). Default: `""`.
+/// - **confirm_button_class**: CSS class for styling the confirm button (`Option<AttrValue>`). Default: `""`.
+/// - **cancel_button_class**: CSS class for styling the cancel button (`Option<AttrValue>`). Default: `""`.
+/// - **deny_button_class**: CSS class for styling the deny button (`Option<AttrValue>`). Default: `""`.
+/// - **title_class**: CSS class for styling the alert title (`Option<AttrValue>`). Default: `""`.
+/// - **body_class**: CSS class for styling the message text in the alert (`Option<AttrValue>`). Default: `""`.
+/// - **alert_style**: Default inline styles for the alert (`Option<AttrValue>`). Default: `DEFAULT_ALERT_STYLE`.
+/// - **close_button_style**: Default inline styles for the close button (`Option<AttrValue>`). Default: `DEFAULT_CLOSE_BUTTON_STYLE`.
+/// - **confirm_button_style**: Default inline styles for the confirm button (`Option<AttrValue>`). Default: `DEFAULT_CONFIRM_BUTTON_STYLE`.
+/// - **cancel_button_style**: Default inline styles for the cancel button (`Option<AttrValue>`). Default: `DEFAULT_CANCEL_BUTTON_STYLE`.
+/// - **deny_button_style**: Default inline styles for the deny button (`Option<AttrValue>`). Default: `DEFAULT_DENY_BUTTON_STYLE`.
+/// - **icon_style**: Default inline styles for the icon (`Option<AttrValue>`). Default: `DEFAULT_ICON_STYLE`.
+/// - **image_style**: Default inline styles for the image (`Option<AttrValue>`). Default: `DEFAULT_IMAGE_STYLE`.
+/// - **title_style**: Default inline styles for the title text (`Option<AttrValue>`). Default: `DEFAULT_TITLE_STYLE`.
+/// - **separator_style**: Default inline styles for the separator (`Option<AttrValue>`). Default: `DEFAULT_SEPARATOR_STYLE`.
+/// - **message_style**: Default inline styles for the message text (`Option<AttrValue>`). Default: `DEFAULT_MESSAGE_STYLE`.
 /// - **native**: Whether to use the native alert implementation (`bool`). Default: `false`.
+/// - **input**: The prompt input shown below the body (`InputType`). Default: `InputType::None`.
+/// - **input_placeholder**: Placeholder of the prompt input (`AttrValue`). Default: `""`.
+/// - **input_value**: Initial value of the prompt input (`AttrValue`). Default: `""`.
+/// - **input_attributes**: Extra HTML attributes of the prompt input (`Vec<(AttrValue, AttrValue)>`). Default: `[]`.
+/// - **input_options**: `(value, label)` options of a select or radio input (`Vec<(AttrValue, AttrValue)>`). Default: `[]`.
+/// - **input_label**: Label next to a checkbox input (`AttrValue`). Default: `""`.
+/// - **input_label_style**: Default inline styles for radio and checkbox labels (`Option<AttrValue>`). Default: `DEFAULT_INPUT_LABEL_STYLE`.
+/// - **input_validator**: Check run on the input value on confirm; an `Err` keeps the alert open (`Option<Callback<String, Result<(), String>>>`). Default: `None`.
+/// - **pre_confirm**: Async check run on confirm with a loading state; an `Err` keeps the alert open (`Option<Callback<Option<String>, PreConfirmFuture>>`). Default: `None`.
+/// - **spinner_style**: Default inline styles for the loading spinner (`Option<AttrValue>`). Default: `DEFAULT_SPINNER_STYLE`.
+/// - **validation_message_class**: CSS class for styling the validation message (`Option<AttrValue>`). Default: `""`.
+/// - **validation_message_style**: Default inline styles for the validation message (`Option<AttrValue>`). Default: `DEFAULT_VALIDATION_MESSAGE_STYLE`.
+/// - **input_class**: CSS class for styling the prompt input (`Option<AttrValue>`). Default: `""`.
+/// - **input_style**: Default inline styles for the prompt input (`Option<AttrValue>`). Default: `DEFAULT_INPUT_STYLE`.
+/// - **close_on_backdrop_click**: Whether clicking the backdrop closes the alert (`Option<bool>`). Default: `false`.
+/// - **close_on_escape**: Whether pressing Escape closes the alert (`Option<bool>`). Default: `true`.
+/// - **initial_focus**: The element focused when the alert opens; focus is trapped inside and restored on close (`Option<InitialFocus>`). Default: `InitialFocus::Confirm`.
+/// - **backdrop_style**: Default inline styles for the backdrop (`Option<AttrValue>`). Default: `DEFAULT_BACKDROP_STYLE`.
+/// - **animation**: The enter and exit animation (`Option<AlertAnimation>`). Default: `AlertAnimation::None`.
+/// - **animation_duration**: Duration of the enter and exit animations in milliseconds (`Option<u32>`). Default: `300`.
+/// - **enter_class**: CSS class added to the alert box while it is open (`AttrValue`). Default: `""`.
+/// - **exit_class**: CSS class added to the alert box while it leaves (`AttrValue`). Default: `""`.
+/// - **reduced_motion**: Whether the alert honors the system's reduced motion setting (`Option<ReducedMotion>`). Default: `ReducedMotion::System`.
 /// - **will_open**: Callback triggered before the alert opens (`Callback<()>`). Default: no-op.
 /// - **did_open**: Callback triggered after the alert opens (`Callback<()>`). Default: no-op.
-/// - **did_close**: Callback triggered after the alert closes (`Callback<()>`). Default: no-op.
+/// - **did_close**: Callback triggered after the alert closes, with the reason (`Callback<AlertResult>`). Default: no-op.
 /// - **on_confirm**: Callback triggered when the confirm button is clicked (`Callback<()>`). Default: no-op.
 /// - **on_close**: Callback triggered when the close button is clicked (`Callback<()>`). Default: no-op.
 /// - **on_cancel**: Callback triggered when the cancel button is clicked (`Callback<()>`). Default: no-op.
+/// - **on_deny**: Callback triggered when the deny button is clicked (`Callback<()>`). Default: no-op.
+/// - **on_action**: Callback triggered when an action is clicked, with its id (`Callback<String>`). Default: no-op.
 ///
 /// # Features
 /// - Customizable alert message and title.
+/// - Rich content through `children`, `header` and `footer`.
 /// - Configurable buttons (confirm, cancel, and close).
 /// - Adjustable timeout duration for auto-closing.
 /// - Dynamic positioning and icon customization.
+/// - Animated SweetAlert-style icons with `animated_icon`, shared by every framework.
+/// - Lazy-loaded images with `image_url`, falling back to `image_fallback_url` when they fail to load.
+/// - Announced as a `dialog` or `alertdialog` labelled by its title and described by its body.
+/// - Traps keyboard focus while open, starting at `initial_focus`, and restores it on close.
 /// - Supports native browser alert functionality with `native`.
 /// - Callbacks for various actions, such as opening, closing, and button clicks.
 ///
@@ -334,6 +794,421 @@ pub struct AlertProps {
 /// }
 /// ```
 ///
+/// ## Runtime Text
+/// ```rust
+/// use alert_rs::yew::Alert;
+/// use yew::prelude::*;
+///
+/// #[function_component(DeletedFiles)]
+/// pub fn deleted_files() -> Html {
+///     let show_alert = use_state(|| false);
+///     let count = use_state(|| 3);
+///     html! {
+///         <>
+///             <button onclick={let show_alert = show_alert.clone(); Callback::from(move |_| {show_alert.set(true);})}>{"Delete"}</button>
+///             <Alert
+///                 show_alert={show_alert.clone()}
+///                 title="Done"
+///                 body={format!("Deleted {} files", *count)}
+///             />
+///         </>
+///     }
+/// }
+/// ```
+///
+/// ## Save / Don't Save / Cancel
+/// ```rust
+/// use alert_rs::yew::Alert;
+/// use yew::prelude::*;
+///
+/// #[function_component(UnsavedChanges)]
+/// pub fn unsaved_changes() -> Html {
+///     let show_alert = use_state(|| false);
+///     html! {
+///         <>
+///             <button onclick={let show_alert = show_alert.clone(); Callback::from(move |_| {show_alert.set(true);})}>{"Close Document"}</button>
+///             <Alert
+///                 show_alert={show_alert.clone()}
+///                 title="Save changes?"
+///                 timeout={0}
+///                 confirm_button_text="Save"
+///                 deny_button_text="Don't save"
+///                 show_deny_button={true}
+///                 on_confirm={Callback::from(|_| log::info!("Saved!"))}
+///                 on_deny={Callback::from(|_| log::info!("Discarded!"))}
+///             />
+///         </>
+///     }
+/// }
+/// ```
+///
+/// ## Actions
+/// ```rust
+/// use alert_rs::common::{ActionVariant, AlertAction};
+/// use alert_rs::yew::Alert;
+/// use yew::prelude::*;
+///
+/// #[function_component(UploadFailed)]
+/// pub fn upload_failed() -> Html {
+///     let show_alert = use_state(|| false);
+///     let actions = vec![
+///         AlertAction::new("retry", "Retry"),
+///         AlertAction::new("logs", "Open logs")
+///             .variant(ActionVariant::Secondary)
+///             .closes(false),
+///         AlertAction::new("dismiss", "Dismiss").variant(ActionVariant::Danger),
+///     ];
+///     html! {
+///         <>
+///             <button onclick={let show_alert = show_alert.clone(); Callback::from(move |_| {show_alert.set(true);})}>{"Upload"}</button>
+///             <Alert
+///                 show_alert={show_alert.clone()}
+///                 title="Upload failed"
+///                 timeout={0}
+///                 show_confirm_button={false}
+///                 show_cancel_button={false}
+///                 actions={actions}
+///                 on_action={Callback::from(|id: String| log::info!("Clicked {id}"))}
+///             />
+///         </>
+///     }
+/// }
+/// ```
+///
+/// ## Rich Content
+/// ```rust
+/// use alert_rs::yew::Alert;
+/// use yew::prelude::*;
+///
+/// #[function_component(TermsAlert)]
+/// pub fn terms_alert() -> Html {
+///     let show_alert = use_state(|| false);
+///     html! {
+///         <>
+///             <button onclick={let show_alert = show_alert.clone(); Callback::from(move |_| {show_alert.set(true);})}>{"Sign Up"}</button>
+///             <Alert
+///                 show_alert={show_alert.clone()}
+///                 title="Terms of Service"
+///                 timeout={0}
+///                 header={html! { <small>{"Step 2 of 3"}</small> }}
+///                 footer={html! { <a href="/privacy">{"Privacy policy"}</a> }}
+///             >
+///                 <ul>
+///                     <li>{"Be kind."}</li>
+///                     <li>{"No spam."}</li>
+///                 </ul>
+///             </Alert>
+///         </>
+///     }
+/// }
+/// ```
+///
+/// ## HTML Body
+/// ```rust
+/// use alert_rs::yew::Alert;
+/// use yew::prelude::*;
+///
+/// #[function_component(LegacyAlert)]
+/// pub fn legacy_alert() -> Html {
+///     let show_alert = use_state(|| false);
+///     // Messages coming from the backend as HTML, like SweetAlert2's `html:` option.
+///     let html_body = "Your <b>trial</b> ends in <i>3 days</i>.<script>alert(1)</script>";
+///     html! {
+///         <>
+///             <button onclick={let show_alert = show_alert.clone(); Callback::from(move |_| {show_alert.set(true);})}>{"Show"}</button>
+///             <Alert show_alert={show_alert.clone()} title="Heads up" html_body={html_body} />
+///         </>
+///     }
+/// }
+/// ```
+///
+/// ## Grouped Classes and Styles
+/// ```rust
+/// use alert_rs::common::{AlertClasses, AlertStyles};
+/// use alert_rs::yew::Alert;
+/// use yew::prelude::*;
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     let show_alert = use_state(|| true);
+///     let classes = AlertClasses::default()
+///         .alert("rounded-lg shadow-xl p-6 bg-white")
+///         .confirm_button("btn btn-primary");
+///     let styles = AlertStyles::default().title("font-size: 20px;");
+///     html! {
+///         <Alert
+///             show_alert={show_alert.clone()}
+///             title="Grouped"
+///             classes={classes.clone().merge(AlertClasses::default().confirm_button("btn btn-danger"))}
+///             {styles}
+///             // Flat props still win over the grouped ones.
+///             title_class="text-xl"
+///         />
+///     }
+/// }
+/// ```
+///
+/// ## Animations
+/// ```rust
+/// use alert_rs::common::AlertAnimation;
+/// use alert_rs::yew::Alert;
+/// use alert_rs::{IconType, Position};
+/// use yew::prelude::*;
+///
+/// #[function_component(SlideAlert)]
+/// pub fn slide_alert() -> Html {
+///     let show_alert = use_state(|| false);
+///     html! {
+///         <>
+///             <button onclick={let show_alert = show_alert.clone(); Callback::from(move |_| {show_alert.set(true);})}>{"Show"}</button>
+///             <Alert
+///                 show_alert={show_alert.clone()}
+///                 title="Sliding in"
+///                 icon_type={IconType::Success}
+///                 animated_icon={true}
+///                 position={Position::BottomRight}
+///                 animation={AlertAnimation::Slide}
+///                 animation_duration={500}
+///                 // Fired once the exit animation is over.
+///                 did_close={Callback::from(|_| log::info!("Gone"))}
+///             />
+///             // Or bring your own keyframes, e.g. from Animate.css.
+///             <Alert
+///                 show_alert={show_alert.clone()}
+///                 animation={AlertAnimation::None}
+///                 enter_class="animate__animated animate__backInDown"
+///                 exit_class="animate__animated animate__backOutDown"
+///             />
+///         </>
+///     }
+/// }
+/// ```
+///
+/// ## Custom Icons
+/// ```rust
+/// use alert_rs::common::CustomIcon;
+/// use alert_rs::yew::Alert;
+/// use alert_rs::IconType;
+/// use yew::prelude::*;
+///
+/// #[function_component(BrandedAlerts)]
+/// pub fn branded_alerts() -> Html {
+///     let show_alert = use_state(|| true);
+///     html! {
+///         <>
+///             <Alert
+///                 show_alert={show_alert.clone()}
+///                 title="New message"
+///                 icon_type={IconType::Custom(CustomIcon::Svg {
+///                     path: "M64 112c-8.8 0-16 7.2-16 16v22.1L220.5 291.7c20.7 17 50.4 17 71.1 0L464 150.1V128c0-8.8-7.2-16-16-16H64z".into(),
+///                     view_box: "0 0 512 512".into(),
+///                 })}
+///                 icon_color="purple"
+///             />
+///             <Alert
+///                 show_alert={show_alert.clone()}
+///                 title="Welcome back"
+///                 icon_type={IconType::Custom(CustomIcon::Image("/assets/avatar.png".into()))}
+///             />
+///             <Alert
+///                 show_alert={show_alert.clone()}
+///                 title="Level up"
+///                 icon_type={IconType::Custom(CustomIcon::Node)}
+///                 icon={html! { <span style="font-size: 48px;">{"🏆"}</span> }}
+///             />
+///             <Alert show_alert={show_alert.clone()} title="No icon" icon_type={IconType::None} />
+///         </>
+///     }
+/// }
+/// ```
+///
+/// ## Image
+/// ```rust
+/// use alert_rs::yew::Alert;
+/// use alert_rs::IconType;
+/// use yew::prelude::*;
+///
+/// #[function_component(WhatsNew)]
+/// pub fn whats_new() -> Html {
+///     let show_alert = use_state(|| true);
+///     html! {
+///         <Alert
+///             show_alert={show_alert.clone()}
+///             title="What's new"
+///             body="Dark mode is here."
+///             icon_type={IconType::None}
+///             image_url="/assets/dark-mode.png"
+///             image_alt="The dashboard in dark mode"
+///             image_width="400"
+///             image_height="200"
+///             image_fallback_url="/assets/placeholder.png"
+///             image_class="rounded-lg"
+///             timeout={0}
+///         />
+///     }
+/// }
+/// ```
+///
+/// ## Accessibility
+/// ```rust
+/// use alert_rs::yew::Alert;
+/// use alert_rs::IconType;
+/// use yew::prelude::*;
+///
+/// #[function_component(DeleteFiles)]
+/// pub fn delete_files() -> Html {
+///     let show_alert = use_state(|| true);
+///     html! {
+///         // Rendered as `role="alertdialog"` with `aria-labelledby="delete-files-title"`
+///         // and `aria-describedby="delete-files-body"`.
+///         <Alert
+///             show_alert={show_alert.clone()}
+///             id="delete-files"
+///             title="Delete files?"
+///             body="This cannot be undone."
+///             icon_type={IconType::Warning}
+///         />
+///     }
+/// }
+/// ```
+///
+/// ## Initial Focus
+/// ```rust
+/// use alert_rs::common::InitialFocus;
+/// use alert_rs::yew::Alert;
+/// use alert_rs::IconType;
+/// use yew::prelude::*;
+///
+/// #[function_component(DeleteAccount)]
+/// pub fn delete_account() -> Html {
+///     let show_alert = use_state(|| true);
+///     html! {
+///         // Focus starts on Cancel, so pressing Enter right away does not delete anything.
+///         <Alert
+///             show_alert={show_alert.clone()}
+///             title="Delete your account?"
+///             icon_type={IconType::Warning}
+///             initial_focus={InitialFocus::Cancel}
+///             timeout={0}
+///         />
+///     }
+/// }
+/// ```
+///
+/// ## Reduced Motion
+/// ```rust
+/// use alert_rs::common::ReducedMotion;
+/// use alert_rs::yew::Alert;
+/// use yew::prelude::*;
+///
+/// #[derive(Properties, PartialEq)]
+/// pub struct SettingsProps {
+///     pub reduce_motion: bool,
+/// }
+///
+/// #[function_component(SavedAlert)]
+/// pub fn saved_alert(props: &SettingsProps) -> Html {
+///     let show_alert = use_state(|| true);
+///     // Follows `prefers-reduced-motion` by default; an in-app setting can force it.
+///     let reduced_motion = if props.reduce_motion {
+///         ReducedMotion::Always
+///     } else {
+///         ReducedMotion::System
+///     };
+///     html! {
+///         <Alert show_alert={show_alert.clone()} title="Saved" {reduced_motion} />
+///     }
+/// }
+/// ```
+///
+/// ## App-wide Defaults
+/// ```rust
+/// use alert_rs::common::{AlertClasses, AlertDefaults};
+/// use alert_rs::yew::Alert;
+/// use alert_rs::Position;
+/// use yew::prelude::*;
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     let show_alert = use_state(|| true);
+///     let defaults = AlertDefaults::default()
+///         .position(Position::TopCenter)
+///         .confirm_button_text("Got it")
+///         .classes(AlertClasses::default().alert("rounded-lg shadow-xl p-6 bg-white"));
+///     html! {
+///         <ContextProvider<AlertDefaults> context={defaults}>
+///             // Uses the app-wide position, button text and class.
+///             <Alert show_alert={show_alert.clone()} title="Saved" />
+///         </ContextProvider<AlertDefaults>>
+///     }
+/// }
+/// ```
+///
+/// ## Prompt
+/// ```rust
+/// use alert_rs::yew::Alert;
+/// use alert_rs::InputType;
+/// use yew::prelude::*;
+///
+/// #[function_component(NamePrompt)]
+/// pub fn name_prompt() -> Html {
+///     let show_alert = use_state(|| false);
+///     html! {
+///         <>
+///             <button onclick={let show_alert = show_alert.clone(); Callback::from(move |_| {show_alert.set(true);})}>{"Ask Name"}</button>
+///             <Alert
+///                 show_alert={show_alert.clone()}
+///                 title="What is your name?"
+///                 input={InputType::Text}
+///                 input_placeholder="Ferris"
+///                 input_validator={Callback::from(|name: String| {
+///                     if name.trim().is_empty() {
+///                         Err("Please enter a name".to_string())
+///                     } else {
+///                         Ok(())
+///                     }
+///                 })}
+///                 timeout={0}
+///                 did_close={Callback::from(|result: alert_rs::common::AlertResult| {
+///                     if let Some(name) = result.value {
+///                         log::info!("Hello, {name}!");
+///                     }
+///                 })}
+///             />
+///         </>
+///     }
+/// }
+/// ```
+///
+/// ## Async Confirmation
+/// ```rust
+/// use alert_rs::common::PreConfirmFuture;
+/// use alert_rs::yew::Alert;
+/// use yew::prelude::*;
+///
+/// #[function_component(SaveAlert)]
+/// pub fn save_alert() -> Html {
+///     let show_alert = use_state(|| false);
+///     let pre_confirm = Callback::from(|_: Option<String>| -> PreConfirmFuture {
+///         Box::pin(async move {
+///             // e.g. `gloo::net::http::Request::post("/api/save").send().await`
+///             Err("The server is unavailable".to_string())
+///         })
+///     });
+///     html! {
+///         <>
+///             <button onclick={let show_alert = show_alert.clone(); Callback::from(move |_| {show_alert.set(true);})}>{"Save"}</button>
+///             <Alert
+///                 show_alert={show_alert.clone()}
+///                 title="Save changes?"
+///                 timeout={0}
+///                 pre_confirm={pre_confirm}
+///             />
+///         </>
+///     }
+/// }
+/// ```
+///
 /// ## Native Alert
 /// ```rust
 /// use alert_rs::yew::Alert;
@@ -355,258 +1230,699 @@ pub struct AlertProps {
 /// - The component uses `use_state` to manage the visibility of the alert.
 /// - It also uses `use_effect_with` to handle side-effects such as displaying the alert and auto-closing after the specified timeout.
 /// - Buttons trigger specific callbacks (e.g., confirm, cancel, close) when clicked.
+/// - Open, close and timer transitions are driven by the shared `AlertMachine`, so every framework behaves the same.
+/// - Clicking confirm, cancel or close hides the alert. A `timeout` of `0` disables auto-closing.
+/// - A hidden alert stays mounted while its exit animation plays, and `did_close` fires once it
+///   is over.
+/// - When `prefers-reduced-motion` is set, or `reduced_motion` forces it, alerts open and close
+///   instantly and the spinner stands still.
+/// - `did_close` receives an `AlertResult` whose `CloseReason` tells confirm, cancel, close,
+///   backdrop, Escape, timer and programmatic hiding apart.
 ///
 /// # Notes
 /// - The `native` prop can be set to `true` to use the browser's default alert behavior instead of the custom component.
+/// - Props left unset take the value of the nearest `AlertDefaults` context, if any.
+/// - Native dialogs only show `title` and `body` text; `children`, `header` and `footer` are not rendered.
+/// - With the `markdown` feature, `body` is rendered as Markdown inside a `<div>`; raw HTML in it is
+///   shown as text.
 #[function_component]
 pub fn Alert(props: &AlertProps) -> Html {
+    let defaults = use_context::<AlertDefaults>().unwrap_or_default();
+    let resolve = |value: &Option<AttrValue>, default: &Option<String>, builtin: &'static str| {
+        AlertDefaults::resolve(
+            value.clone(),
+            default.clone().map(AttrValue::from),
+            AttrValue::Static(builtin),
+        )
+    };
+    let icon_type = AlertDefaults::resolve(
+        props.icon_type.clone(),
+        defaults.icon_type.clone(),
+        IconType::Info,
+    );
+    let show_confirm_button = AlertDefaults::resolve(
+        props.show_confirm_button,
+        defaults.show_confirm_button,
+        true,
+    );
+    let show_cancel_button =
+        AlertDefaults::resolve(props.show_cancel_button, defaults.show_cancel_button, true);
+    let show_deny_button =
+        AlertDefaults::resolve(props.show_deny_button, defaults.show_deny_button, false);
+    let show_close_button =
+        AlertDefaults::resolve(props.show_close_button, defaults.show_close_button, false);
+    let close_on_backdrop_click = AlertDefaults::resolve(
+        props.close_on_backdrop_click,
+        defaults.close_on_backdrop_click,
+        false,
+    );
+    let close_on_escape =
+        AlertDefaults::resolve(props.close_on_escape, defaults.close_on_escape, true);
+    let initial_focus = AlertDefaults::resolve(
+        props.initial_focus,
+        defaults.initial_focus,
+        InitialFocus::Confirm,
+    );
+    let animation =
+        AlertDefaults::resolve(props.animation, defaults.animation, AlertAnimation::None);
+    let animation_duration =
+        AlertDefaults::resolve(props.animation_duration, defaults.animation_duration, 300);
+    let classes = defaults.classes.clone().merge(props.classes.clone());
+    let styles = defaults.styles.clone().merge(props.styles.clone());
+    let timeout = AlertDefaults::resolve(props.timeout, defaults.timeout, 2500);
+    let position = AlertDefaults::resolve(
+        props.position.clone(),
+        defaults.position.clone(),
+        Position::TopRight,
+    );
+    let confirm_button_text = resolve(
+        &props.confirm_button_text,
+        &defaults.confirm_button_text,
+        "Okay",
+    );
+    let cancel_button_text = resolve(
+        &props.cancel_button_text,
+        &defaults.cancel_button_text,
+        "Cancel",
+    );
+    let deny_button_text = resolve(&props.deny_button_text, &defaults.deny_button_text, "No");
+    let alert_class = resolve(&props.alert_class, &classes.alert, "");
+    let icon_class = resolve(&props.icon_class, &classes.icon, "");
+    let image_class = resolve(&props.image_class, &classes.image, "");
+    let title_class = resolve(&props.title_class, &classes.title, "");
+    let body_class = resolve(&props.body_class, &classes.body, "");
+    let confirm_button_class = resolve(&props.confirm_button_class, &classes.confirm_button, "");
+    let cancel_button_class = resolve(&props.cancel_button_class, &classes.cancel_button, "");
+    let deny_button_class = resolve(&props.deny_button_class, &classes.deny_button, "");
+    let input_class = resolve(&props.input_class, &classes.input, "");
+    let validation_message_class = resolve(
+        &props.validation_message_class,
+        &classes.validation_message,
+        "",
+    );
+    let alert_style = resolve(&props.alert_style, &styles.alert, DEFAULT_ALERT_STYLE);
+    let backdrop_style = resolve(
+        &props.backdrop_style,
+        &styles.backdrop,
+        DEFAULT_BACKDROP_STYLE,
+    );
+    let close_button_style = resolve(
+        &props.close_button_style,
+        &styles.close_button,
+        DEFAULT_CLOSE_BUTTON_STYLE,
+    );
+    let confirm_button_style = resolve(
+        &props.confirm_button_style,
+        &styles.confirm_button,
+        DEFAULT_CONFIRM_BUTTON_STYLE,
+    );
+    let cancel_button_style = resolve(
+        &props.cancel_button_style,
+        &styles.cancel_button,
+        DEFAULT_CANCEL_BUTTON_STYLE,
+    );
+    let deny_button_style = resolve(
+        &props.deny_button_style,
+        &styles.deny_button,
+        DEFAULT_DENY_BUTTON_STYLE,
+    );
+    let icon_style = resolve(&props.icon_style, &styles.icon, DEFAULT_ICON_STYLE);
+    let image_style = resolve(&props.image_style, &styles.image, DEFAULT_IMAGE_STYLE);
+    let title_style = resolve(&props.title_style, &styles.title, DEFAULT_TITLE_STYLE);
+    let separator_style = resolve(
+        &props.separator_style,
+        &styles.separator,
+        DEFAULT_SEPARATOR_STYLE,
+    );
+    let message_style = resolve(&props.message_style, &styles.message, DEFAULT_MESSAGE_STYLE);
+    let input_style = resolve(&props.input_style, &styles.input, DEFAULT_INPUT_STYLE);
+    let input_label_style = resolve(
+        &props.input_label_style,
+        &styles.input_label,
+        DEFAULT_INPUT_LABEL_STYLE,
+    );
+    let validation_message_style = resolve(
+        &props.validation_message_style,
+        &styles.validation_message,
+        DEFAULT_VALIDATION_MESSAGE_STYLE,
+    );
+    let spinner_style = resolve(&props.spinner_style, &styles.spinner, DEFAULT_SPINNER_STYLE);
     let show = *props.show_alert;
-    let timeout = props.timeout;
     let native = props.native;
-    let show_alert = props.show_alert.clone();
-
-    let title = props.title.to_string();
-    let body = props.body.to_string();
-
-    let show_confirm_button = props.show_confirm_button;
-    let show_cancel_button = props.show_cancel_button;
-    let show_close_button = props.show_close_button;
-
-    let timer = Some(props.timeout);
-    let will_open = props.will_open.clone();
-    let did_open = props.did_open.clone();
-    let did_close = props.did_close.clone();
-    let on_confirm = props.on_confirm.clone();
-    let on_close = props.on_close.clone();
-    let on_cancel = props.on_cancel.clone();
-
-    use_effect_with(show_alert.clone(), move |show_alert| {
-        if **show_alert && !native {
-            let show_alert = show_alert.clone();
-            will_open.emit(());
-            let handle = Timeout::new(timeout, move || {
-                show_alert.set(false);
-                did_close.emit(());
-            })
-            .forget();
-            let clear_handle = move || {
-                web_sys::Window::clear_timeout_with_handle(
-                    &web_sys::window().unwrap(),
-                    handle.as_f64().unwrap() as i32,
-                );
-            };
 
-            did_open.emit(());
+    let machine = use_mut_ref(|| AlertMachine::new(timeout));
+    let timer = use_mut_ref(|| None::<Timeout>);
+    machine.borrow_mut().set_timeout(timeout);
+    machine
+        .borrow_mut()
+        .set_pre_confirm(props.pre_confirm.is_some());
+    let reduced_motion = AlertDefaults::resolve(
+        props.reduced_motion,
+        defaults.reduced_motion,
+        ReducedMotion::System,
+    )
+    .is_reduced();
+    let animation = if reduced_motion {
+        AlertAnimation::None
+    } else {
+        animation
+    };
+    machine.borrow_mut().set_animation_duration(if native {
+        0
+    } else {
+        animation.exit_duration(animation_duration, &props.exit_class, reduced_motion)
+    });
 
-            Box::new(clear_handle) as Box<dyn FnOnce()>
-        } else if **show_alert && native {
-            if let Some(win) = window() {
-                will_open.emit(());
+    let validation_message = use_state(|| None::<String>);
+    let loading = use_state(|| false);
+    let leaving = use_state(|| false);
+    let failed_images = use_state(Vec::<String>::new);
+    let generated_ids = use_state(AlertIds::generate);
+    let ids = if props.id.is_empty() {
+        (*generated_ids).clone()
+    } else {
+        AlertIds::new(&props.id)
+    };
 
-                let full_message = if !title.is_empty() {
-                    format!("{}\n\n{}", title, body)
-                } else {
-                    body.clone()
-                };
+    let driver = Driver {
+        machine,
+        timer: timer.clone(),
+        show_alert: props.show_alert.clone(),
+        will_open: props.will_open.clone(),
+        did_open: props.did_open.clone(),
+        did_close: props.did_close.clone(),
+        on_confirm: props.on_confirm.clone(),
+        on_close: props.on_close.clone(),
+        on_cancel: props.on_cancel.clone(),
+        on_deny: props.on_deny.clone(),
+        on_action: props.on_action.clone(),
+        input_validator: props.input_validator.clone(),
+        validation_message: validation_message.clone(),
+        pre_confirm: props.pre_confirm.clone(),
+        loading: loading.clone(),
+        leaving: leaving.clone(),
+        native,
+    };
 
-                match (show_confirm_button, show_cancel_button, show_close_button) {
-                    (true, true, true) => {
-                        if win.confirm_with_message(&full_message).unwrap_or(false) {
-                            on_confirm.emit(());
-                        } else {
-                            on_close.emit(());
-                            on_cancel.emit(());
-                        }
-                    }
-                    (true, true, false) => {
-                        if win.confirm_with_message(&full_message).unwrap_or(false) {
-                            on_confirm.emit(());
+    use_effect_with((), move |_| {
+        move || {
+            timer.borrow_mut().take();
+        }
+    });
+
+    {
+        let driver = driver.clone();
+        let title = props.title.clone();
+        let body = props.body.clone();
+        let input = props.input;
+        let input_value = input.initial_value(&props.input_value, &props.input_options);
+        let dialog = NativeDialog::for_input(
+            input,
+            show_confirm_button,
+            show_deny_button,
+            show_cancel_button,
+            show_close_button,
+        );
+
+        use_effect_with(show, move |show| {
+            if !*show {
+                driver.send(AlertEvent::Hidden);
+            } else {
+                driver.send(AlertEvent::Show);
+                if !input.is_none() {
+                    driver.send(AlertEvent::Input(input_value.clone()));
+                }
+                driver.send(AlertEvent::Shown);
+
+                if native {
+                    if let Some(win) = window() {
+                        let full_message = if !title.is_empty() {
+                            format!("{}\n\n{}", title, body)
                         } else {
-                            on_cancel.emit(());
-                        }
-                    }
-                    (true, false, false) => {
-                        win.alert_with_message(&full_message).ok();
-                        on_confirm.emit(());
+                            body.to_string()
+                        };
+
+                        let accepted = match dialog {
+                            NativeDialog::Alert => win.alert_with_message(&full_message).is_ok(),
+                            NativeDialog::Confirm { .. } => {
+                                win.confirm_with_message(&full_message).unwrap_or(false)
+                            }
+                            NativeDialog::Prompt { .. } => {
+                                let mut message = full_message.clone();
+                                loop {
+                                    match win
+                                        .prompt_with_message_and_default(&message, &input_value)
+                                    {
+                                        Ok(Some(value)) => match driver.validate(&value) {
+                                            Ok(()) => {
+                                                driver.send(AlertEvent::Input(value));
+                                                break true;
+                                            }
+                                            Err(error) => {
+                                                message = format!("{}\n\n{}", error, full_message)
+                                            }
+                                        },
+                                        _ => break false,
+                                    }
+                                }
+                            }
+                            NativeDialog::None => false,
+                        };
+
+                        driver.send(dialog.event(accepted));
                     }
-                    _ => {}
                 }
+            }
+        });
+    }
+
+    {
+        let driver = driver.clone();
+        let close_on_escape = close_on_escape && !native;
 
-                if let Some(duration) = timer {
-                    let show_alert = show_alert.clone();
-                    Timeout::new(duration, move || {
-                        show_alert.set(false);
-                        did_close.emit(());
+        use_effect_with((show, close_on_escape), move |(show, close_on_escape)| {
+            let listener = (*show && *close_on_escape)
+                .then(window)
+                .flatten()
+                .map(|win| {
+                    EventListener::new(&win, "keydown", move |event| {
+                        let escape = event
+                            .dyn_ref::<web_sys::KeyboardEvent>()
+                            .is_some_and(|event| event.key() == "Escape");
+                        if escape {
+                            driver.send(AlertEvent::Escape);
+                        }
                     })
-                    .forget();
+                });
+            move || drop(listener)
+        });
+    }
+
+    {
+        let ids = ids.clone();
+
+        use_effect_with((show, native), move |(show, native)| {
+            let trap = (*show && !*native).then(|| FocusTrap::activate(&ids, initial_focus));
+            move || drop(trap)
+        });
+    }
+
+    let input_ref = use_node_ref();
+
+    {
+        let input_ref = input_ref.clone();
+        let attributes = props.input_attributes.clone();
+
+        use_effect_with((show, attributes), move |(_, attributes)| {
+            if let Some(element) = input_ref.cast::<web_sys::Element>() {
+                set_input_attributes(&element, attributes);
+            }
+        });
+    }
+
+    let on_input = {
+        let driver = driver.clone();
+        Callback::from(move |event: InputEvent| {
+            let value = if let Some(input) = event.target_dyn_into::<web_sys::HtmlInputElement>() {
+                input.value()
+            } else if let Some(textarea) = event.target_dyn_into::<web_sys::HtmlTextAreaElement>() {
+                textarea.value()
+            } else {
+                return;
+            };
+            driver.send(AlertEvent::Input(value));
+        })
+    };
+
+    let on_change = {
+        let driver = driver.clone();
+        Callback::from(move |event: Event| {
+            let value = if let Some(select) = event.target_dyn_into::<web_sys::HtmlSelectElement>()
+            {
+                select.value()
+            } else if let Some(input) = event.target_dyn_into::<web_sys::HtmlInputElement>() {
+                if input.type_() == "checkbox" {
+                    input.checked().to_string()
                 } else {
-                    show_alert.set(false);
+                    input.value()
                 }
+            } else {
+                return;
+            };
+            driver.send(AlertEvent::Input(value));
+        })
+    };
 
-                did_open.emit(());
-            }
+    let initial_value = props
+        .input
+        .initial_value(&props.input_value, &props.input_options);
 
-            Box::new(|| {}) as Box<dyn FnOnce()>
-        } else {
-            Box::new(|| {}) as Box<dyn FnOnce()>
-        }
-    });
+    let invalid = validation_message.is_some().to_string();
+    let error_described_by = validation_message
+        .is_some()
+        .then(|| ids.validation_message.clone());
 
-    let on_cancel = {
-        let on_cancel = props.on_cancel.clone();
+    let input_tag = match props.input {
+        InputType::None => html! {},
+        InputType::Textarea => html! {
+            <textarea
+                ref={input_ref}
+                aria-invalid={invalid}
+                aria-describedby={error_described_by.clone()}
+                class={input_class.clone()}
+                style={input_style.clone()}
+                placeholder={props.input_placeholder.clone()}
+                value={initial_value}
+                oninput={on_input}
+            />
+        },
+        InputType::Select => html! {
+            <select
+                ref={input_ref}
+                aria-invalid={invalid}
+                aria-describedby={error_described_by.clone()}
+                class={input_class.clone()}
+                style={input_style.clone()}
+                onchange={on_change}
+            >
+                { for props.input_options.iter().map(|(value, label)| html! {
+                    <option value={value.clone()} selected={value.as_str() == initial_value}>{ label.clone() }</option>
+                }) }
+            </select>
+        },
+        InputType::Radio => html! {
+            <div
+                ref={input_ref}
+                class={input_class.clone()}
+                role="radiogroup"
+                aria-invalid={invalid}
+                aria-describedby={error_described_by.clone()}
+            >
+                { for props.input_options.iter().map(|(value, label)| html! {
+                    <label style={input_label_style.clone()}>
+                        <input
+                            type="radio"
+                            name={ids.radio_group.clone()}
+                            value={value.clone()}
+                            checked={value.as_str() == initial_value}
+                            onchange={on_change.clone()}
+                        />
+                        { label.clone() }
+                    </label>
+                }) }
+            </div>
+        },
+        InputType::Checkbox => html! {
+            <label class={input_class.clone()} style={input_label_style.clone()}>
+                <input
+                    ref={input_ref}
+                    aria-invalid={invalid}
+                    aria-describedby={error_described_by.clone()}
+                    type="checkbox"
+                    checked={initial_value == "true"}
+                    onchange={on_change}
+                />
+                { props.input_label.clone() }
+            </label>
+        },
+        InputType::Range { min, max, step } => html! {
+            <input
+                ref={input_ref}
+                aria-invalid={invalid}
+                aria-describedby={error_described_by.clone()}
+                type="range"
+                class={input_class.clone()}
+                style={input_style.clone()}
+                min={min.to_string()}
+                max={max.to_string()}
+                step={step.to_string()}
+                value={initial_value}
+                oninput={on_input}
+            />
+        },
+        input => html! {
+            <input
+                ref={input_ref}
+                aria-invalid={invalid}
+                aria-describedby={error_described_by.clone()}
+                type={input.html_type()}
+                class={input_class.clone()}
+                style={input_style.clone()}
+                placeholder={props.input_placeholder.clone()}
+                value={initial_value}
+                oninput={on_input}
+            />
+        },
+    };
+
+    let on_backdrop = {
+        let driver = driver.clone();
         Callback::from(move |_| {
-            on_cancel.emit(());
-            show_alert.set(false);
+            if close_on_backdrop_click {
+                driver.send(AlertEvent::Backdrop);
+            }
         })
     };
 
-    let on_confirm = {
-        let on_confirm = props.on_confirm.clone();
-        Callback::from(move |_| {
-            on_confirm.emit(());
+    let on_cancel = {
+        let driver = driver.clone();
+        Callback::from(move |_| driver.send(AlertEvent::Cancel))
+    };
+
+    let on_deny = {
+        let driver = driver.clone();
+        Callback::from(move |_| driver.send(AlertEvent::Deny))
+    };
+
+    let action_buttons = props
+        .actions
+        .iter()
+        .map(|action| {
+            let driver = driver.clone();
+            let event = AlertEvent::Action {
+                id: action.id.clone(),
+                closes: action.closes,
+            };
+            let onclick = Callback::from(move |_| driver.send(event.clone()));
+            html! {
+                <button key={action.id.clone()} class={action.class.clone()} style={action.button_style().to_string()} {onclick} disabled={*loading}>
+                    {action.label.clone()}
+                </button>
+            }
         })
+        .collect::<Html>();
+
+    let on_close = {
+        let driver = driver.clone();
+        Callback::from(move |_| driver.send(AlertEvent::Close))
     };
 
-    let position_style = match props.position {
-        Position::TopLeft => "top: 0; left: 0;",
-        Position::TopCenter => "top: 0; left: 50%; transform: translateX(-50%);",
-        Position::TopRight => "top: 0; right: 0;",
-        Position::LeftCenter => "top: 50%; left: 0; transform: translateY(-50%);",
-        Position::Center => "top: 50%; left: 50%; transform: translate(-50%, -50%);",
-        Position::BottomCenter => "bottom: 0; left: 50%; transform: translateX(-50%);",
-        Position::RightCenter => "top: 50%; right: 0; transform: translateY(-50%);",
-        Position::BottomRight => "bottom: 0; right: 0;",
-        Position::BottomLeft => "bottom: 0; left: 0;",
-        Position::Custom(x, y) => &format!("top: {}; left: {};", y, x),
+    let on_confirm = Callback::from(move |_| driver.confirm());
+
+    #[cfg(not(feature = "markdown"))]
+    let body_tag = html! {
+        <p class={body_class.clone()} style={message_style.clone()}>
+            { props.body.clone() }
+        </p>
+    };
+    #[cfg(feature = "markdown")]
+    let body_tag = html! {
+        <div class={body_class.clone()} style={message_style.clone()}>
+            { markdown::render_markdown(&parse_markdown(&props.body)) }
+        </div>
     };
 
-    let icon_color = if props.icon_color.is_empty() {
-        match props.icon_type {
-            IconType::Warning => "orange",
-            IconType::Error => "red",
-            IconType::Success => "green",
-            IconType::Info => "blue",
-            IconType::Question => "gray",
+    #[cfg(feature = "sanitize")]
+    let html_body_tag = if props.html_body.is_empty() {
+        html! {}
+    } else {
+        let html_body = Html::from_html_unchecked(sanitize_html(&props.html_body).into());
+        html! {
+            <div class={body_class.clone()} style={message_style.clone()}>{ html_body }</div>
         }
+    };
+    #[cfg(not(feature = "sanitize"))]
+    let html_body_tag = if props.html_body.is_empty() {
+        html! {}
     } else {
-        props.icon_color
+        html! {
+            <div class={body_class.clone()} style={message_style.clone()}>{ props.html_body.clone() }</div>
+        }
     };
 
-    // SVGs taken from: https://fontawesome.com/icons
-    let icon_tag = match props.icon_type {
-        IconType::Warning => html! {
-            <svg
-                xmlns="http://www.w3.org/2000/svg"
-                width={props.icon_width}
-                style={props.icon_style}
-                class="p-2 m-2"
-                fill={icon_color}
-                viewBox="0 0 512 512"
-            >
-                <path
-                    d="M248.4 84.3c1.6-2.7 4.5-4.3 7.6-4.3s6 1.6 7.6 4.3L461.9 410c1.4 2.3 2.1 4.9 2.1 7.5c0 8-6.5 14.5-14.5 14.5H62.5c-8 0-14.5-6.5-14.5-14.5c0-2.7 .7-5.3 2.1-7.5L248.4 84.3zm-41-25L9.1 385c-6 9.8-9.1 21-9.1 32.5C0 452 28 480 62.5 480h387c34.5 0 62.5-28 62.5-62.5c0-11.5-3.2-22.7-9.1-32.5L304.6 59.3C294.3 42.4 275.9 32 256 32s-38.3 10.4-48.6 27.3zM288 368a32 32 0 1 0 -64 0 32 32 0 1 0 64 0zm-8-184c0-13.3-10.7-24-24-24s-24 10.7-24 24v96c0 13.3 10.7 24 24 24s24-10.7 24-24V184z"
-                />
-            </svg>
-        },
-        IconType::Error => html! {
-            <svg
-                xmlns="http://www.w3.org/2000/svg"
-                width={props.icon_width}
-                style={props.icon_style}
-                class="p-2 m-2"
-                fill={icon_color}
-                viewBox="0 0 20 20"
-            >
-                <path
-                    d="M12.71,7.291c-0.15-0.15-0.393-0.15-0.542,0L10,9.458L7.833,7.291c-0.15-0.15-0.392-0.15-0.542,0c-0.149,0.149-0.149,0.392,0,0.541L9.458,10l-2.168,2.167c-0.149,0.15-0.149,0.393,0,0.542c0.15,0.149,0.392,0.149,0.542,0L10,10.542l2.168,2.167c0.149,0.149,0.392,0.149,0.542,0c0.148-0.149,0.148-0.392,0-0.542L10.542,10l2.168-2.168C12.858,7.683,12.858,7.44,12.71,7.291z M10,1.188c-4.867,0-8.812,3.946-8.812,8.812c0,4.867,3.945,8.812,8.812,8.812s8.812-3.945,8.812-8.812C18.812,5.133,14.867,1.188,10,1.188z M10,18.046c-4.444,0-8.046-3.603-8.046-8.046c0-4.444,3.603-8.046,8.046-8.046c4.443,0,8.046,3.602,8.046,8.046C18.046,14.443,14.443,18.046,10,18.046z"
-                />
-            </svg>
-        },
-        IconType::Success => html! {
+    let has_body =
+        !props.body.is_empty() || !props.html_body.is_empty() || props.children != Html::default();
+    let position_style = position.style();
+    let leaving = *leaving && !show;
+    let animation_class = match (reduced_motion, leaving) {
+        (true, _) => AttrValue::default(),
+        (false, true) => props.exit_class.clone(),
+        (false, false) => props.enter_class.clone(),
+    };
+
+    let icon_color = if props.icon_color.is_empty() {
+        AttrValue::Static(icon_type.default_color())
+    } else {
+        props.icon_color.clone()
+    };
+
+    let icon_tag = match icon_type.svg() {
+        Some(svg) => html! {
             <svg
                 xmlns="http://www.w3.org/2000/svg"
-                width={props.icon_width}
-                style={props.icon_style}
+                width={props.icon_width.clone()}
+                style={icon_style.clone()}
                 class="p-2 m-2"
-                fill={icon_color}
-                viewBox="0 0 512 512"
+                fill={icon_color.clone()}
+                viewBox={svg.view_box.to_string()}
             >
-                <path
-                    d="M256 48a208 208 0 1 1 0 416 208 208 0 1 1 0-416zm0 464A256 256 0 1 0 256 0a256 256 0 1 0 0 512zM369 209c9.4-9.4 9.4-24.6 0-33.9s-24.6-9.4-33.9 0l-111 111-47-47c-9.4-9.4-24.6-9.4-33.9 0s-9.4 24.6 0 33.9l64 64c9.4 9.4 24.6 9.4 33.9 0L369 209z"
-                />
+                <path d={svg.path.to_string()} />
             </svg>
         },
-        IconType::Info => html! {
-            <svg
-                xmlns="http://www.w3.org/2000/svg"
-                width={props.icon_width}
-                style={props.icon_style}
-                class="p-2 m-2"
-                fill={icon_color}
-                viewBox="0 0 16 16"
-            >
-                <path d="M8 15A7 7 0 1 1 8 1a7 7 0 0 1 0 14zm0 1A8 8 0 1 0 8 0a8 8 0 0 0 0 16z" />
-                <path
-                    d="m8.93 6.588-2.29.287-.082.38.45.083c.294.07.352.176.288.469l-.738 3.468c-.194.897.105 1.319.808 1.319.545 0 1.178-.252 1.465-.598l.088-.416c-.2.176-.492.246-.686.246-.275 0-.375-.193-.304-.533L8.93 6.588zM9 4.5a1 1 0 1 1-2 0 1 1 0 0 1 2 0z"
-                />
-            </svg>
+        None => match &icon_type {
+            IconType::Custom(CustomIcon::Image(url)) => html! {
+                <img src={url.clone()} width={props.icon_width.clone()} style={icon_style.clone()} class="p-2 m-2" alt="" />
+            },
+            IconType::Custom(CustomIcon::Node) => props.icon.clone(),
+            _ => html! {},
         },
-        IconType::Question => html! {
-            <svg
-                xmlns="http://www.w3.org/2000/svg"
-                width={props.icon_width}
-                style={props.icon_style}
-                class="p-2 m-2"
-                fill={icon_color}
-                viewBox="0 0 16 16"
-            >
-                <path d="M8 15A7 7 0 1 1 8 1a7 7 0 0 1 0 14zm0 1A8 8 0 1 0 8 0a8 8 0 0 0 0 16z" />
-                <path
-                    d="M5.255 5.786a.237.237 0 0 0 .241.247h.825c.138 0 .248-.113.266-.25.09-.656.54-1.134 1.342-1.134.686 0 1.314.343 1.314 1.168 0 .635-.374.927-.965 1.371-.673.489-1.206 1.06-1.168 1.987l.003.217a.25.25 0 0 0 .25.246h.811a.25.25 0 0 0 .25-.25v-.105c0-.718.273-.927 1.01-1.486.609-.463 1.244-.977 1.244-2.056 0-1.511-1.276-2.241-2.673-2.241-1.267 0-2.655.59-2.75 2.286zm1.557 5.763c0 .533.425.927 1.01.927.609 0 1.028-.394 1.028-.927 0-.552-.42-.94-1.029-.94-.584 0-1.009.388-1.009.94z"
+    };
+    let image_src = image_source(&props.image_url, &props.image_fallback_url, &failed_images)
+        .map(|src| AttrValue::from(src.to_string()));
+    let image_tag = match image_src {
+        Some(src) => {
+            let on_image_error = {
+                let failed_images = failed_images.clone();
+                let src = src.clone();
+                Callback::from(move |_: Event| {
+                    let mut failed = (*failed_images).clone();
+                    failed.push(src.to_string());
+                    failed_images.set(failed);
+                })
+            };
+            html! {
+                <img
+                    src={src}
+                    alt={props.image_alt.clone()}
+                    width={(!props.image_width.is_empty()).then(|| props.image_width.clone())}
+                    height={(!props.image_height.is_empty()).then(|| props.image_height.clone())}
+                    class={image_class.clone()}
+                    style={image_style.clone()}
+                    loading="lazy"
+                    onerror={on_image_error}
                 />
-            </svg>
+            }
+        }
+        None => html! {},
+    };
+    let animated_svg = (props.animated_icon && !reduced_motion)
+        .then(|| icon_type.animated_svg(&props.icon_width, &icon_color))
+        .flatten();
+    let icon_tag = match animated_svg {
+        Some(svg) => html! {
+            <>
+                <style>{ ANIMATED_ICON_STYLES }</style>
+                <span>{ Html::from_html_unchecked(svg.into()) }</span>
+            </>
         },
+        None => icon_tag,
     };
 
     if !native {
         html! {
-            if show {
-                <div style={props.alert_style}>
+            if show || leaving {
+                <div
+                    style={format!(
+                        "{} {}",
+                        alert_style,
+                        animation.overlay_style(animation_duration, leaving)
+                    )}
+                >
+                    if animation != AlertAnimation::None {
+                        <style>{ ANIMATION_KEYFRAMES }</style>
+                    }
+                    <div style={backdrop_style.clone()} onclick={on_backdrop}></div>
                     <div
-                        class={props.alert_class}
-                        style={format!("position: absolute; {}", position_style)}
+                        id={ids.dialog.clone()}
+                        tabindex="-1"
+                        role={icon_type.dialog_role()}
+                        aria-modal="true"
+                        aria-labelledby={ids.title.clone()}
+                        aria-describedby={has_body.then(|| ids.body.clone())}
+                        class={classes!(ALERT_CLASS, alert_class.clone(), animation_class)}
+                        style={format!(
+                            "position: absolute; {} {}",
+                            position_style,
+                            animation.box_style(&position, animation_duration, leaving)
+                        )}
                     >
-                        { if props.show_close_button {
+                        { if show_close_button {
                             html! {
-                                <button style={props.close_button_style} onclick={on_cancel.clone()}>{"X"}</button>
+                                <button style={close_button_style.clone()} onclick={on_close} disabled={*loading} aria-label="Close">{"X"}</button>
                             }
                         } else {
                             html! {}
                         } }
-                        <div class={props.icon_class} style={props.icon_style}>{ icon_tag }</div>
-                        <strong style={props.title_style} class={props.title_class}>
-                            { props.title }
+                        { props.header.clone() }
+                        if icon_type != IconType::None {
+                            <div class={icon_class.clone()} style={icon_style.clone()}>{ icon_tag }</div>
+                        }
+                        { image_tag }
+                        <strong id={ids.title.clone()} style={title_style.clone()} class={title_class.clone()}>
+                            { props.title.clone() }
                         </strong>
-                        <hr style={props.separator_style} />
-                        <p class={props.body_class} style={props.message_style}>{ props.body }</p>
-                        { if props.show_confirm_button {
+                        <hr style={separator_style.clone()} />
+                        if has_body {
+                            <div id={ids.body.clone()}>
+                                if !props.body.is_empty() {
+                                    { body_tag }
+                                }
+                                { html_body_tag }
+                                { props.children.clone() }
+                            </div>
+                        }
+                        { input_tag }
+                        if let Some(message) = (*validation_message).clone() {
+                            <div
+                                id={ids.validation_message.clone()}
+                                class={validation_message_class.clone()}
+                                style={validation_message_style.clone()}
+                                role="alert"
+                            >
+                                { message }
+                            </div>
+                        }
+                        if *loading && !reduced_motion {
+                            <style>{ SPINNER_KEYFRAMES }</style>
+                        }
+                        { if show_confirm_button {
                             html! {
-                                <button class={props.confirm_button_class} style={props.confirm_button_style} onclick={on_confirm}>
-                                    {props.confirm_button_text}
+                                <button id={ids.confirm_button.clone()} class={confirm_button_class.clone()} style={confirm_button_style.clone()} onclick={on_confirm} disabled={*loading}>
+                                    if *loading {
+                                        <span style={spinner_style.clone()} aria-hidden="true"></span>
+                                    }
+                                    {confirm_button_text.clone()}
                                 </button>
                             }
                         } else {
                             html! {}
                         } }
-                        { if props.show_cancel_button {
+                        if show_deny_button {
+                            <button id={ids.deny_button.clone()} class={deny_button_class.clone()} style={deny_button_style.clone()} onclick={on_deny} disabled={*loading}>
+                                {deny_button_text.clone()}
+                            </button>
+                        }
+                        { if show_cancel_button {
                             html! {
-                                <button  class={props.cancel_button_class} style={props.cancel_button_style} onclick={on_cancel.clone()}>
-                                    {props.cancel_button_text}
+                                <button id={ids.cancel_button.clone()} class={cancel_button_class.clone()} style={cancel_button_style.clone()} onclick={on_cancel} disabled={*loading}>
+                                    {cancel_button_text.clone()}
                                 </button>
                             }
                         } else {
                             html! {}
                         } }
+                        { action_buttons }
+                        { props.footer.clone() }
                     </div>
                 </div>
             }
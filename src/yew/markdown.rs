@@ -0,0 +1,34 @@
+use crate::common::MarkdownNode;
+use yew::prelude::*;
+
+/// Renders a Markdown `body` parsed by [`parse_markdown`](crate::common::parse_markdown).
+///
+/// Text is inserted as text nodes, so Yew escapes any markup it contains.
+pub(crate) fn render_markdown(nodes: &[MarkdownNode]) -> Html {
+    nodes.iter().map(render_node).collect()
+}
+
+fn render_node(node: &MarkdownNode) -> Html {
+    match node {
+        MarkdownNode::Text(text) => html! { { text.clone() } },
+        MarkdownNode::Code(code) => html! { <code>{ code.clone() }</code> },
+        MarkdownNode::LineBreak => html! { <br /> },
+        MarkdownNode::Paragraph(children) => html! { <p>{ render_markdown(children) }</p> },
+        MarkdownNode::Strong(children) => {
+            html! { <strong>{ render_markdown(children) }</strong> }
+        }
+        MarkdownNode::Emphasis(children) => html! { <em>{ render_markdown(children) }</em> },
+        MarkdownNode::List {
+            start: Some(start),
+            items,
+        } => html! { <ol start={start.to_string()}>{ render_markdown(items) }</ol> },
+        MarkdownNode::List { start: None, items } => {
+            html! { <ul>{ render_markdown(items) }</ul> }
+        }
+        MarkdownNode::ListItem(children) => html! { <li>{ render_markdown(children) }</li> },
+        MarkdownNode::Link { href, children } => html! {
+            <a href={href.clone().map(AttrValue::from)}>{ render_markdown(children) }</a>
+        },
+        MarkdownNode::CodeBlock(code) => html! { <pre><code>{ code.clone() }</code></pre> },
+    }
+}
@@ -0,0 +1,174 @@
+use super::Alert;
+use crate::common::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+use yew::prelude::*;
+
+/// Handle to the alerts of the nearest `AlertProvider`.
+///
+/// Obtained with [`use_alert`]. Cloning is cheap and every clone feeds the same queue.
+#[derive(Clone)]
+pub struct AlertHandle {
+    queue: Rc<RefCell<AlertQueue>>,
+    show_alert: UseStateSetter<bool>,
+}
+
+impl PartialEq for AlertHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.queue, &other.queue)
+    }
+}
+
+impl AlertHandle {
+    /// Shows an alert, or queues it behind the current one, and resolves to the user's answer.
+    pub fn fire(&self, config: AlertConfig) -> AlertFuture {
+        let mut queue = self.queue.borrow_mut();
+        let idle = queue.is_empty();
+        let future = queue.push(config);
+        if idle {
+            self.show_alert.set(true);
+        }
+        future
+    }
+}
+
+/// Returns the [`AlertHandle`] of the nearest `AlertProvider`.
+///
+/// # Panics
+/// Panics when called outside of an `AlertProvider`.
+#[hook]
+pub fn use_alert() -> AlertHandle {
+    use_context::<AlertHandle>().expect("`use_alert` must be called inside an `AlertProvider`")
+}
+
+/// Properties for configuring the `AlertProvider` component.
+#[derive(Debug, PartialEq, Properties, Clone)]
+pub struct AlertProviderProps {
+    /// The application content that can fire alerts through [`use_alert`].
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// Alert Provider Component
+///
+/// Renders the alerts fired through [`use_alert`] one at a time, in the order they were fired,
+/// and resolves each [`AlertFuture`] once its alert closes.
+///
+/// # Examples
+/// ```rust
+/// use alert_rs::common::AlertConfig;
+/// use alert_rs::yew::{use_alert, AlertProvider};
+/// use alert_rs::IconType;
+/// use yew::prelude::*;
+///
+/// #[function_component(DeleteButton)]
+/// pub fn delete_button() -> Html {
+///     let alert = use_alert();
+///     let onclick = Callback::from(move |_| {
+///         let alert = alert.clone();
+///         yew::platform::spawn_local(async move {
+///             let config = AlertConfig::new("This cannot be undone.")
+///                 .title("Delete file?")
+///                 .icon_type(IconType::Warning)
+///                 .timeout(0);
+///             if alert.fire(config).await.is_confirmed() {
+///                 log::info!("Deleted!");
+///             }
+///         });
+///     });
+///     html! { <button {onclick}>{"Delete"}</button> }
+/// }
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     html! {
+///         <AlertProvider>
+///             <DeleteButton />
+///         </AlertProvider>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn AlertProvider(props: &AlertProviderProps) -> Html {
+    let queue = use_mut_ref(AlertQueue::new);
+    let show_alert = use_state(|| false);
+
+    let handle = AlertHandle {
+        queue: queue.clone(),
+        show_alert: show_alert.setter(),
+    };
+
+    let did_close = {
+        let queue = queue.clone();
+        let show_alert = show_alert.setter();
+        Callback::from(move |result| {
+            if queue.borrow_mut().resolve(result).is_some() {
+                show_alert.set(true);
+            }
+        })
+    };
+
+    let current = {
+        let queue = queue.borrow();
+        queue.current().cloned().zip(queue.current_id())
+    };
+
+    html! {
+        <ContextProvider<AlertHandle> context={handle}>
+            { props.children.clone() }
+            // Keyed by the queue entry, so no state carries over from the previous alert.
+            if let Some((config, id)) = current {
+                <Alert
+                    key={id}
+                    show_alert={show_alert}
+                    title={config.title}
+                    body={config.body}
+                    icon_type={config.icon_type}
+                    animated_icon={config.animated_icon}
+                    image_url={config.image_url}
+                    image_alt={config.image_alt}
+                    image_width={config.image_width}
+                    image_height={config.image_height}
+                    image_fallback_url={config.image_fallback_url}
+                    position={config.position}
+                    timeout={config.timeout}
+                    confirm_button_text={config.confirm_button_text}
+                    cancel_button_text={config.cancel_button_text}
+                    deny_button_text={config.deny_button_text}
+                    show_confirm_button={config.show_confirm_button}
+                    show_cancel_button={config.show_cancel_button}
+                    show_deny_button={config.show_deny_button}
+                    show_close_button={config.show_close_button}
+                    actions={config.actions}
+                    input={config.input}
+                    input_placeholder={config.input_placeholder}
+                    input_value={config.input_value}
+                    input_attributes={config
+                        .input_attributes
+                        .into_iter()
+                        .map(|(name, value)| (name.into(), value.into()))
+                        .collect::<Vec<_>>()}
+                    input_options={config
+                        .input_options
+                        .into_iter()
+                        .map(|(value, label)| (value.into(), label.into()))
+                        .collect::<Vec<_>>()}
+                    input_label={config.input_label}
+                    input_validator={config.input_validator.map(|validator| {
+                        Callback::from(move |value: String| validator.validate(&value))
+                    })}
+                    pre_confirm={config.pre_confirm.map(|pre_confirm| {
+                        Callback::from(move |value| pre_confirm.run(value))
+                    })}
+                    classes={config.classes}
+                    styles={config.styles}
+                    animation={config.animation}
+                    animation_duration={config.animation_duration}
+                    reduced_motion={config.reduced_motion}
+                    initial_focus={config.initial_focus}
+                    {did_close}
+                />
+            }
+        </ContextProvider<AlertHandle>>
+    }
+}
@@ -0,0 +1,68 @@
+use crate::common::*;
+use yew::prelude::*;
+
+/// Properties for configuring the `AlertThemeProvider` component.
+#[derive(Debug, PartialEq, Properties, Clone)]
+pub struct AlertThemeProviderProps {
+    /// The application content whose alerts and toasts follow the theme.
+    #[prop_or_default]
+    pub children: Html,
+
+    /// Which theme to apply.
+    ///
+    /// Defaults to `ThemeMode::Auto`, following the system color scheme.
+    #[prop_or_default]
+    pub mode: ThemeMode,
+
+    /// The theme used in light mode.
+    ///
+    /// Defaults to `AlertTheme::light()`.
+    #[prop_or_else(AlertTheme::light)]
+    pub light: AlertTheme,
+
+    /// The theme used in dark mode.
+    ///
+    /// Defaults to `AlertTheme::dark()`.
+    #[prop_or_else(AlertTheme::dark)]
+    pub dark: AlertTheme,
+}
+
+/// Alert Theme Provider Component
+///
+/// Sets the `--alert-rs-*` CSS variables of an [`AlertTheme`] for the whole page, so every
+/// alert and toast switches theme at once when `mode` changes.
+///
+/// # Examples
+/// ```rust
+/// use alert_rs::common::{AlertTheme, ThemeMode};
+/// use alert_rs::yew::{Alert, AlertThemeProvider};
+/// use yew::prelude::*;
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     let show_alert = use_state(|| true);
+///     let dark = use_state(|| false);
+///     let mode = if *dark { ThemeMode::Dark } else { ThemeMode::Light };
+///     let on_toggle = {
+///         let dark = dark.clone();
+///         Callback::from(move |_| dark.set(!*dark))
+///     };
+///     html! {
+///         <AlertThemeProvider {mode} light={AlertTheme::light().confirm_color("#7c3aed")}>
+///             <button onclick={on_toggle}>{"Toggle theme"}</button>
+///             <Alert show_alert={show_alert} title="Themed" timeout={0} />
+///         </AlertThemeProvider>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn AlertThemeProvider(props: &AlertThemeProviderProps) -> Html {
+    let stylesheet = props.mode.stylesheet(&props.light, &props.dark);
+
+    html! {
+        <>
+            <style>{ stylesheet }</style>
+            { props.children.clone() }
+        </>
+    }
+}
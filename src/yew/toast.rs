@@ -0,0 +1,207 @@
+use crate::common::*;
+use gloo::timers::callback::Timeout;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use yew::prelude::*;
+
+/// Handle to the toasts of the nearest `ToastProvider`.
+///
+/// Obtained with [`use_toast`]. Cloning is cheap and every clone controls the same stacks.
+#[derive(Clone)]
+pub struct ToastHandle {
+    manager: Rc<RefCell<ToastManager>>,
+    refresh: Callback<()>,
+}
+
+impl PartialEq for ToastHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.manager, &other.manager)
+    }
+}
+
+impl ToastHandle {
+    /// Shows a toast, or queues it if its stack is full, and returns its id.
+    pub fn push(&self, toast: Toast) -> ToastId {
+        let id = self.manager.borrow_mut().push(toast);
+        self.refresh.emit(());
+        id
+    }
+
+    /// Removes a toast before its timeout expires.
+    pub fn dismiss(&self, id: ToastId) {
+        let removed = self.manager.borrow_mut().dismiss(id);
+        if removed.is_some() {
+            self.refresh.emit(());
+        }
+    }
+
+    /// Removes every toast.
+    pub fn clear(&self) {
+        self.manager.borrow_mut().clear();
+        self.refresh.emit(());
+    }
+}
+
+/// Returns the [`ToastHandle`] of the nearest `ToastProvider`.
+///
+/// # Panics
+/// Panics when called outside of a `ToastProvider`.
+#[hook]
+pub fn use_toast() -> ToastHandle {
+    use_context::<ToastHandle>().expect("`use_toast` must be called inside a `ToastProvider`")
+}
+
+/// Properties for configuring the `ToastProvider` component.
+#[derive(Debug, PartialEq, Properties, Clone)]
+pub struct ToastProviderProps {
+    /// The application content that can push toasts through [`use_toast`].
+    #[prop_or_default]
+    pub children: Html,
+
+    /// The gap in pixels between stacked toasts.
+    ///
+    /// Defaults to `10`.
+    #[prop_or(10)]
+    pub gap: u32,
+
+    /// The number of toasts shown at once per position; the rest are queued.
+    ///
+    /// Defaults to `5`.
+    #[prop_or(5)]
+    pub max_visible: usize,
+
+    /// Custom CSS class for styling each toast.
+    ///
+    /// Defaults to an empty string.
+    #[prop_or_default]
+    pub toast_class: AttrValue,
+
+    /// Default style for each toast.
+    ///
+    /// Defaults to `DEFAULT_TOAST_STYLE`.
+    #[prop_or(AttrValue::Static(DEFAULT_TOAST_STYLE))]
+    pub toast_style: AttrValue,
+
+    /// Default style for the toast title.
+    ///
+    /// Defaults to `DEFAULT_TOAST_TITLE_STYLE`.
+    #[prop_or(AttrValue::Static(DEFAULT_TOAST_TITLE_STYLE))]
+    pub title_style: AttrValue,
+
+    /// Default style for the toast body.
+    ///
+    /// Defaults to `DEFAULT_MESSAGE_STYLE`.
+    #[prop_or(AttrValue::Static(DEFAULT_MESSAGE_STYLE))]
+    pub message_style: AttrValue,
+
+    /// Default style for the close button of each toast.
+    ///
+    /// Defaults to `DEFAULT_CLOSE_BUTTON_STYLE`.
+    #[prop_or(AttrValue::Static(DEFAULT_CLOSE_BUTTON_STYLE))]
+    pub close_button_style: AttrValue,
+}
+
+/// Toast Provider Component
+///
+/// Hosts a [`ToastManager`] for its children and renders one stack of toasts per `Position`.
+/// Descendants call [`use_toast`] to push toasts; each toast is removed when its own `timeout`
+/// expires or when its close button is clicked.
+///
+/// # Examples
+/// ```rust
+/// use alert_rs::common::Toast;
+/// use alert_rs::yew::{use_toast, ToastProvider};
+/// use alert_rs::IconType;
+/// use yew::prelude::*;
+///
+/// #[function_component(SaveButton)]
+/// pub fn save_button() -> Html {
+///     let toast = use_toast();
+///     let onclick = Callback::from(move |_| {
+///         toast.push(Toast::new("Your changes were saved.").icon_type(IconType::Success));
+///     });
+///     html! { <button {onclick}>{"Save"}</button> }
+/// }
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     html! {
+///         <ToastProvider gap={8} max_visible={3}>
+///             <SaveButton />
+///         </ToastProvider>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn ToastProvider(props: &ToastProviderProps) -> Html {
+    let manager = use_mut_ref(ToastManager::new);
+    let timers = use_mut_ref(HashMap::<ToastId, Timeout>::new);
+    let update = use_force_update();
+    manager.borrow_mut().configure(props.gap, props.max_visible);
+
+    let handle = ToastHandle {
+        manager: manager.clone(),
+        refresh: Callback::from(move |_| update.force_update()),
+    };
+
+    {
+        let handle = handle.clone();
+        use_effect(move || {
+            let timeouts = handle.manager.borrow().visible_timeouts();
+            let mut timers = timers.borrow_mut();
+            timers.retain(|id, _| timeouts.iter().any(|(visible, _)| visible == id));
+            for (id, millis) in timeouts {
+                timers.entry(id).or_insert_with(|| {
+                    let handle = handle.clone();
+                    Timeout::new(millis, move || handle.dismiss(id))
+                });
+            }
+        });
+    }
+
+    let manager = manager.borrow();
+    let stacks = manager.stacks().into_iter().map(|(position, toasts)| {
+        let toasts = toasts.into_iter().map(|(id, toast)| {
+            let on_close = {
+                let handle = handle.clone();
+                Callback::from(move |_| handle.dismiss(id))
+            };
+            let style = format!(
+                "{} border-left: 4px solid {};",
+                props.toast_style,
+                toast.icon_type.default_color()
+            );
+            html! {
+                <div
+                    key={id.get()}
+                    class={props.toast_class.clone()}
+                    {style}
+                    aria-atomic="true"
+                >
+                    <button style={props.close_button_style.clone()} onclick={on_close} aria-label="Close">{"X"}</button>
+                    if !toast.title.is_empty() {
+                        <strong style={props.title_style.clone()}>{ toast.title.clone() }</strong>
+                    }
+                    <p style={props.message_style.clone()}>{ toast.body.clone() }</p>
+                </div>
+            }
+        });
+        html! {
+            <div
+                style={manager.stack_style(&position)}
+                aria-live={manager.stack_aria_live(&position)}
+                aria-relevant="additions text"
+            >
+                { for toasts }
+            </div>
+        }
+    });
+
+    html! {
+        <ContextProvider<ToastHandle> context={handle.clone()}>
+            { props.children.clone() }
+            { for stacks }
+        </ContextProvider<ToastHandle>>
+    }
+}